@@ -0,0 +1,118 @@
+use std::path::Path;
+
+use log::warn;
+use serde::{Deserialize, Serialize};
+
+use crate::{AnimationIndex, AnimationSlot, ModelFolderState};
+
+/// The animation slots for a model folder saved to a sharable JSON file.
+// Use defaults for missing values to avoid most version conflicts.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AnimationPreset {
+    pub slots: Vec<AnimationSlotPreset>,
+}
+
+/// An [AnimationSlot] with the animation identified by file name instead of index.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AnimationSlotPreset {
+    pub file_name: String,
+    pub enabled: bool,
+    pub speed: f32,
+    pub offset: f32,
+    pub label: String,
+}
+
+impl Default for AnimationSlotPreset {
+    fn default() -> Self {
+        Self {
+            file_name: String::new(),
+            enabled: true,
+            speed: 1.0,
+            offset: 0.0,
+            label: String::new(),
+        }
+    }
+}
+
+impl AnimationPreset {
+    pub fn from_slots(slots: &[AnimationSlot], models: &[ModelFolderState]) -> Self {
+        Self {
+            slots: slots
+                .iter()
+                .map(|slot| AnimationSlotPreset {
+                    file_name: slot
+                        .animation
+                        .and_then(|anim_index| anim_index.get_animation(models))
+                        .map(|(name, _)| name.clone())
+                        .unwrap_or_default(),
+                    enabled: slot.is_enabled,
+                    speed: slot.speed_multiplier,
+                    offset: slot.frame_offset,
+                    label: slot.label.clone(),
+                })
+                .collect(),
+        }
+    }
+
+    /// Create slots by finding each file name in `available_folders`.
+    /// Folders later in `available_folders` take priority like the animation selector.
+    pub fn to_slots(&self, available_folders: &[(usize, &ModelFolderState)]) -> Vec<AnimationSlot> {
+        self.slots
+            .iter()
+            .map(|slot| {
+                let animation = find_animation(available_folders, &slot.file_name);
+                if animation.is_none() && !slot.file_name.is_empty() {
+                    warn!(
+                        "Failed to find animation {:?} for animation setup",
+                        slot.file_name
+                    );
+                }
+
+                AnimationSlot {
+                    is_enabled: slot.enabled,
+                    animation,
+                    speed_multiplier: slot.speed,
+                    frame_offset: slot.offset,
+                    label: slot.label.clone(),
+                }
+            })
+            .collect()
+    }
+
+    pub fn from_file<P: AsRef<Path>>(path: P) -> anyhow::Result<Self> {
+        let data = std::fs::read(path)?;
+        Ok(serde_json::from_slice(&data)?)
+    }
+
+    pub fn write_to_file<P: AsRef<Path>>(&self, path: P) -> anyhow::Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, json)?;
+        Ok(())
+    }
+}
+
+fn find_animation(
+    available_folders: &[(usize, &ModelFolderState)],
+    file_name: &str,
+) -> Option<AnimationIndex> {
+    if file_name.is_empty() {
+        return None;
+    }
+
+    available_folders
+        .iter()
+        .rev()
+        .find_map(|(folder_index, folder)| {
+            folder
+                .model
+                .anims
+                .iter()
+                .position(|(name, _)| name == file_name)
+                .map(|anim_index| AnimationIndex {
+                    folder_index: *folder_index,
+                    anim_index,
+                })
+        })
+}
@@ -1,56 +1,77 @@
 use self::{
-    animation_bar::display_animation_bar, file_list::show_folder_files, menu::menu_bar,
-    rendering::calculate_mvp, window::*,
+    animation_bar::display_animation_bar,
+    file_list::{file_row_count, open_file_row, show_folder_files},
+    menu::menu_bar,
+    rendering::{
+        bone_axes_screen_lines, bone_screen_positions, bounding_sphere_screen_lines, calculate_mvp,
+        pick_mesh, screen_ray,
+    },
+    shading_shortcuts::shading_shortcut_bar,
+    window::*,
 };
 use crate::{
     app::{anim_list::anim_list, swing_list::swing_list},
-    capture::{render_animation_to_gif, render_animation_to_image_sequence, render_screenshot},
+    capture::{
+        render_animation_to_gif, render_animation_to_image_sequence, render_animation_to_webm,
+        render_comparison_screenshot, render_depth_screenshot, render_material_swatch,
+        render_multiangle_batch, render_nutexb_to_images, skinned_positions, submit_screenshot,
+        submit_screenshot_at_resolution, ComparisonCapture, DepthMesh, PendingCapture,
+        TurntableCapture,
+    },
     editors::{
         adj::{add_missing_adj_entries, adj_editor},
-        anim::anim_editor,
+        anim::{
+            anim_editor, material_param_curves, material_track_indices, sample_bone_transform,
+            set_bone_transform_keyframe, FrameRangeClipboard,
+        },
         hlpb::hlpb_editor,
-        matl::{matl_editor, preset_editor},
-        mesh::mesh_editor,
+        matl::{export_matl_swatch_sheet, matl_editor, preset_editor},
+        mesh::{mesh_editor, paint_vertex_colors},
         meshex::meshex_editor,
         modl::modl_editor,
         nutexb::nutexb_viewer,
-        skel::skel_editor,
+        skel::{bone_world_transforms, skel_editor},
     },
     generate_model_thumbnails, load_model,
-    log::AppLogger,
+    log::{AppLogger, LogMessage},
     model_folder::{FileChanged, ModelFolderState},
-    path::{folder_display_name, folder_editor_title, last_update_check_file},
-    preferences::AppPreferences,
+    path::{folder_display_name, last_update_check_file},
+    plugins::{ValidationError as PluginValidationError, ValidationPlugin},
+    preferences::{AppPreferences, PanelLayout},
+    project::ProjectFile,
     update::LatestReleaseInfo,
-    update_color_theme,
+    update_color_theme, update_text_styles,
     widgets::*,
-    AnimationIndex, AnimationSlot, AnimationState, CameraState, EditorResponse, FileResult,
-    RenderState, SwingState, Thumbnail, TEXT_COLOR_DARK, TEXT_COLOR_LIGHT,
+    AnimationIndex, AnimationSlot, AnimationState, CameraState, CameraValues, EditorResponse,
+    FileResult, RenderState, SwingState, Thumbnail, TEXT_COLOR_DARK, TEXT_COLOR_LIGHT,
 };
 use egui::{
-    collapsing_header::CollapsingState, Button, CentralPanel, CollapsingHeader, Context, Image,
-    ImageSource, Label, Response, RichText, ScrollArea, SidePanel, TextureOptions, TopBottomPanel,
-    Ui,
+    collapsing_header::CollapsingState, Button, CentralPanel, Context, Image, ImageSource, Label,
+    Response, RichText, ScrollArea, SidePanel, TextureOptions, TopBottomPanel, Ui,
 };
 use egui_commonmark::CommonMarkCache;
 use egui_wgpu::{CallbackResources, CallbackTrait, ScreenDescriptor};
 use log::error;
 use once_cell::sync::Lazy;
 use rfd::FileDialog;
-use ssbh_data::matl_data::MatlEntryData;
+use ssbh_data::anim_data::{GroupType, TrackData};
+use ssbh_data::matl_data::{MatlEntryData, ParamId};
 use ssbh_data::prelude::*;
-use ssbh_wgpu::{next_frame, ModelFiles, RenderModel};
+use ssbh_wgpu::{next_frame, ModelFiles, ModelRenderOptions, RenderModel, SsbhRenderer};
 use std::{
-    collections::{HashSet, VecDeque},
+    collections::{HashMap, HashSet, VecDeque},
     path::{Path, PathBuf},
-    sync::Mutex,
+    sync::atomic::Ordering,
 };
 
+pub use rendering::camera_transforms;
+
 mod anim_list;
 mod animation_bar;
 mod file_list;
 mod menu;
 mod rendering;
+mod shading_shortcuts;
 mod swing_list;
 mod window;
 
@@ -98,13 +119,13 @@ impl Editor for AdjData {
 }
 
 impl Editor for HlpbData {
-    type EditorState = ();
+    type EditorState = HlpbEditorState;
 
     fn editor(
         ctx: &Context,
         model: &mut ModelFolderState,
         open_file_index: &mut Option<usize>,
-        _: &mut Self::EditorState,
+        state: &mut Self::EditorState,
         _: bool,
     ) -> Option<EditorResponse> {
         let (name, hlpb) = get_file_to_edit(&mut model.model.hlpbs, *open_file_index)?;
@@ -114,6 +135,7 @@ impl Editor for HlpbData {
             name,
             hlpb,
             find_file(&model.model.skels, "model.nusktb"),
+            state,
         ))
     }
 
@@ -159,7 +181,14 @@ impl Editor for AnimData {
         _: bool,
     ) -> Option<EditorResponse> {
         let (name, anim) = get_file_to_edit(&mut model.model.anims, *open_file_index)?;
-        Some(anim_editor(ctx, &model.folder_path, name, anim, state))
+        Some(anim_editor(
+            ctx,
+            &model.folder_path,
+            name,
+            anim,
+            find_file(&model.model.skels, "model.nusktb"),
+            state,
+        ))
     }
 
     fn set_changed(response: &EditorResponse, changed: &mut FileChanged, index: usize) {
@@ -193,13 +222,13 @@ impl Editor for MeshExData {
 }
 
 impl Editor for MeshData {
-    type EditorState = ();
+    type EditorState = MeshEditorState;
 
     fn editor(
         ctx: &Context,
         model: &mut ModelFolderState,
         open_file_index: &mut Option<usize>,
-        _: &mut Self::EditorState,
+        state: &mut Self::EditorState,
         dark_mode: bool,
     ) -> Option<EditorResponse> {
         let (name, mesh) = get_file_to_edit(&mut model.model.meshes, *open_file_index)?;
@@ -207,8 +236,11 @@ impl Editor for MeshData {
             ctx,
             &model.folder_path,
             name,
+            state,
             mesh,
             find_file(&model.model.skels, "model.nusktb"),
+            find_file(&model.model.modls, "model.numdlb"),
+            find_file(&model.model.matls, "model.numatb"),
             &model.validation.mesh_errors,
             dark_mode,
         ))
@@ -297,9 +329,7 @@ fn open_editor<T: Editor>(
     }
 }
 
-pub static LOGGER: Lazy<AppLogger> = Lazy::new(|| AppLogger {
-    messages: Mutex::new(Vec::new()),
-});
+pub static LOGGER: Lazy<AppLogger> = Lazy::new(|| AppLogger::new(1000));
 
 // Create messages for updates instead of updating directly.
 // This avoids mixing rendering and UI logic in the same function.
@@ -327,6 +357,7 @@ pub enum RenderModelAction {
     Clear,
     HideAll,
     ShowAll,
+    ToggleVisibility(usize),
     HideExpressions,
     HideInkMeshes,
     SelectMesh {
@@ -340,6 +371,8 @@ pub struct SsbhApp {
     pub render_actions: VecDeque<RenderAction>,
 
     pub should_update_thumbnails: bool,
+    /// The progress of GPU work split across multiple frames like generating thumbnails.
+    pub gpu_progress: Option<GpuProgress>,
     pub should_validate_models: bool,
 
     pub release_info: LatestReleaseInfo,
@@ -347,6 +380,12 @@ pub struct SsbhApp {
     pub screenshot_to_render: Option<PathBuf>,
     pub animation_gif_to_render: Option<PathBuf>,
     pub animation_image_sequence_to_render: Option<PathBuf>,
+    pub animation_webm_to_render: Option<PathBuf>,
+    pub multiangle_batch_to_render: Option<PathBuf>,
+    pub turntable_gif_to_render: Option<PathBuf>,
+    pub comparison_screenshot_to_render: Option<PathBuf>,
+    pub depth_screenshot_to_render: Option<PathBuf>,
+    pub pending_captures: Vec<PendingCapture>,
 
     pub material_presets: Vec<MatlEntryData>,
     pub default_presets: Vec<MatlEntryData>,
@@ -355,8 +394,17 @@ pub struct SsbhApp {
     pub yellow_checkerboard: egui::TextureId,
 
     pub draw_bone_names: bool,
+    pub show_bone_axes: bool,
+    /// Draw the bounding sphere for the selected mesh or all meshes if no mesh is selected.
+    pub show_bounding_spheres: bool,
     pub enable_helper_bones: bool,
 
+    /// The bone name shared by the skel editor and viewport selection.
+    pub selected_bone: Option<String>,
+
+    /// The mesh object name in the selected folder shared by the mesh editor and viewport selection.
+    pub selected_mesh: Option<String>,
+
     pub ui_state: UiState,
     // TODO: Is parallel list with models the best choice here?
     pub models: Vec<ModelFolderState>,
@@ -370,14 +418,26 @@ pub struct SsbhApp {
     pub show_bottom_panel: bool,
 
     pub camera_state: CameraState,
+    /// The saved camera for each folder or `None` if all folders share [Self::camera_state].
+    pub per_folder_cameras: Option<Vec<CameraValues>>,
+    /// The folder whose camera is currently stored in [Self::camera_state].
+    pub camera_folder_index: Option<usize>,
 
     pub preferences: AppPreferences,
+    pub project: ProjectFile,
+
+    pub validation_plugins: Vec<Box<dyn ValidationPlugin>>,
+    pub plugin_errors: Vec<PluginValidationError>,
 
     pub markdown_cache: CommonMarkCache,
 
     pub previous_viewport_width: f32,
     pub previous_viewport_height: f32,
 
+    /// Split the viewport into two panes with separate cameras.
+    pub split_viewport: bool,
+    pub split_state: SplitViewportState,
+
     pub has_initialized_zoom_factor: bool,
 }
 
@@ -453,8 +513,17 @@ pub struct UiState {
     pub preset_editor_open: bool,
     pub right_panel_tab: PanelTab,
     pub log_window_open: bool,
+    /// The folder path for the open notes window.
+    pub notes_folder: Option<String>,
+    /// The folder index for the open model render settings window.
+    pub model_options_folder: Option<usize>,
+    pub validation_plugins_open: bool,
+    pub toasts: ToastQueue,
     pub preferences_window_open: bool,
     pub device_info_window_open: bool,
+    pub should_reset_layout: bool,
+    /// The focused row in the folder list if navigating with the keyboard.
+    pub tree_focus: Option<TreeFocusState>,
 
     // TODO: Is there a better way to track this?
     // Clicking an item in the file list sets the selected index.
@@ -473,14 +542,261 @@ pub struct UiState {
     pub matl_editor: MatlEditorState,
     pub preset_editor: MatlEditorState,
     pub anim_editor: AnimEditorState,
+    pub nutexb_viewer: NutexbViewerState,
     pub skel_editor: SkelEditorState,
+    pub hlpb_editor: HlpbEditorState,
+    pub mesh_editor: MeshEditorState,
     pub modl_editor: ModlEditorState,
     pub stage_lighting: StageLightingState,
+    pub log_window: LogWindowState,
+    pub turntable_capture: TurntableCapture,
+    pub comparison_capture: ComparisonCapture,
+    /// The open files for the second editor window or `None` if the window is closed.
+    pub secondary_editors: Option<EditorWindowState>,
+}
+
+/// The files and editor state for an additional editor window.
+#[derive(Default)]
+pub struct EditorWindowState {
+    pub selected_folder_index: Option<usize>,
+    pub open_skel: Option<usize>,
+    pub open_hlpb: Option<usize>,
+    pub open_matl: Option<usize>,
+    pub open_modl: Option<usize>,
+    pub open_mesh: Option<usize>,
+    pub open_adj: Option<usize>,
+    pub open_anim: Option<usize>,
+    pub open_meshex: Option<usize>,
+    pub open_nutexb: Option<usize>,
+
+    pub matl_editor: MatlEditorState,
+    pub anim_editor: AnimEditorState,
+    pub skel_editor: SkelEditorState,
+    pub hlpb_editor: HlpbEditorState,
+    pub mesh_editor: MeshEditorState,
+    pub modl_editor: ModlEditorState,
+}
+
+impl EditorWindowState {
+    /// Exchange the open files with `ui_state`.
+    /// This allows the main window's editor code to show the editors for this window.
+    fn swap(&mut self, ui_state: &mut UiState) {
+        use std::mem::swap;
+        swap(
+            &mut self.selected_folder_index,
+            &mut ui_state.selected_folder_index,
+        );
+        swap(&mut self.open_skel, &mut ui_state.open_skel);
+        swap(&mut self.open_hlpb, &mut ui_state.open_hlpb);
+        swap(&mut self.open_matl, &mut ui_state.open_matl);
+        swap(&mut self.open_modl, &mut ui_state.open_modl);
+        swap(&mut self.open_mesh, &mut ui_state.open_mesh);
+        swap(&mut self.open_adj, &mut ui_state.open_adj);
+        swap(&mut self.open_anim, &mut ui_state.open_anim);
+        swap(&mut self.open_meshex, &mut ui_state.open_meshex);
+        swap(&mut self.open_nutexb, &mut ui_state.open_nutexb);
+        swap(&mut self.matl_editor, &mut ui_state.matl_editor);
+        swap(&mut self.anim_editor, &mut ui_state.anim_editor);
+        // Share copied anim data between windows to paste into files opened in either window.
+        swap(
+            &mut self.anim_editor.track_clipboard,
+            &mut ui_state.anim_editor.track_clipboard,
+        );
+        swap(
+            &mut self.anim_editor.frame_range_clipboard,
+            &mut ui_state.anim_editor.frame_range_clipboard,
+        );
+        swap(&mut self.skel_editor, &mut ui_state.skel_editor);
+        swap(&mut self.hlpb_editor, &mut ui_state.hlpb_editor);
+        swap(&mut self.mesh_editor, &mut ui_state.mesh_editor);
+        swap(&mut self.modl_editor, &mut ui_state.modl_editor);
+    }
+}
+
+pub struct LogWindowState {
+    pub filter: String,
+    pub cleared_messages: Option<Vec<LogMessage>>,
+    pub show_timestamps: bool,
+    pub auto_scroll: bool,
+    pub previous_scroll_offset: f32,
+    pub show_debug: bool,
+    pub show_info: bool,
+    pub show_warn: bool,
+    pub show_error: bool,
+}
+
+impl Default for LogWindowState {
+    fn default() -> Self {
+        Self {
+            filter: String::new(),
+            cleared_messages: None,
+            show_timestamps: false,
+            auto_scroll: true,
+            previous_scroll_offset: 0.0,
+            show_debug: true,
+            show_info: true,
+            show_warn: true,
+            show_error: true,
+        }
+    }
+}
+
+impl LogWindowState {
+    pub fn is_level_visible(&self, level: &log::Level) -> bool {
+        match level {
+            log::Level::Error => self.show_error,
+            log::Level::Warn => self.show_warn,
+            log::Level::Info => self.show_info,
+            log::Level::Debug | log::Level::Trace => self.show_debug,
+        }
+    }
+}
+
+pub struct SplitViewportState {
+    /// The fraction of the viewport width used by the left pane.
+    pub fraction: f32,
+    /// The camera for the right pane.
+    pub camera_state: CameraState,
+    /// Apply camera input to the right pane instead of the left pane.
+    pub is_right_focused: bool,
+    /// The size of the right pane in physical pixels when the renderer was last resized.
+    pub previous_size: Option<(f32, f32)>,
+}
+
+impl Default for SplitViewportState {
+    fn default() -> Self {
+        Self {
+            fraction: 0.5,
+            camera_state: CameraState::default(),
+            is_right_focused: false,
+            previous_size: None,
+        }
+    }
+}
+
+pub struct GpuProgress {
+    /// The number of completed steps.
+    pub current: usize,
+    pub total: usize,
+    pub label: String,
+}
+
+/// Temporary messages shown in the corner of the window for [TOAST_DURATION] seconds.
+#[derive(Default)]
+pub struct ToastQueue {
+    /// The messages in the order they were added.
+    pub toasts: VecDeque<Toast>,
+}
+
+const TOAST_DURATION: f64 = 3.0;
+
+#[derive(Clone, Copy)]
+pub struct TreeFocusState {
+    pub selected_folder: usize,
+    /// The selected file or `None` if the folder itself is selected.
+    pub selected_file: Option<usize>,
+}
+
+#[derive(Default)]
+pub struct MeshEditorState {
+    /// A copy of [SsbhApp::selected_mesh] for the current frame.
+    pub selected_mesh: Option<String>,
+    /// The viewport visibility for each mesh object name and subindex in the current frame.
+    pub mesh_visibility: HashMap<(String, u64), bool>,
+    /// The mesh object name and subindex for the vertex buffer window.
+    pub vertex_buffer_mesh: Option<(String, u64)>,
+    /// The mesh object name for the index buffer window.
+    pub index_buffer: Option<IndexBufferState>,
+    /// Clicking the viewport paints vertex colors for the open mesh if enabled.
+    pub paint_mode: Option<PaintMode>,
+}
+
+#[derive(Clone, Default)]
+pub struct NutexbViewerState {
+    /// The folder and nutexb index for [NutexbViewerState::decoded_images].
+    pub decoded_key: Option<(usize, usize)>,
+    /// The RGBA data of the base mip level for each layer.
+    pub decoded_images: Vec<image::RgbaImage>,
+    /// The size of a texel in points or `None` to fit the texture to the viewer.
+    pub zoom: Option<f32>,
+    /// The offset of the texture center from the viewer center in points.
+    pub pan: egui::Vec2,
+    /// A PNG to import once the user confirms replacing a compressed format.
+    pub png_to_import: Option<PathBuf>,
+}
+
+pub struct IndexBufferState {
+    pub name: String,
+    /// The triangles for each mesh object with this name calculated when the window opened
+    /// or the mesh changed.
+    pub sections: Vec<IndexBufferSection>,
+}
+
+impl IndexBufferState {
+    pub fn new(name: String) -> Self {
+        Self {
+            name,
+            sections: Vec::new(),
+        }
+    }
+}
+
+pub struct IndexBufferSection {
+    pub subindex: u64,
+    pub vertex_count: usize,
+    pub triangles: Vec<[u32; 3]>,
+    /// The number of triangles with out of bounds vertex indices.
+    pub invalid_count: usize,
+}
+
+#[derive(Clone)]
+pub struct PaintMode {
+    /// The distance from the clicked vertex of the vertices to paint.
+    pub radius: f32,
+    pub color: [u8; 4],
+    /// The blend factor with the existing color at the clicked vertex.
+    pub strength: f32,
+}
+
+impl Default for PaintMode {
+    fn default() -> Self {
+        Self {
+            radius: 1.0,
+            color: [255; 4],
+            strength: 0.5,
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct HlpbEditorState {
+    /// Only show constraints with a bone name containing this text.
+    pub hlpb_filter: String,
+    /// Constraints read from JSON that will replace the current constraints once confirmed.
+    pub hlpb_to_import: Option<HlpbData>,
+    /// Bones missing from the skel that blocked the last save.
+    pub missing_bones: Option<Vec<String>>,
 }
 
 #[derive(Default)]
 pub struct SkelEditorState {
     pub mode: SkelMode,
+    /// A copy of [SsbhApp::selected_bone] for the current frame.
+    pub selected_bone: Option<String>,
+    /// Scroll to and expand the selected bone after selecting it in the viewport.
+    pub scroll_to_selected_bone: bool,
+    /// The bone to reset to its skeleton transform in the current animation frame.
+    pub bone_to_reset: Option<String>,
+    /// The world position of each bone in the current animation frame.
+    pub world_positions: Vec<glam::Vec3>,
+    pub copy_transforms: Option<CopyTransformsState>,
+}
+
+pub struct CopyTransformsState {
+    /// The skel to copy transforms from.
+    pub source: SkelData,
+    /// Whether to copy and the source bone index for each bone in the current skel.
+    pub mappings: Vec<(bool, Option<usize>)>,
 }
 
 #[derive(PartialEq, Eq)]
@@ -515,6 +831,42 @@ pub struct MatlEditorState {
     pub selected_preset_index: usize,
     pub preset_mode: PresetMode,
     pub texture_to_edit_index: Option<usize>,
+    pub swatch_sheet_to_render: Option<PathBuf>,
+    pub texture_reassign: Option<TextureReassignState>,
+    /// The folder and matl index and data for the loaded matl for resetting parameters.
+    /// Set to `None` after reloading files to snapshot the new data.
+    pub original_matl: Option<((usize, usize), MatlData)>,
+    /// The most recently applied preset for each material label.
+    pub applied_presets: HashMap<String, MatlEntryData>,
+    pub material_filter: String,
+    pub shader_filter: String,
+    /// The entry index and parameter to copy to entries with the same shader.
+    pub param_to_copy: Option<(usize, ParamId)>,
+    pub entries_to_import: Option<Vec<MatlEntryData>>,
+    /// The values for each frame of animated parameters keyed by material label and parameter name.
+    pub param_curves: HashMap<(String, String), Vec<Vec<f32>>>,
+    /// The animation track for animated parameters keyed by material label and parameter name.
+    pub animated_params: HashMap<(String, String), AnimTrackIndex>,
+    /// The colors for the XYZ components of animated parameter curves.
+    pub axis_colors: [egui::Color32; 3],
+    /// The selected folder and enabled slot animations used to calculate the animated parameters.
+    pub param_curves_key: Option<(usize, Vec<Option<AnimationIndex>>)>,
+    /// The track to select in the anim editor.
+    pub track_to_open: Option<AnimTrackIndex>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct AnimTrackIndex {
+    pub animation: AnimationIndex,
+    pub group_index: usize,
+    pub node_index: usize,
+    pub track_index: usize,
+}
+
+#[derive(Default)]
+pub struct TextureReassignState {
+    pub old_name: String,
+    pub new_name: String,
 }
 
 #[derive(Default)]
@@ -562,13 +914,68 @@ pub struct AnimEditorState {
     pub selected_group_index: Option<usize>,
     pub selected_node_index: Option<usize>,
     pub selected_track_index: Option<usize>,
+    pub keyframe_to_add: usize,
+    /// The track copied from the track list for pasting to other nodes.
+    pub track_clipboard: Option<TrackData>,
+    /// The (component, frame) of the keyframe point being dragged in the graph view.
+    pub dragged_keyframe: Option<(usize, usize)>,
+    /// Only show nodes with names containing this text.
+    pub node_filter: String,
+    pub hidden_group_types: Vec<GroupType>,
+    pub frame_range_start: usize,
+    pub frame_range_end: usize,
+    pub paste_target_frame: usize,
+    pub paste_shifts_frames: bool,
+    pub frame_range_clipboard: Option<FrameRangeClipboard>,
+    pub retarget: Option<RetargetState>,
+}
+
+pub struct RetargetState {
+    /// The bone names of the skel to retarget to.
+    pub target_bone_names: Vec<String>,
+    /// The anim transform node names and their target bone names.
+    pub mapping: Vec<(String, Option<String>)>,
+}
+
+impl RetargetState {
+    pub fn new(anim: &AnimData, target_bone_names: Vec<String>) -> Self {
+        let mut mapping: Vec<(String, Option<String>)> = Vec::new();
+        for node in anim
+            .groups
+            .iter()
+            .filter(|g| g.group_type == GroupType::Transform)
+            .flat_map(|g| g.nodes.iter())
+        {
+            if !mapping.iter().any(|(name, _)| *name == node.name) {
+                mapping.push((node.name.clone(), None));
+            }
+        }
+
+        let mut state = Self {
+            target_bone_names: Vec::new(),
+            mapping,
+        };
+        state.set_target_bones(target_bone_names);
+        state
+    }
+
+    /// Update the target bones and match bones with the same name by default.
+    pub fn set_target_bones(&mut self, target_bone_names: Vec<String>) {
+        for (name, target) in &mut self.mapping {
+            *target = target_bone_names.contains(name).then(|| name.clone());
+        }
+        self.target_bone_names = target_bone_names;
+    }
 }
 
 const ICON_SIZE: f32 = 18.0;
 const ICON_TEXT_SIZE: f32 = 14.0;
-pub const ERROR_COLOR: egui::Color32 = egui::Color32::from_rgb(240, 80, 80);
 pub const WARNING_COLOR: egui::Color32 = egui::Color32::from_rgb(255, 210, 0);
 
+const LEFT_PANEL_ID: &str = "left_panel";
+const RIGHT_PANEL_ID: &str = "right panel";
+const BOTTOM_PANEL_ID: &str = "bottom panel";
+
 // Keep track of what UI should be displayed.
 #[derive(PartialEq, Eq)]
 pub enum PanelTab {
@@ -596,6 +1003,11 @@ impl SsbhApp {
             self.clear_workspace();
         }
 
+        // Clear before loading to keep any errors for the new files.
+        if self.preferences.clear_log_on_load {
+            LOGGER.messages.lock().unwrap().clear();
+        }
+
         // Load recursively for nested folders like stages.
         let mut new_models = ssbh_wgpu::load_model_folders(&folder);
 
@@ -612,11 +1024,11 @@ impl SsbhApp {
                 {
                     // The model.nuanmb always plays, so assign it automatically.
                     vec![AnimationSlot {
-                        is_enabled: true,
                         animation: Some(AnimationIndex {
                             folder_index: self.models.len() + i,
                             anim_index,
                         }),
+                        ..AnimationSlot::new()
                     }]
                 } else {
                     // Add a dummy animation to prompt the user to select one.
@@ -638,6 +1050,7 @@ impl SsbhApp {
             let model_state = load_model(path, model);
             self.models.push(model_state);
         }
+        self.restore_slot_labels();
 
         self.sort_files();
 
@@ -684,9 +1097,14 @@ impl SsbhApp {
         // Reloaded models should have their animations applied.
         // This includes if the animation playback is paused.
         self.animation_state.should_update_animations = true;
+        self.ui_state.nutexb_viewer.decoded_key = None;
+        self.ui_state.matl_editor.param_curves_key = None;
+        self.ui_state.matl_editor.original_matl = None;
     }
 
     pub fn clear_workspace(&mut self) {
+        self.store_slot_labels();
+
         // TODO: Is it easier to have dedicated reset methods?
         self.models = Vec::new();
         self.render_actions
@@ -696,10 +1114,44 @@ impl SsbhApp {
         self.swing_state.hidden_collisions = Vec::new();
         self.camera_state.anim_path = None;
         self.render_actions.push_back(RenderAction::UpdateCamera);
+        self.ui_state.nutexb_viewer.decoded_key = None;
+        self.ui_state.matl_editor.param_curves_key = None;
+        self.ui_state.matl_editor.original_matl = None;
         // TODO: Reset selected indices?
         // TODO: Is there an easy way to write this?
     }
 
+    /// Save the animation slot labels for each folder to the project.
+    fn store_slot_labels(&mut self) {
+        for (model, slots) in self.models.iter().zip(&self.animation_state.animations) {
+            let key = model.folder_path.to_string_lossy().to_string();
+            if slots.iter().all(|s| s.label.is_empty()) {
+                self.project.slot_labels.remove(&key);
+            } else {
+                let labels = slots.iter().map(|s| s.label.clone()).collect();
+                self.project.slot_labels.insert(key, labels);
+            }
+        }
+    }
+
+    /// Apply the saved project labels to any unlabeled animation slots.
+    fn restore_slot_labels(&mut self) {
+        for (model, slots) in self
+            .models
+            .iter()
+            .zip(self.animation_state.animations.iter_mut())
+        {
+            let key = model.folder_path.to_string_lossy().to_string();
+            if let Some(labels) = self.project.slot_labels.get(&key) {
+                for (slot, label) in slots.iter_mut().zip(labels) {
+                    if slot.label.is_empty() {
+                        slot.label.clone_from(label);
+                    }
+                }
+            }
+        }
+    }
+
     fn sort_files(&mut self) {
         // Don't sort the files themselves so render models and animations stay in sync.
         for model in &mut self.models {
@@ -714,17 +1166,53 @@ impl SsbhApp {
         }
     }
 
-    fn update_model_thumbnails(&mut self, wgpu_state: &egui_wgpu::RenderState) {
+    /// Restore the saved camera when selecting a different folder.
+    fn update_per_folder_camera(&mut self) {
+        let Some(cameras) = self.per_folder_cameras.as_mut() else {
+            return;
+        };
+        cameras.resize(self.models.len(), self.camera_state.values.clone());
+
+        let selected = self.ui_state.selected_folder_index;
+        if selected != self.camera_folder_index {
+            if let Some(camera) = self.camera_folder_index.and_then(|i| cameras.get_mut(i)) {
+                *camera = self.camera_state.values.clone();
+            }
+            if let Some(camera) = selected.and_then(|i| cameras.get(i)) {
+                self.camera_state.values = camera.clone();
+                self.render_actions.push_back(RenderAction::UpdateCamera);
+            }
+            self.camera_folder_index = selected;
+        }
+    }
+
+    fn update_model_thumbnails(&mut self, ctx: &Context, wgpu_state: &egui_wgpu::RenderState) {
         if self.should_update_thumbnails {
-            for (i, model) in self.models.iter_mut().enumerate() {
-                let binding = &mut wgpu_state.renderer.write();
-                let render_state: &RenderState = binding.callback_resources.get().unwrap();
+            // Restart any thumbnails in progress since the models may have changed.
+            self.gpu_progress = Some(GpuProgress {
+                current: 0,
+                total: self.models.len(),
+                label: "Generating thumbnails".to_owned(),
+            });
+            self.should_update_thumbnails = false;
+        }
+
+        let Some(progress) = &mut self.gpu_progress else {
+            return;
+        };
 
+        // Generate thumbnails for one folder per frame to show the progress.
+        let i = progress.current;
+        if let Some(model) = self.models.get_mut(i) {
+            let binding = &mut wgpu_state.renderer.write();
+            let render_state: &RenderState = binding.callback_resources.get().unwrap();
+
+            if let Some(render_model) = render_state.render_models.get(i) {
                 // Split into two steps to avoid mutably and immutably borrowing egui renderer.
                 let thumbnails = generate_model_thumbnails(
                     binding,
                     &model.model,
-                    &render_state.render_models[i],
+                    render_model,
                     &wgpu_state.device,
                     &wgpu_state.queue,
                 );
@@ -741,7 +1229,139 @@ impl SsbhApp {
                     })
                     .collect();
             }
-            self.should_update_thumbnails = false;
+        }
+        progress.current += 1;
+
+        if progress.current >= progress.total.min(self.models.len()) {
+            if progress.total > 0 {
+                let message = format!(
+                    "Finished {} for {} folder(s)",
+                    progress.label.to_lowercase(),
+                    progress.total
+                );
+                show_toast(ctx, ToastLevel::Info, message);
+            }
+            self.gpu_progress = None;
+        } else {
+            // Keep working without waiting for user input.
+            ctx.request_repaint();
+        }
+    }
+
+    /// Show a draggable divider between the panes of the split viewport.
+    /// Returns the rects for the left and right panes.
+    fn split_viewport_divider(
+        &mut self,
+        ui: &mut Ui,
+        rect: egui::Rect,
+    ) -> (egui::Rect, egui::Rect) {
+        let divider_x = rect.left() + rect.width() * self.split_state.fraction;
+        let divider_rect =
+            egui::Rect::from_x_y_ranges(divider_x - 3.0..=divider_x + 3.0, rect.y_range());
+        let response = ui.interact(
+            divider_rect,
+            ui.id().with("split_viewport_divider"),
+            egui::Sense::drag(),
+        );
+        if response.hovered() || response.dragged() {
+            ui.ctx().set_cursor_icon(egui::CursorIcon::ResizeHorizontal);
+        }
+        if let Some(pos) = response
+            .interact_pointer_pos()
+            .filter(|_| response.dragged())
+        {
+            self.split_state.fraction = ((pos.x - rect.left()) / rect.width()).clamp(0.1, 0.9);
+        }
+
+        let left = egui::Rect::from_min_max(rect.min, egui::pos2(divider_x - 1.0, rect.max.y));
+        let right = egui::Rect::from_min_max(egui::pos2(divider_x + 1.0, rect.min.y), rect.max);
+        (left, right)
+    }
+
+    fn secondary_editor_window(&mut self, ctx: &Context, wgpu_state: &egui_wgpu::RenderState) {
+        let Some(mut window) = self.ui_state.secondary_editors.take() else {
+            return;
+        };
+
+        let mut open = true;
+        ctx.show_viewport_immediate(
+            egui::ViewportId::from_hash_of("secondary_editors"),
+            egui::ViewportBuilder::default()
+                .with_title("SSBH Editor (Second Window)")
+                .with_inner_size([800.0, 600.0]),
+            |ctx, _| {
+                if ctx.input(|i| i.viewport().close_requested()) {
+                    open = false;
+                }
+
+                // The window is drawn after this function returns.
+                // Release the lock before then to avoid a deadlock.
+                let binding = &mut wgpu_state.renderer.write();
+                let render_state: &mut RenderState = binding.callback_resources.get_mut().unwrap();
+
+                // The editors use the open files from the UI state.
+                window.swap(&mut self.ui_state);
+
+                SidePanel::left("secondary_files_list").show(ctx, |ui| {
+                    ScrollArea::vertical().show(ui, |ui| {
+                        for (i, model) in self.models.iter_mut().enumerate() {
+                            egui::CollapsingHeader::new(folder_display_name(model))
+                                .id_salt(egui::Id::new("secondary_folder").with(i))
+                                .default_open(true)
+                                .show(ui, |ui| {
+                                    show_folder_files(
+                                        &mut self.ui_state,
+                                        model,
+                                        ctx,
+                                        ui,
+                                        i,
+                                        None,
+                                        self.preferences.dark_mode,
+                                    );
+                                });
+                        }
+                    });
+                });
+                CentralPanel::default().show(ctx, |ui| {
+                    ui.label("Select a file to open its editor. Textures open in the main window.");
+                });
+
+                // TODO: Support rendering textures for more than one window.
+                let nutexb_to_open = self
+                    .ui_state
+                    .selected_folder_index
+                    .zip(self.ui_state.open_nutexb.take());
+
+                self.should_validate_models |= self.file_editors(ctx, render_state);
+
+                window.swap(&mut self.ui_state);
+
+                if let Some((folder_index, nutexb_index)) = nutexb_to_open {
+                    self.ui_state.selected_folder_index = Some(folder_index);
+                    self.ui_state.open_nutexb = Some(nutexb_index);
+                }
+            },
+        );
+
+        if open {
+            self.ui_state.secondary_editors = Some(window);
+        }
+    }
+
+    fn gpu_progress_bar(&self, ctx: &Context) {
+        if let Some(progress) = &self.gpu_progress {
+            egui::Area::new(egui::Id::new("gpu_progress"))
+                .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+                .show(ctx, |ui| {
+                    egui::Frame::popup(ui.style()).show(ui, |ui| {
+                        ui.set_width(300.0);
+                        let fraction = progress.current as f32 / progress.total.max(1) as f32;
+                        ui.add(egui::ProgressBar::new(fraction).text(format!(
+                            "{} ({}/{})",
+                            progress.label, progress.current, progress.total
+                        )));
+                    });
+                });
         }
     }
 
@@ -769,7 +1389,10 @@ impl eframe::App for SsbhApp {
         let device = &wgpu_state.device;
         let queue = &wgpu_state.queue;
 
-        self.update_model_thumbnails(wgpu_state);
+        self.update_model_thumbnails(ctx, wgpu_state);
+        self.update_per_folder_camera();
+        self.save_pending_captures(ctx, device);
+        self.secondary_editor_window(ctx, wgpu_state);
 
         // TODO: Create a function for updating rendering stuff?
         // Access all the rendering state from a single item in the type map.
@@ -780,6 +1403,13 @@ impl eframe::App for SsbhApp {
         // This can be set by the mesh list and mesh editor.
         // Clear every frame so both sources can set is_selected to true.
         render_state.clear_selected_meshes();
+        if let Some((folder_index, name)) = self
+            .ui_state
+            .selected_folder_index
+            .zip(self.selected_mesh.as_ref())
+        {
+            render_state.select_meshes(folder_index, name);
+        }
 
         // TODO: Rework these fields to use Option<T>.
         let mask_model_index = self.ui_state.selected_folder_index.unwrap_or(0);
@@ -793,16 +1423,24 @@ impl eframe::App for SsbhApp {
 
         if self.animation_state.is_playing {
             let final_frame_index = self.max_final_frame_index(render_state);
+            let (start, end) = self.animation_state.playback_range(final_frame_index);
 
+            // Play the range as if it were a separate animation starting at frame 0.
+            let current_frame = self.animation_state.current_frame.clamp(start, end);
             self.animation_state.current_frame = next_frame(
-                self.animation_state.current_frame,
+                current_frame - start,
                 current_frame_start.duration_since(self.animation_state.previous_frame_start),
-                final_frame_index,
+                end - start,
                 self.animation_state.playback_speed,
                 self.animation_state.should_loop,
-            );
+            ) + start;
             // eframe is reactive by default, so we need to repaint.
             ctx.request_repaint();
+        } else {
+            // Animations can be shortened in the anim editor.
+            let final_frame_index = self.max_final_frame_index(render_state);
+            self.animation_state.current_frame =
+                self.animation_state.current_frame.min(final_frame_index);
         }
         // Always update the frame times even if no animation is playing.
         // This avoids skipping when resuming playback.
@@ -820,6 +1458,7 @@ impl eframe::App for SsbhApp {
                 &render_state.texture_render_settings,
             );
         }
+        self.decode_viewer_nutexb(device, queue, render_state);
 
         ctx.input(|input| {
             for file in &input.raw.dropped_files {
@@ -850,11 +1489,14 @@ impl eframe::App for SsbhApp {
         render_settings_window(
             ctx,
             &mut render_state.render_settings,
+            &mut render_state.antialiasing,
             &mut render_state.model_render_options,
             &mut render_state.skinning_settings,
             &mut self.ui_state.render_settings_open,
             &mut self.draw_bone_names,
+            &mut self.show_bone_axes,
             &mut self.enable_helper_bones,
+            &mut self.ui_state.comparison_capture,
         );
         if self.ui_state.render_settings_open {
             self.render_actions
@@ -866,6 +1508,7 @@ impl eframe::App for SsbhApp {
             &mut self.ui_state.camera_settings_open,
             &mut self.camera_state,
             &mut self.preferences.default_camera,
+            &mut self.preferences.camera_bookmarks,
         ) {
             self.render_actions.push_back(RenderAction::UpdateCamera);
         }
@@ -874,6 +1517,7 @@ impl eframe::App for SsbhApp {
             ctx,
             &mut self.ui_state.device_info_window_open,
             &render_state.adapter_info,
+            render_state.antialiasing.sample_count(),
         );
 
         if stage_lighting_window(
@@ -884,7 +1528,39 @@ impl eframe::App for SsbhApp {
             self.render_actions.push_back(RenderAction::UpdateLighting);
         }
 
-        log_window(ctx, &mut self.ui_state.log_window_open);
+        log_window(
+            ctx,
+            &mut self.ui_state.log_window_open,
+            &mut self.ui_state.log_window,
+        );
+
+        folder_notes_window(ctx, &mut self.ui_state.notes_folder, &mut self.project);
+
+        let folder_name = self
+            .ui_state
+            .model_options_folder
+            .and_then(|i| self.models.get(i))
+            .map(folder_display_name)
+            .unwrap_or_default();
+        model_render_options_window(
+            ctx,
+            &mut self.ui_state.model_options_folder,
+            &folder_name,
+            &render_state.model_render_options,
+            &mut render_state.per_model_options,
+        );
+
+        validation_plugins_window(
+            ctx,
+            &mut self.ui_state.validation_plugins_open,
+            &self.validation_plugins,
+            &self.plugin_errors,
+            &self.models,
+        );
+
+        self.log_overflow_warning(ctx);
+        self.toasts(ctx);
+        self.gpu_progress_bar(ctx);
 
         if preferences_window(
             ctx,
@@ -892,9 +1568,13 @@ impl eframe::App for SsbhApp {
             &mut self.ui_state.preferences_window_open,
         ) {
             update_color_theme(&self.preferences, ctx);
+            update_text_styles(&self.preferences, ctx);
             self.render_actions
                 .push_back(RenderAction::UpdateClearColor);
             ctx.set_zoom_factor(self.preferences.scale_factor);
+            LOGGER
+                .max_messages
+                .store(self.preferences.max_log_messages, Ordering::Relaxed);
         } else {
             self.preferences.scale_factor = ctx.zoom_factor();
         }
@@ -918,25 +1598,50 @@ impl eframe::App for SsbhApp {
 
         self.should_validate_models |= self.file_editors(ctx, render_state);
 
+        if let Some(file) = self.ui_state.matl_editor.swatch_sheet_to_render.take() {
+            self.render_swatch_sheet(ctx, device, queue, render_state, &file);
+        }
+
+        if self.ui_state.should_reset_layout {
+            reset_panel_layout(ctx);
+            self.preferences.panel_layout = PanelLayout::default();
+            self.ui_state.should_reset_layout = false;
+        }
+
+        // Store the panel sizes after any resizing to restore them on the next launch.
+        let layout = self.preferences.panel_layout;
         if self.show_left_panel {
-            SidePanel::left("left_panel")
-                .default_width(200.0)
-                .show(ctx, |ui| self.files_list(ctx, ui));
+            let response = SidePanel::left(LEFT_PANEL_ID)
+                .default_width(layout.tree_width)
+                .show(ctx, |ui| {
+                    self.files_list(ctx, ui, &render_state.per_model_options)
+                });
+            self.preferences.panel_layout.tree_width = response.response.rect.width();
         }
 
         if self.show_bottom_panel {
-            TopBottomPanel::bottom("bottom panel")
+            let response = TopBottomPanel::bottom(BOTTOM_PANEL_ID)
+                .resizable(true)
+                .default_height(layout.timeline_height)
                 .show(ctx, |ui| self.bottom_panel(ui, render_state));
+            self.preferences.panel_layout.timeline_height = response.response.rect.height();
         }
 
         if self.show_right_panel {
-            SidePanel::right("right panel")
-                .default_width(450.0)
+            let response = SidePanel::right(RIGHT_PANEL_ID)
+                .default_width(layout.right_panel_width)
                 .show(ctx, |ui| self.right_panel(ctx, ui, render_state));
+            self.preferences.panel_layout.right_panel_width = response.response.rect.width();
         }
 
         CentralPanel::default().show(ctx, |ui| {
-            let rect = ui.available_rect_before_wrap();
+            let viewport_rect = ui.available_rect_before_wrap();
+            let (rect, split_rect) = if self.split_viewport {
+                let (left, right) = self.split_viewport_divider(ui, viewport_rect);
+                (left, Some(right))
+            } else {
+                (viewport_rect, None)
+            };
 
             // Convert logical points to physical pixels.
             let scale_factor = ctx.native_pixels_per_point().unwrap_or(1.0);
@@ -946,13 +1651,65 @@ impl eframe::App for SsbhApp {
             // It's possible to interact with the UI with the mouse over the viewport.
             // Disable tracking the mouse in this case to prevent unwanted camera rotations.
             // This mostly affects resizing the left and right side panels.
+            let mut clicked_pos = None;
+            let mut double_clicked_pos = None;
             if !ctx.wants_keyboard_input() && !ctx.wants_pointer_input() {
                 ctx.input(|input| {
+                    // Clicking a pane in the split viewport gives its camera focus.
+                    if let Some(split_rect) = split_rect {
+                        if let Some(pos) = input.pointer.press_origin() {
+                            if input.pointer.primary_pressed() {
+                                self.split_state.is_right_focused = split_rect.contains(pos);
+                            }
+                        }
+                    }
+
                     // Handle camera input here to get the viewport's actual size.
-                    handle_input(&mut self.camera_state, input, height);
+                    match split_rect.filter(|_| self.split_state.is_right_focused) {
+                        Some(split_rect) => handle_input(
+                            &mut self.split_state.camera_state,
+                            input,
+                            split_rect.height() * scale_factor,
+                        ),
+                        None => handle_input(&mut self.camera_state, input, height),
+                    }
+
+                    // Clicks aren't triggered after dragging to rotate the camera.
+                    if input.pointer.primary_clicked() {
+                        clicked_pos = input.pointer.interact_pos();
+                    }
+                    if input
+                        .pointer
+                        .button_double_clicked(egui::PointerButton::Primary)
+                    {
+                        double_clicked_pos = input.pointer.interact_pos();
+                    }
                 });
             }
 
+            if split_rect.is_none() {
+                // Free the GPU resources for the right pane.
+                render_state.split_renderer = None;
+            } else if render_state.split_renderer.is_none() {
+                render_state.split_renderer = Some(SsbhRenderer::new(
+                    device,
+                    queue,
+                    512,
+                    512,
+                    1.0,
+                    [0.0, 0.0, 0.0, 1.0],
+                    wgpu_state.target_format,
+                ));
+                self.split_state.previous_size = None;
+
+                // Apply the current settings to the new renderer.
+                self.render_actions
+                    .push_back(RenderAction::UpdateRenderSettings);
+                self.render_actions.push_back(RenderAction::UpdateLighting);
+                self.render_actions
+                    .push_back(RenderAction::UpdateClearColor);
+            }
+
             if width > 0.0 && height > 0.0 {
                 self.refresh_render_state(device, queue, render_state, width, height, 1.0);
 
@@ -970,6 +1727,13 @@ impl eframe::App for SsbhApp {
 
             // TODO: Avoid calculating the camera twice?
             let (_, _, _, mvp_matrix) = calculate_mvp(width, height, &self.camera_state.values);
+            // The split viewport shows the first half of the folders on the left.
+            let model_count = render_state.render_models.len();
+            let left_model_count = if split_rect.is_some() {
+                model_count.div_ceil(2)
+            } else {
+                model_count
+            };
             // TODO: Find a way to avoid clone?
             let cb = egui_wgpu::Callback::new_paint_callback(
                 rect,
@@ -980,10 +1744,98 @@ impl eframe::App for SsbhApp {
                     draw_bone_names: self.draw_bone_names,
                     mvp_matrix,
                     hidden_collisions: self.swing_state.hidden_collisions.clone(),
+                    model_range: 0..left_model_count,
+                    use_split_renderer: false,
                 },
             );
             ui.painter().add(cb);
 
+            if let Some(split_rect) = split_rect {
+                let split_width = split_rect.width() * scale_factor;
+                let split_height = split_rect.height() * scale_factor;
+                if split_width > 0.0 && split_height > 0.0 {
+                    self.update_split_renderer(
+                        device,
+                        queue,
+                        render_state,
+                        split_width,
+                        split_height,
+                    );
+
+                    let (_, _, _, mvp_matrix) = calculate_mvp(
+                        split_width,
+                        split_height,
+                        &self.split_state.camera_state.values,
+                    );
+                    let cb = egui_wgpu::Callback::new_paint_callback(
+                        split_rect,
+                        ViewportCallback {
+                            width: split_width,
+                            height: split_height,
+                            scale_factor,
+                            draw_bone_names: false,
+                            mvp_matrix,
+                            hidden_collisions: self.swing_state.hidden_collisions.clone(),
+                            model_range: left_model_count..model_count,
+                            use_split_renderer: true,
+                        },
+                    );
+                    ui.painter().add(cb);
+                }
+
+                // Draw the divider over both panes.
+                let divider_x = (rect.right() + split_rect.left()) / 2.0;
+                ui.painter().vline(
+                    divider_x,
+                    viewport_rect.y_range(),
+                    ui.visuals().widgets.noninteractive.bg_stroke,
+                );
+            }
+
+            // Selection only applies to the left pane of the split viewport.
+            let mut clicked_pos = clicked_pos.filter(|p| rect.contains(*p));
+
+            // Clicks paint vertex colors instead of selecting while painting.
+            if self.ui_state.open_mesh.is_some() {
+                if let Some(paint_mode) = self.ui_state.mesh_editor.paint_mode.clone() {
+                    if let Some(pos) = clicked_pos.take() {
+                        self.paint_viewport_mesh(render_state, rect, mvp_matrix, pos, &paint_mode);
+                    }
+                }
+            }
+
+            if render_state
+                .model_render_options_for(0..left_model_count)
+                .draw_bones
+            {
+                self.select_viewport_bone(ui, render_state, rect, mvp_matrix, clicked_pos);
+            }
+
+            if self.show_bone_axes {
+                self.draw_bone_axes(ui, render_state, rect, mvp_matrix);
+            }
+
+            if self.show_bounding_spheres {
+                self.draw_bounding_spheres(ui, render_state, rect, mvp_matrix);
+            }
+
+            if let Some(pos) = double_clicked_pos.filter(|p| rect.contains(*p)) {
+                self.select_viewport_mesh(render_state, rect, mvp_matrix, pos);
+            }
+
+            if self.preferences.show_shading_shortcuts
+                && shading_shortcut_bar(
+                    ctx,
+                    rect,
+                    &self.preferences.shading_shortcuts,
+                    &mut render_state.render_settings,
+                    &mut render_state.model_render_options,
+                )
+            {
+                self.render_actions
+                    .push_back(RenderAction::UpdateRenderSettings);
+            }
+
             // TODO: Run these on another thread?
             // TODO: Avoid clone?
             // TODO: This will be cleaner if the main renderer isn't mutated?
@@ -997,11 +1849,28 @@ impl eframe::App for SsbhApp {
                     height as u32,
                     file,
                     wgpu_state.target_format,
+                    None,
                 );
                 self.animation_gif_to_render = None;
                 render_state.update_clear_color(self.preferences.viewport_color);
             }
 
+            if let Some(file) = self.turntable_gif_to_render.take() {
+                let turntable = self.ui_state.turntable_capture;
+                render_animation_to_gif(
+                    self,
+                    device,
+                    queue,
+                    render_state,
+                    width as u32,
+                    height as u32,
+                    file,
+                    wgpu_state.target_format,
+                    Some(&turntable),
+                );
+                render_state.update_clear_color(self.preferences.viewport_color);
+            }
+
             if let Some(file) = self.animation_image_sequence_to_render.clone() {
                 render_animation_to_image_sequence(
                     self,
@@ -1017,19 +1886,110 @@ impl eframe::App for SsbhApp {
                 render_state.update_clear_color(self.preferences.viewport_color);
             }
 
-            if let Some(file) = &self.screenshot_to_render {
-                let image = render_screenshot(
+            if let Some(file) = self.animation_webm_to_render.take() {
+                render_animation_to_webm(
+                    ctx,
+                    self,
                     device,
                     queue,
                     render_state,
                     width as u32,
                     height as u32,
+                    file,
                     wgpu_state.target_format,
                 );
-                if let Err(e) = image.save(file) {
-                    error!("Error saving screenshot to {:?}: {}", file, e);
-                }
-                self.screenshot_to_render = None;
+                render_state.update_clear_color(self.preferences.viewport_color);
+            }
+
+            if let Some(output_dir) = self.multiangle_batch_to_render.take() {
+                let bookmarks = self.preferences.camera_bookmarks.clone();
+                if let Err(e) = render_multiangle_batch(
+                    self,
+                    &bookmarks,
+                    device,
+                    queue,
+                    render_state,
+                    width as u32,
+                    height as u32,
+                    wgpu_state.target_format,
+                    &output_dir,
+                ) {
+                    error!("Error saving batch screenshots to {:?}: {}", output_dir, e);
+                }
+                render_state.update_clear_color(self.preferences.viewport_color);
+            }
+
+            if let Some(file) = self.depth_screenshot_to_render.take() {
+                let (_, _, _, mvp_matrix) = calculate_mvp(width, height, &self.camera_state.values);
+                let meshes = self.depth_meshes(&render_state.render_models);
+                match render_depth_screenshot(
+                    device,
+                    queue,
+                    &meshes,
+                    mvp_matrix,
+                    width as u32,
+                    height as u32,
+                ) {
+                    Ok(image) => match image.save(&file) {
+                        Ok(()) => {
+                            let message = format!("Saved depth screenshot to {:?}", file);
+                            show_toast(ctx, ToastLevel::Info, message);
+                        }
+                        Err(e) => error!("Error saving depth screenshot to {:?}: {}", file, e),
+                    },
+                    Err(e) => error!("Error rendering depth screenshot to {:?}: {}", file, e),
+                }
+            }
+
+            if let Some(file) = self.comparison_screenshot_to_render.take() {
+                let comparison = &self.ui_state.comparison_capture;
+                if let (Some(settings_a), Some(settings_b)) =
+                    (&comparison.settings_a, &comparison.settings_b)
+                {
+                    let image = render_comparison_screenshot(
+                        device,
+                        queue,
+                        render_state,
+                        settings_a,
+                        settings_b,
+                        comparison.layout,
+                        comparison.split,
+                        width as u32,
+                        height as u32,
+                        wgpu_state.target_format,
+                    );
+                    if let Err(e) = image.save(&file) {
+                        error!("Error saving screenshot to {:?}: {}", file, e);
+                    }
+                }
+                render_state.update_clear_color(self.preferences.viewport_color);
+            }
+
+            if let Some(file) = self.screenshot_to_render.take() {
+                // Finish the capture on a later frame to keep the UI responsive.
+                let capture = match self.preferences.screenshot_resolution {
+                    Some(resolution) => submit_screenshot_at_resolution(
+                        self,
+                        device,
+                        queue,
+                        render_state,
+                        resolution,
+                        width as u32,
+                        height as u32,
+                        wgpu_state.target_format,
+                        file,
+                    ),
+                    None => submit_screenshot(
+                        device,
+                        queue,
+                        render_state,
+                        width as u32,
+                        height as u32,
+                        wgpu_state.target_format,
+                        file,
+                    ),
+                };
+                self.pending_captures.push(capture);
                 render_state.update_clear_color(self.preferences.viewport_color);
             }
         });
@@ -1042,9 +2002,329 @@ impl eframe::App for SsbhApp {
         }
 
         self.preferences.write_to_file();
+
+        self.store_slot_labels();
+        self.project
+            .frame_markers
+            .clone_from(&self.animation_state.frame_markers);
+        self.project.write_to_file();
     }
 }
 
+impl SsbhApp {
+    fn draw_bone_axes(
+        &self,
+        ui: &mut Ui,
+        render_state: &RenderState,
+        rect: egui::Rect,
+        mvp_matrix: glam::Mat4,
+    ) {
+        let lines = bone_axes_screen_lines(
+            &self.models,
+            &render_state.render_models,
+            rect,
+            mvp_matrix,
+            self.preferences.bone_axes_length,
+            self.preferences.color_blind_mode.palette().axes,
+            |folder_index, skel| self.animated_world_transforms(folder_index, skel),
+        );
+
+        let painter = ui.painter_at(rect);
+        for (points, color) in lines {
+            painter.line_segment(points, egui::Stroke::new(1.0, color));
+        }
+    }
+
+    fn draw_bounding_spheres(
+        &mut self,
+        ui: &mut Ui,
+        render_state: &RenderState,
+        rect: egui::Rect,
+        mvp_matrix: glam::Mat4,
+    ) {
+        let selected = self
+            .ui_state
+            .selected_folder_index
+            .zip(self.selected_mesh.as_deref());
+        let lines = bounding_sphere_screen_lines(
+            &mut self.models,
+            &render_state.render_models,
+            rect,
+            mvp_matrix,
+            selected,
+        );
+
+        let painter = ui.painter_at(rect);
+        let stroke = egui::Stroke::new(1.0, ui.visuals().selection.stroke.color);
+        for points in lines {
+            painter.line_segment(points, stroke);
+        }
+    }
+
+    fn select_viewport_mesh(
+        &mut self,
+        render_state: &RenderState,
+        rect: egui::Rect,
+        mvp_matrix: glam::Mat4,
+        pos: egui::Pos2,
+    ) {
+        // Clicking empty space clears the selection.
+        self.selected_mesh = match pick_mesh(
+            &mut self.models,
+            &render_state.render_models,
+            rect,
+            mvp_matrix,
+            pos,
+        ) {
+            Some((folder_index, name)) => {
+                // The mesh editor only shows the selected folder.
+                self.ui_state.selected_folder_index = Some(folder_index);
+                Some(name)
+            }
+            None => None,
+        };
+    }
+
+    /// Decode the texture open in the nutexb viewer to RGBA for reading pixel values.
+    fn decode_viewer_nutexb(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        render_state: &RenderState,
+    ) {
+        let Some(key) = self
+            .ui_state
+            .selected_folder_index
+            .zip(self.ui_state.open_nutexb)
+        else {
+            return;
+        };
+
+        let state = &mut self.ui_state.nutexb_viewer;
+        if state.decoded_key != Some(key) {
+            state.decoded_images = self
+                .models
+                .get(key.0)
+                .and_then(|m| m.model.nutexbs.get(key.1))
+                .and_then(|(name, nutexb)| Some((name, nutexb.as_ref().ok()?)))
+                .map(|(name, nutexb)| {
+                    render_nutexb_to_images(device, queue, &render_state.texture_renderer, nutexb)
+                        .unwrap_or_else(|e| {
+                            error!("Failed to decode {:?}: {}", name, e);
+                            Vec::new()
+                        })
+                })
+                .unwrap_or_default();
+            state.decoded_key = Some(key);
+            state.zoom = None;
+            state.pan = egui::Vec2::ZERO;
+        }
+    }
+
+    fn paint_viewport_mesh(
+        &mut self,
+        render_state: &RenderState,
+        rect: egui::Rect,
+        mvp_matrix: glam::Mat4,
+        pos: egui::Pos2,
+        paint_mode: &PaintMode,
+    ) {
+        // Only paint the mesh open in the mesh editor.
+        let Some(folder_index) = self.ui_state.selected_folder_index else {
+            return;
+        };
+        let Some(mesh_index) = self.ui_state.open_mesh else {
+            return;
+        };
+        let Some(model) = self.models.get_mut(folder_index) else {
+            return;
+        };
+
+        // Skip the triangle tests for meshes the ray can't hit.
+        let (origin, direction) = screen_ray(mvp_matrix.inverse(), pos, rect);
+        let candidate_meshes: Vec<_> = render_state
+            .render_models
+            .get(folder_index)
+            .map(|r| {
+                model
+                    .mesh_bounds()
+                    .iter()
+                    .filter(|b| {
+                        r.meshes
+                            .iter()
+                            .any(|m| m.name == b.name && m.subindex == b.subindex && m.is_visible)
+                    })
+                    .filter(|b| b.intersect_ray(origin, direction).is_some())
+                    .map(|b| (b.name.clone(), b.subindex))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let Some((_, Ok(mesh))) = model.model.meshes.get_mut(mesh_index) else {
+            return;
+        };
+
+        if paint_vertex_colors(mesh, &candidate_meshes, origin, direction, paint_mode) {
+            if let Some(changed) = model.changed.meshes.get_mut(mesh_index) {
+                *changed = true;
+            }
+            model.clear_mesh_bounds();
+
+            // Upload the modified vertex buffers at most once per frame.
+            // ssbh_wgpu only supports updating vertex data by recreating the render model.
+            let action = RenderAction::Model(RenderModelAction::Update(folder_index));
+            if !self.render_actions.contains(&action) {
+                self.render_actions.push_back(action);
+            }
+        }
+    }
+
+    fn select_viewport_bone(
+        &mut self,
+        ui: &mut Ui,
+        render_state: &RenderState,
+        rect: egui::Rect,
+        mvp_matrix: glam::Mat4,
+        clicked_pos: Option<egui::Pos2>,
+    ) {
+        let bones = bone_screen_positions(
+            &self.models,
+            &render_state.render_models,
+            rect,
+            mvp_matrix,
+            |folder_index, skel| self.animated_world_positions(folder_index, skel),
+        );
+
+        if let Some(clicked_pos) = clicked_pos.filter(|p| rect.contains(*p)) {
+            // Select the closest bone to the cursor if it's near enough.
+            let selection_radius = 12.0;
+            self.selected_bone = bones
+                .iter()
+                .map(|b| (&b.name, b.position.distance(clicked_pos)))
+                .filter(|(_, distance)| *distance <= selection_radius)
+                .min_by(|(_, a), (_, b)| a.total_cmp(b))
+                .map(|(name, _)| name.clone());
+            self.ui_state.skel_editor.scroll_to_selected_bone = self.selected_bone.is_some();
+        }
+
+        if let Some(selected_bone) = &self.selected_bone {
+            for bone in bones.iter().filter(|b| &b.name == selected_bone) {
+                let stroke = egui::Stroke::new(2.0, egui::Color32::YELLOW);
+                ui.painter().circle_stroke(bone.position, 8.0, stroke);
+                if let Some(parent_position) = bone.parent_position {
+                    bone_pyramid(ui.painter(), parent_position, bone.position, stroke);
+                }
+            }
+        }
+    }
+}
+
+/// Draw a pyramid pointing from the parent bone to the child bone in screen space.
+fn bone_pyramid(
+    painter: &egui::Painter,
+    parent_position: egui::Pos2,
+    position: egui::Pos2,
+    stroke: egui::Stroke,
+) {
+    let direction = position - parent_position;
+    let length = direction.length();
+    if length < 1.0 {
+        return;
+    }
+
+    // Scale the base with the bone length so short bones remain visible.
+    let half_width = (length * 0.15).clamp(2.0, 8.0);
+    let offset = direction.normalized().rot90() * half_width;
+    let base = parent_position + direction * 0.2;
+    painter.add(egui::Shape::convex_polygon(
+        vec![parent_position, base + offset, position, base - offset],
+        stroke.color.gamma_multiply(0.25),
+        stroke,
+    ));
+}
+
+impl SsbhApp {
+    /// Save any screenshots that finished rendering since the last frame.
+    fn save_pending_captures(&mut self, ctx: &Context, device: &wgpu::Device) {
+        if self.pending_captures.is_empty() {
+            return;
+        }
+
+        device.poll(wgpu::Maintain::Poll);
+
+        self.pending_captures
+            .retain(|capture| !capture.try_save(ctx));
+
+        // Keep checking for completion without waiting for user input.
+        ctx.request_repaint();
+    }
+
+    fn toasts(&mut self, ctx: &Context) {
+        let time = ctx.input(|i| i.time);
+        let toasts = &mut self.ui_state.toasts.toasts;
+
+        // Widgets can also show messages without access to the app state.
+        toasts.extend(take_toasts(ctx));
+
+        // Toasts are added in order, so the oldest messages expire first.
+        while toasts
+            .front()
+            .is_some_and(|toast| time - toast.time >= TOAST_DURATION)
+        {
+            toasts.pop_front();
+        }
+
+        // Show temporary messages in the corner of the window.
+        if let Some(start_time) = toasts.front().map(|toast| toast.time) {
+            egui::Area::new(egui::Id::new("toasts"))
+                .anchor(egui::Align2::RIGHT_BOTTOM, [-8.0, -88.0])
+                .show(ctx, |ui| {
+                    for toast in toasts.iter() {
+                        egui::Frame::popup(ui.style()).show(ui, |ui| {
+                            let message = &toast.message;
+                            let text = match toast.level {
+                                ToastLevel::Info => RichText::new(message),
+                                ToastLevel::Warning => warning_icon_text(message),
+                                ToastLevel::Error => RichText::new("⚠ ".to_string() + message)
+                                    .color(ui.visuals().error_fg_color),
+                            };
+                            ui.label(text);
+                        });
+                    }
+                });
+            ctx.request_repaint_after(std::time::Duration::from_secs_f64(
+                TOAST_DURATION - (time - start_time),
+            ));
+        }
+    }
+
+    fn log_overflow_warning(&self, ctx: &Context) {
+        if LOGGER.take_overflow() {
+            show_toast(
+                ctx,
+                ToastLevel::Warning,
+                "The application log is full. The oldest messages will be removed.",
+            );
+        }
+    }
+}
+
+/// The id for the folder's collapsing state.
+/// The folder header and [SsbhApp::handle_tree_input] must use the same id
+/// so keyboard navigation sees the folders the user expanded or collapsed.
+fn folder_collapsing_id(folder_index: usize) -> egui::Id {
+    egui::Id::new("folder").with(folder_index)
+}
+
+fn reset_panel_layout(ctx: &Context) {
+    // Panels only use their default size if there is no stored state.
+    ctx.data_mut(|d| {
+        for id in [LEFT_PANEL_ID, RIGHT_PANEL_ID, BOTTOM_PANEL_ID] {
+            d.remove::<egui::panel::PanelState>(egui::Id::new(id));
+        }
+    });
+}
+
 // TODO: Create a separate module for input handling?
 fn handle_input(camera: &mut CameraState, input: &egui::InputState, viewport_height: f32) {
     // Assume zero deltas if no updates are needed.
@@ -1094,6 +2374,10 @@ struct ViewportCallback {
     draw_bone_names: bool,
     mvp_matrix: glam::Mat4,
     hidden_collisions: Vec<HashSet<u64>>,
+    /// The indices of the render models to draw.
+    model_range: std::ops::Range<usize>,
+    /// Draw with [RenderState::split_renderer] for the right pane of the split viewport.
+    use_split_renderer: bool,
 }
 
 impl CallbackTrait for ViewportCallback {
@@ -1107,19 +2391,32 @@ impl CallbackTrait for ViewportCallback {
     ) -> Vec<wgpu::CommandBuffer> {
         let state: &mut RenderState = callback_resources.get_mut().unwrap();
 
-        state.renderer.begin_render_models(
+        let options = state.model_render_options_for(self.model_range.clone());
+        let render_models = state
+            .render_models
+            .get(self.model_range.clone())
+            .unwrap_or_default();
+        let renderer = if self.use_split_renderer {
+            match state.split_renderer.as_mut() {
+                Some(renderer) => renderer,
+                None => return Vec::new(),
+            }
+        } else {
+            &mut state.renderer
+        };
+        renderer.begin_render_models(
             egui_encoder,
-            &state.render_models,
+            render_models,
             state.shared_data.database(),
-            &state.model_render_options,
+            &options,
         );
 
         // TODO: Make the font size configurable.
-        if state.model_render_options.draw_bones && self.draw_bone_names {
+        if options.draw_bones && self.draw_bone_names {
             state.bone_name_renderer.prepare(
                 device,
                 queue,
-                &state.render_models,
+                render_models,
                 self.width as u32,
                 self.height as u32,
                 self.mvp_matrix,
@@ -1137,19 +2434,31 @@ impl CallbackTrait for ViewportCallback {
         callback_resources: &egui_wgpu::CallbackResources,
     ) {
         let state: &RenderState = callback_resources.get().unwrap();
-        state.renderer.end_render_models(render_pass);
+        let renderer = if self.use_split_renderer {
+            match state.split_renderer.as_ref() {
+                Some(renderer) => renderer,
+                None => return,
+            }
+        } else {
+            &state.renderer
+        };
+        renderer.end_render_models(render_pass);
 
         for (render_model, hidden_collisions) in state
             .render_models
             .iter()
             .zip(self.hidden_collisions.iter())
+            .skip(self.model_range.start)
+            .take(self.model_range.len())
         {
-            state
-                .renderer
-                .render_swing(render_pass, render_model, hidden_collisions);
+            renderer.render_swing(render_pass, render_model, hidden_collisions);
         }
 
-        if state.model_render_options.draw_bones && self.draw_bone_names {
+        if state
+            .model_render_options_for(self.model_range.clone())
+            .draw_bones
+            && self.draw_bone_names
+        {
             state.bone_name_renderer.render(render_pass);
         }
     }
@@ -1159,6 +2468,15 @@ impl SsbhApp {
     fn file_editors(&mut self, ctx: &Context, render_state: &mut RenderState) -> bool {
         let mut file_changed = false;
 
+        if self.ui_state.open_skel.is_some() {
+            self.ui_state.skel_editor.world_positions = self.skel_world_positions();
+        }
+        if self.ui_state.open_matl.is_some() {
+            self.update_animated_material_params();
+            self.ui_state.matl_editor.axis_colors =
+                self.preferences.color_blind_mode.palette().axes;
+        }
+
         // TODO: Use some sort of trait to clean up repetitive code?
         // The functions would take an additional ui parameter.
         if let Some(folder_index) = self.ui_state.selected_folder_index {
@@ -1170,6 +2488,7 @@ impl SsbhApp {
                             ctx,
                             &model.folder_path,
                             name,
+                            (folder_index, matl_index),
                             &mut self.ui_state.matl_editor,
                             matl,
                             find_file_mut(&mut model.model.modls, "model.numdlb"),
@@ -1192,6 +2511,18 @@ impl SsbhApp {
                             self.ui_state.open_matl = None;
                         }
 
+                        if let Some(track) = self.ui_state.matl_editor.track_to_open.take() {
+                            // The anim editor only shows files from the selected folder.
+                            self.ui_state.selected_folder_index =
+                                Some(track.animation.folder_index);
+                            self.ui_state.open_anim = Some(track.animation.anim_index);
+                            self.ui_state.anim_editor.selected_group_index =
+                                Some(track.group_index);
+                            self.ui_state.anim_editor.selected_node_index = Some(track.node_index);
+                            self.ui_state.anim_editor.selected_track_index =
+                                Some(track.track_index);
+                        }
+
                         // Update on change to avoid costly state changes every frame.
                         if response.changed {
                             // Only the model.numatb is rendered in the viewport for now.
@@ -1211,20 +2542,54 @@ impl SsbhApp {
                     }
                 }
 
+                self.ui_state
+                    .mesh_editor
+                    .selected_mesh
+                    .clone_from(&self.selected_mesh);
+                if self.ui_state.open_mesh.is_some() {
+                    self.ui_state.mesh_editor.mesh_visibility = render_state
+                        .render_models
+                        .get(folder_index)
+                        .map(|r| {
+                            r.meshes
+                                .iter()
+                                .map(|m| ((m.name.clone(), m.subindex), m.is_visible))
+                                .collect()
+                        })
+                        .unwrap_or_default();
+                }
                 if open_editor::<MeshData>(
                     ctx,
                     model,
                     &mut self.ui_state.open_mesh,
-                    &mut (),
+                    &mut self.ui_state.mesh_editor,
                     &mut self.render_actions,
                     self.preferences.dark_mode,
                 ) {
                     // The mesh editor has no high frequency edits (sliders), so reload on any change.
                     self.render_actions
                         .push_back(RenderAction::Model(RenderModelAction::Update(folder_index)));
+                    model.clear_mesh_bounds();
                     file_changed = true;
                 }
+                self.selected_mesh
+                    .clone_from(&self.ui_state.mesh_editor.selected_mesh);
+                if let Some(render_model) = render_state.render_models.get_mut(folder_index) {
+                    for ((name, subindex), visible) in &self.ui_state.mesh_editor.mesh_visibility {
+                        for mesh in render_model
+                            .meshes
+                            .iter_mut()
+                            .filter(|m| &m.name == name && m.subindex == *subindex)
+                        {
+                            mesh.is_visible = *visible;
+                        }
+                    }
+                }
 
+                self.ui_state
+                    .skel_editor
+                    .selected_bone
+                    .clone_from(&self.selected_bone);
                 file_changed |= open_editor::<SkelData>(
                     ctx,
                     model,
@@ -1233,6 +2598,8 @@ impl SsbhApp {
                     &mut self.render_actions,
                     self.preferences.dark_mode,
                 );
+                self.selected_bone
+                    .clone_from(&self.ui_state.skel_editor.selected_bone);
 
                 if open_editor::<ModlData>(
                     ctx,
@@ -1257,7 +2624,7 @@ impl SsbhApp {
                     ctx,
                     model,
                     &mut self.ui_state.open_hlpb,
-                    &mut (),
+                    &mut self.ui_state.hlpb_editor,
                     &mut self.render_actions,
                     self.preferences.dark_mode,
                 ) {
@@ -1285,6 +2652,7 @@ impl SsbhApp {
                 ) {
                     // Reapply the animations in the viewport.
                     self.animation_state.should_update_animations = true;
+                    self.ui_state.matl_editor.param_curves_key = None;
                     file_changed = true;
                 }
 
@@ -1303,24 +2671,285 @@ impl SsbhApp {
                 }
 
                 if let Some(nutexb_index) = self.ui_state.open_nutexb {
-                    if let Some((name, Ok(nutexb))) = model.model.nutexbs.get(nutexb_index) {
-                        if !nutexb_viewer(
+                    if let Some((name, Ok(nutexb))) = model.model.nutexbs.get_mut(nutexb_index) {
+                        let response = nutexb_viewer(
                             ctx,
-                            &folder_editor_title(&model.folder_path, name),
+                            &model.folder_path,
+                            name,
                             nutexb,
                             &mut render_state.texture_render_settings,
-                        ) {
+                            &mut self.ui_state.nutexb_viewer,
+                        );
+                        if !response.open {
                             // Close the window.
                             self.ui_state.open_nutexb = None;
                         }
+
+                        if response.changed {
+                            // Imported image data requires recreating the GPU texture.
+                            self.render_actions.push_back(RenderAction::Model(
+                                RenderModelAction::Update(folder_index),
+                            ));
+                            self.ui_state.nutexb_viewer.decoded_key = None;
+                            self.should_update_thumbnails = true;
+                            file_changed = true;
+                        }
+
+                        response.set_changed(&mut model.changed.nutexbs[nutexb_index]);
                     }
                 }
             }
+
+            if let Some(bone_name) = self.ui_state.skel_editor.bone_to_reset.take() {
+                file_changed |= self.reset_bone_transform(folder_index, &bone_name);
+            }
         }
 
         file_changed
     }
 
+    fn render_swatch_sheet(
+        &self,
+        ctx: &Context,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        render_state: &RenderState,
+        file: &Path,
+    ) {
+        let Some(folder_index) = self.ui_state.selected_folder_index else {
+            return;
+        };
+        let matl = self
+            .models
+            .get(folder_index)
+            .zip(self.ui_state.open_matl)
+            .and_then(|(m, i)| m.model.matls.get(i))
+            .and_then(|(_, m)| m.as_ref().ok());
+
+        if let Some(matl) = matl {
+            let render_swatch = |entry: &MatlEntryData, size| {
+                render_material_swatch(device, queue, render_state, folder_index, entry, size)
+            };
+            match export_matl_swatch_sheet(matl, render_swatch, file, 128) {
+                Ok(()) => {
+                    let message = format!("Saved swatch sheet to {:?}", file);
+                    show_toast(ctx, ToastLevel::Info, message);
+                }
+                Err(e) => error!("Error saving swatch sheet to {:?}: {}", file, e),
+            }
+        }
+    }
+
+    /// The world position of each bone in the open skel using the current frame of the enabled animations.
+    fn skel_world_positions(&self) -> Vec<glam::Vec3> {
+        let folder_index = self.ui_state.selected_folder_index;
+        folder_index
+            .zip(self.ui_state.open_skel)
+            .and_then(|(folder_index, skel_index)| {
+                let (_, skel) = self.models.get(folder_index)?.model.skels.get(skel_index)?;
+                let skel = skel.as_ref().ok()?;
+                Some(self.animated_world_positions(folder_index, skel))
+            })
+            .unwrap_or_default()
+    }
+
+    /// The loaded anims for the enabled animation slots of `folder_index`.
+    ///
+    /// Later slots take priority like in the viewport,
+    /// so collecting values from this iterator keeps the value from the last slot.
+    fn enabled_slot_anims(
+        &self,
+        folder_index: usize,
+    ) -> impl Iterator<Item = (&AnimationSlot, AnimationIndex, &AnimData)> {
+        self.animation_state
+            .animations
+            .get(folder_index)
+            .into_iter()
+            .flatten()
+            .filter(|s| s.is_enabled)
+            .filter_map(|slot| {
+                let animation = slot.animation?;
+                let (_, anim) = animation.get_animation(&self.models)?;
+                Some((slot, animation, anim.as_ref().ok()?))
+            })
+    }
+
+    /// Recalculate the animated material parameters if the selected folder or its slots changed.
+    fn update_animated_material_params(&mut self) {
+        let Some(folder_index) = self.ui_state.selected_folder_index else {
+            return;
+        };
+        let slots = self
+            .animation_state
+            .animations
+            .get(folder_index)
+            .map(|slots| {
+                slots
+                    .iter()
+                    .map(|s| s.animation.filter(|_| s.is_enabled))
+                    .collect()
+            })
+            .unwrap_or_default();
+        let key = (folder_index, slots);
+
+        if self.ui_state.matl_editor.param_curves_key.as_ref() != Some(&key) {
+            self.ui_state.matl_editor.param_curves = self.material_param_curves();
+            self.ui_state.matl_editor.animated_params = self.animated_material_params();
+            self.ui_state.matl_editor.param_curves_key = Some(key);
+        }
+    }
+
+    /// The material parameter curves from the enabled animations for the selected folder.
+    fn material_param_curves(&self) -> HashMap<(String, String), Vec<Vec<f32>>> {
+        self.ui_state
+            .selected_folder_index
+            .into_iter()
+            .flat_map(|i| self.enabled_slot_anims(i))
+            .flat_map(|(_, _, anim)| material_param_curves(anim))
+            .collect()
+    }
+
+    /// The material parameter tracks from the enabled animations for the selected folder.
+    fn animated_material_params(&self) -> HashMap<(String, String), AnimTrackIndex> {
+        self.ui_state
+            .selected_folder_index
+            .into_iter()
+            .flat_map(|i| self.enabled_slot_anims(i))
+            .flat_map(|(_, animation, anim)| {
+                material_track_indices(anim).into_iter().map(
+                    move |(key, [group_index, node_index, track_index])| {
+                        let track = AnimTrackIndex {
+                            animation,
+                            group_index,
+                            node_index,
+                            track_index,
+                        };
+                        (key, track)
+                    },
+                )
+            })
+            .collect()
+    }
+
+    /// The world position of each bone in `skel` using the current frame of the enabled animations.
+    fn animated_world_positions(&self, folder_index: usize, skel: &SkelData) -> Vec<glam::Vec3> {
+        self.animated_world_transforms(folder_index, skel)
+            .iter()
+            .map(|t| t.col(3).truncate())
+            .collect()
+    }
+
+    /// The visible mesh objects posed using the current frame of the enabled animations.
+    fn depth_meshes(&self, render_models: &[RenderModel]) -> Vec<DepthMesh> {
+        self.models
+            .iter()
+            .zip(render_models)
+            .enumerate()
+            .filter(|(_, (_, render_model))| render_model.is_visible)
+            .flat_map(|(folder_index, (model, render_model))| {
+                let mesh = model
+                    .model
+                    .meshes
+                    .iter()
+                    .find(|(f, _)| f == "model.numshb")
+                    .and_then(|(_, m)| m.as_ref().ok());
+                let skel = model.model.find_skel();
+                let (bind_transforms, world_transforms) = skel
+                    .map(|skel| {
+                        let local_transforms: Vec<_> = skel
+                            .bones
+                            .iter()
+                            .map(|b| glam::Mat4::from_cols_array_2d(&b.transform))
+                            .collect();
+                        (
+                            bone_world_transforms(skel, &local_transforms),
+                            self.animated_world_transforms(folder_index, skel),
+                        )
+                    })
+                    .unwrap_or_default();
+
+                mesh.into_iter()
+                    .flat_map(|mesh| &mesh.objects)
+                    .filter(|o| {
+                        render_model
+                            .meshes
+                            .iter()
+                            .any(|m| m.name == o.name && m.subindex == o.subindex && m.is_visible)
+                    })
+                    .map(|o| DepthMesh {
+                        positions: skinned_positions(o, skel, &bind_transforms, &world_transforms),
+                        vertex_indices: o.vertex_indices.clone(),
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    /// The world transform of each bone in `skel` using the current frame of the enabled animations.
+    fn animated_world_transforms(&self, folder_index: usize, skel: &SkelData) -> Vec<glam::Mat4> {
+        let local_transforms: Vec<_> = skel
+            .bones
+            .iter()
+            .map(|bone| {
+                self.animated_bone_transform(folder_index, &bone.name)
+                    .unwrap_or_else(|| glam::Mat4::from_cols_array_2d(&bone.transform))
+            })
+            .collect();
+        bone_world_transforms(skel, &local_transforms)
+    }
+
+    fn animated_bone_transform(&self, folder_index: usize, bone_name: &str) -> Option<glam::Mat4> {
+        if self.animation_state.force_tpose {
+            return None;
+        }
+
+        self.enabled_slot_anims(folder_index)
+            .filter_map(|(slot, _, anim)| {
+                let frame =
+                    slot.slot_frame(self.animation_state.current_frame, anim.final_frame_index);
+                sample_bone_transform(anim, bone_name, frame)
+            })
+            .last()
+    }
+
+    /// Set the current frame for `bone_name` in the enabled animations to the skel's transform.
+    fn reset_bone_transform(&mut self, folder_index: usize, bone_name: &str) -> bool {
+        let Some(transform) = self
+            .models
+            .get(folder_index)
+            .and_then(|m| m.model.find_skel())
+            .and_then(|skel| skel.bones.iter().find(|b| b.name == bone_name))
+            .map(|bone| bone.transform)
+        else {
+            return false;
+        };
+
+        let frame = self.animation_state.current_frame.floor() as usize;
+
+        let mut changed = false;
+        if let Some(slots) = self.animation_state.animations.get(folder_index) {
+            for anim_index in slots
+                .iter()
+                .filter(|s| s.is_enabled)
+                .filter_map(|s| s.animation)
+            {
+                if let Some(model) = self.models.get_mut(anim_index.folder_index) {
+                    if let Some((_, Ok(anim))) = model.model.anims.get_mut(anim_index.anim_index) {
+                        if set_bone_transform_keyframe(anim, bone_name, frame, transform) {
+                            model.changed.anims[anim_index.anim_index] = true;
+                            changed = true;
+                        }
+                    }
+                }
+            }
+        }
+
+        if changed {
+            self.animation_state.should_update_animations = true;
+        }
+        changed
+    }
+
     pub fn max_final_frame_index(&self, render_state: &RenderState) -> f32 {
         // Find the minimum number of frames to cover all animations.
         // This should include stage animations like lighting and cameras.
@@ -1352,34 +2981,126 @@ impl SsbhApp {
             .fold(0.0, f32::max)
     }
 
-    fn files_list(&mut self, ctx: &Context, ui: &mut Ui) {
-        ui.heading("Files");
+    fn files_list(
+        &mut self,
+        ctx: &Context,
+        ui: &mut Ui,
+        per_model_options: &HashMap<usize, ModelRenderOptions>,
+    ) {
+        // The heading receives keyboard focus to navigate the folders with arrow keys.
+        let response = ui
+            .add(Label::new(RichText::new("Files").heading()).sense(egui::Sense::click()))
+            .on_hover_text("Click or press Tab to navigate the files with the keyboard.");
+        if response.clicked() {
+            response.request_focus();
+        }
+
+        let mut folder_to_remove = None;
+        if response.has_focus() {
+            // Prevent the arrow keys from moving focus to other widgets.
+            ui.memory_mut(|m| {
+                m.set_focus_lock_filter(
+                    response.id,
+                    egui::EventFilter {
+                        horizontal_arrows: true,
+                        vertical_arrows: true,
+                        ..Default::default()
+                    },
+                )
+            });
+            folder_to_remove = self.handle_tree_input(ctx, ui);
+        } else {
+            self.ui_state.tree_focus = None;
+        }
+
         ScrollArea::vertical()
             .auto_shrink([false; 2])
             .show(ui, |ui| {
-                let mut folder_to_remove = None;
-
                 for (folder_index, model) in self
                     .models
                     .iter_mut()
                     .enumerate()
                     .filter(|(_, model)| !model.model.is_empty())
                 {
+                    let focus = self
+                        .ui_state
+                        .tree_focus
+                        .filter(|f| f.selected_folder == folder_index);
+
                     // TODO: Use folder icons for open vs closed.
-                    CollapsingHeader::new(folder_display_name(model))
-                        .id_salt(format!("folder.{folder_index}"))
-                        .default_open(true)
-                        .show(ui, |ui| {
+                    let id = folder_collapsing_id(folder_index);
+                    let folder_key = model.folder_path.to_string_lossy().to_string();
+                    let has_notes = self.project.folder_notes.contains_key(&folder_key);
+                    let has_render_options = per_model_options.contains_key(&folder_index);
+                    let (_, header, _) = CollapsingState::load_with_default_open(ctx, id, true)
+                        .show_header(ui, |ui| {
+                            let response = ui.add(
+                                Label::new(folder_display_name(model))
+                                    .selectable(false)
+                                    .sense(egui::Sense::click()),
+                            );
+
+                            // Highlight the button for folders with notes.
+                            let notes_text = if has_notes {
+                                RichText::new("🗒").strong()
+                            } else {
+                                RichText::new("🗒").weak()
+                            };
+                            if ui
+                                .small_button(notes_text)
+                                .on_hover_text("Edit notes for this folder.")
+                                .clicked()
+                            {
+                                self.ui_state.notes_folder = Some(folder_key.clone());
+                            }
+
+                            // Highlight the button for folders with render setting overrides.
+                            let options_text = if has_render_options {
+                                RichText::new("⚙").strong()
+                            } else {
+                                RichText::new("⚙").weak()
+                            };
+                            if ui
+                                .small_button(options_text)
+                                .on_hover_text(
+                                    "Override the render settings for all models while this folder is selected.",
+                                )
+                                .clicked()
+                            {
+                                self.ui_state.model_options_folder = Some(folder_index);
+                            }
+
+                            response
+                        })
+                        .body(|ui| {
                             show_folder_files(
                                 &mut self.ui_state,
                                 model,
                                 ctx,
                                 ui,
                                 folder_index,
+                                focus.and_then(|f| f.selected_file),
                                 self.preferences.dark_mode,
                             );
-                        })
-                        .header_response
+                        });
+                    let header_response = header.inner;
+
+                    // Clicking the name also expands or collapses the folder.
+                    if header_response.clicked() {
+                        if let Some(mut state) = CollapsingState::load(ctx, id) {
+                            state.toggle(ui);
+                            state.store(ctx);
+                        }
+                    }
+
+                    let header_response = if focus.is_some_and(|f| f.selected_file.is_none()) {
+                        header_response.scroll_to_me(None);
+                        header_response.highlight()
+                    } else {
+                        header_response
+                    };
+
+                    header_response
                         .on_hover_text(model.folder_path.to_string_lossy())
                         .context_menu(|ui| {
                             // Prevent adding a file that already exists.
@@ -1442,17 +3163,135 @@ impl SsbhApp {
                             }
                         });
                 }
+            });
 
-                if let Some(folder_to_remove) = folder_to_remove {
-                    if self.models.get(folder_to_remove).is_some() {
-                        self.models.remove(folder_to_remove);
-                    }
-                    self.render_actions
-                        .push_back(RenderAction::Model(RenderModelAction::Remove(
-                            folder_to_remove,
-                        )));
+        if let Some(folder_to_remove) = folder_to_remove {
+            if self.models.get(folder_to_remove).is_some() {
+                self.models.remove(folder_to_remove);
+            }
+            if let Some(cameras) = self.per_folder_cameras.as_mut() {
+                if folder_to_remove < cameras.len() {
+                    cameras.remove(folder_to_remove);
                 }
+                // The current camera may no longer match the folder at this index.
+                self.camera_folder_index = None;
+            }
+            self.ui_state.nutexb_viewer.decoded_key = None;
+            self.ui_state.matl_editor.param_curves_key = None;
+            self.ui_state.matl_editor.original_matl = None;
+            self.render_actions
+                .push_back(RenderAction::Model(RenderModelAction::Remove(
+                    folder_to_remove,
+                )));
+            self.ui_state.tree_focus = None;
+        }
+    }
+
+    /// Navigate the folder tree with the keyboard and return the folder to remove, if any.
+    fn handle_tree_input(&mut self, ctx: &Context, ui: &Ui) -> Option<usize> {
+        let folder_indices: Vec<_> = self
+            .models
+            .iter()
+            .enumerate()
+            .filter(|(_, model)| !model.model.is_empty())
+            .map(|(i, _)| i)
+            .collect();
+        let first_folder = *folder_indices.first()?;
+
+        let focus = self
+            .ui_state
+            .tree_focus
+            .get_or_insert_with(|| TreeFocusState {
+                selected_folder: first_folder,
+                selected_file: None,
             });
+
+        let folder_state = |folder_index: usize| {
+            let id = folder_collapsing_id(folder_index);
+            CollapsingState::load_with_default_open(ctx, id, true)
+        };
+        // Collapsed folders have no selectable files.
+        let row_count = |folder_index: usize| {
+            if folder_state(folder_index).is_open() {
+                self.models
+                    .get(folder_index)
+                    .map(file_row_count)
+                    .unwrap_or_default()
+            } else {
+                0
+            }
+        };
+
+        let position = folder_indices
+            .iter()
+            .position(|i| *i == focus.selected_folder)
+            .unwrap_or_default();
+
+        let (up, down, enter, space, delete) = ctx.input(|i| {
+            (
+                i.key_pressed(egui::Key::ArrowUp),
+                i.key_pressed(egui::Key::ArrowDown),
+                i.key_pressed(egui::Key::Enter),
+                i.key_pressed(egui::Key::Space),
+                i.key_pressed(egui::Key::Delete),
+            )
+        });
+
+        if down {
+            match focus.selected_file {
+                Some(f) if f + 1 < row_count(focus.selected_folder) => {
+                    focus.selected_file = Some(f + 1)
+                }
+                None if row_count(focus.selected_folder) > 0 => focus.selected_file = Some(0),
+                _ => {
+                    if let Some(next) = folder_indices.get(position + 1) {
+                        focus.selected_folder = *next;
+                        focus.selected_file = None;
+                    }
+                }
+            }
+        }
+
+        if up {
+            match focus.selected_file {
+                Some(0) => focus.selected_file = None,
+                Some(f) => focus.selected_file = Some(f - 1),
+                None => {
+                    if let Some(previous) = position.checked_sub(1).map(|p| folder_indices[p]) {
+                        focus.selected_folder = previous;
+                        focus.selected_file = row_count(previous).checked_sub(1);
+                    }
+                }
+            }
+        }
+
+        let TreeFocusState {
+            selected_folder,
+            selected_file,
+        } = *focus;
+
+        if enter {
+            match selected_file {
+                Some(row) => {
+                    if let Some(model) = self.models.get(selected_folder) {
+                        open_file_row(&mut self.ui_state, model, selected_folder, row);
+                    }
+                }
+                None => {
+                    let mut state = folder_state(selected_folder);
+                    state.toggle(ui);
+                    state.store(ctx);
+                }
+            }
+        }
+
+        if space {
+            self.render_actions.push_back(RenderAction::Model(
+                RenderModelAction::ToggleVisibility(selected_folder),
+            ));
+        }
+
+        delete.then_some(selected_folder)
     }
 
     fn bottom_panel(&mut self, ui: &mut Ui, render_state: &mut RenderState) {
@@ -1469,7 +3308,7 @@ impl SsbhApp {
 
     fn show_most_recent_log_message(&mut self, ui: &mut Ui) {
         // The layout is right to left, so add in reverse order.
-        if let Some((level, message)) = LOGGER.messages.lock().unwrap().last() {
+        if let Some((_, level, message)) = LOGGER.messages.lock().unwrap().last() {
             if ui.add_sized([60.0, 30.0], Button::new("Logs")).clicked() {
                 self.ui_state.log_window_open = true;
             }
@@ -1605,7 +3444,7 @@ pub fn error_icon(ui: &mut Ui) -> Response {
         Label::new(
             RichText::new("⚠")
                 .strong()
-                .color(ERROR_COLOR)
+                .color(ui.visuals().error_fg_color)
                 .size(ICON_TEXT_SIZE),
         ),
     )
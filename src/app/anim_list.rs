@@ -1,13 +1,21 @@
 use crate::{
-    app::{folder_display_name, SsbhApp},
+    animation_preset::AnimationPreset,
+    app::{draggable_icon, folder_display_name, warning_icon_text, SsbhApp},
+    editors::anim::animation_duration_seconds,
     model_folder::find_anim_folders,
     widgets::EyeCheckBox,
     AnimationIndex, AnimationSlot, ModelFolderState,
 };
 use egui::{
-    collapsing_header::CollapsingState, CollapsingHeader, Context, Label, RichText, TextWrapMode,
-    Ui,
+    collapsing_header::CollapsingState, Button, CollapsingHeader, Context, DragValue, Label,
+    RichText, TextEdit, TextWrapMode, Ui,
 };
+use egui_dnd::{dnd, Handle};
+use log::error;
+use rfd::FileDialog;
+
+/// The maximum number of animation slots for each folder.
+const MAX_ANIMATION_SLOTS: usize = 8;
 
 pub fn anim_list(ctx: &Context, app: &mut SsbhApp, ui: &mut Ui) {
     // Only assign animations to folders with model files.
@@ -36,21 +44,105 @@ pub fn anim_list(ctx: &Context, app: &mut SsbhApp, ui: &mut Ui) {
                     // TODO: Disable the UI instead?
                     let model_animations = app.animation_state.animations.get_mut(model_index);
                     if let Some(model_animations) = model_animations {
-                        if ui.button("Add Slot").clicked() {
-                            model_animations.push(AnimationSlot::new());
+                        ui.horizontal(|ui| {
+                            let can_add = model_animations.len() < MAX_ANIMATION_SLOTS;
+                            if ui
+                                .add_enabled(can_add, Button::new("Add Slot"))
+                                .on_disabled_hover_text(format!(
+                                    "Folders support at most {MAX_ANIMATION_SLOTS} slots."
+                                ))
+                                .clicked()
+                            {
+                                model_animations.push(AnimationSlot::new());
+
+                                // Expand the new slot to make it easier to find.
+                                let id = anim_slot_id(model_index, model_animations.len() - 1);
+                                let mut state =
+                                    CollapsingState::load_with_default_open(ctx, id, false);
+                                state.set_open(true);
+                                state.store(ctx);
+                            }
+
+                            if ui
+                                .add_enabled(
+                                    !model_animations.is_empty(),
+                                    Button::new("Remove Last Slot"),
+                                )
+                                .clicked()
+                            {
+                                model_animations.pop();
+                                app.animation_state.should_update_animations = true;
+                            }
+
+                            if ui
+                                .button("Save Animation Setup...")
+                                .on_hover_text("Save the slots to a JSON file.")
+                                .clicked()
+                            {
+                                save_animation_setup(model_animations, &app.models);
+                            }
+
+                            if ui
+                                .button("Load Animation Setup...")
+                                .on_hover_text(
+                                    "Replace the slots with animations matching the file names.",
+                                )
+                                .clicked()
+                            {
+                                if let Some(mut slots) = load_animation_setup(&available_folders) {
+                                    slots.truncate(MAX_ANIMATION_SLOTS);
+                                    *model_animations = slots;
+                                    app.animation_state.should_update_animations = true;
+                                }
+                            }
+                        });
+
+                        if has_mismatched_lengths(model_animations, &app.models) {
+                            ui.label(warning_icon_text(
+                                "Assigned animations have different final frames. \
+                                Shorter animations hold their final frame until the longest finishes.",
+                            ));
                         }
 
-                        for (slot, anim_slot) in model_animations.iter_mut().enumerate().rev() {
-                            app.animation_state.should_update_animations |= show_anim_slot(
-                                ctx,
-                                ui,
-                                anim_slot,
-                                &app.models,
-                                &available_folders,
-                                model_index,
-                                slot,
-                                &mut slot_to_remove,
-                            );
+                        // Show the most recently added slots first.
+                        // TODO: Avoid allocating here.
+                        let mut slot_indices: Vec<_> = (0..model_animations.len()).rev().collect();
+                        let response = dnd(ui, ("anim_slots_dnd", model_index)).show_vec(
+                            &mut slot_indices,
+                            |ui, slot, handle, _| {
+                                app.animation_state.should_update_animations |= show_anim_slot(
+                                    ctx,
+                                    ui,
+                                    &mut model_animations[*slot],
+                                    &app.models,
+                                    &available_folders,
+                                    model_index,
+                                    *slot,
+                                    &mut slot_to_remove,
+                                    handle,
+                                    app.preferences.dark_mode,
+                                );
+                            },
+                        );
+
+                        if response.final_update().is_some() {
+                            // The slot order affects how animations are applied.
+                            let new_slots = slot_indices
+                                .iter()
+                                .rev()
+                                .map(|i| model_animations[*i].clone())
+                                .collect();
+                            *model_animations = new_slots;
+
+                            if app.animation_state.selected_folder == model_index {
+                                let selected_slot = app.animation_state.selected_slot;
+                                app.animation_state.selected_slot = slot_indices
+                                    .iter()
+                                    .rev()
+                                    .position(|i| *i == selected_slot)
+                                    .unwrap_or_default();
+                            }
+                            app.animation_state.should_update_animations = true;
                         }
 
                         if let Some(slot) = slot_to_remove {
@@ -64,6 +156,52 @@ pub fn anim_list(ctx: &Context, app: &mut SsbhApp, ui: &mut Ui) {
     }
 }
 
+fn has_mismatched_lengths(slots: &[AnimationSlot], models: &[ModelFolderState]) -> bool {
+    let mut final_frames = slots
+        .iter()
+        .filter_map(|slot| slot.animation?.get_animation(models))
+        .filter_map(|(_, anim)| anim.as_ref().ok())
+        .map(|anim| anim.final_frame_index);
+
+    match final_frames.next() {
+        Some(first) => final_frames.any(|f| f != first),
+        None => false,
+    }
+}
+
+fn anim_slot_id(model_index: usize, slot: usize) -> egui::Id {
+    // Don't depend on the ui since the slots are drawn inside drag and drop items.
+    egui::Id::new("anim_slot").with(model_index).with(slot)
+}
+
+fn save_animation_setup(slots: &[AnimationSlot], models: &[ModelFolderState]) {
+    if let Some(file) = FileDialog::new()
+        .add_filter("Animation Setup", &["json"])
+        .save_file()
+    {
+        let preset = AnimationPreset::from_slots(slots, models);
+        if let Err(e) = preset.write_to_file(&file) {
+            error!("Failed to save animation setup to {:?}: {}", file, e);
+        }
+    }
+}
+
+fn load_animation_setup(
+    available_folders: &[(usize, &ModelFolderState)],
+) -> Option<Vec<AnimationSlot>> {
+    let file = FileDialog::new()
+        .add_filter("Animation Setup", &["json"])
+        .pick_file()?;
+
+    match AnimationPreset::from_file(&file) {
+        Ok(preset) => Some(preset.to_slots(available_folders)),
+        Err(e) => {
+            error!("Failed to load animation setup from {:?}: {}", file, e);
+            None
+        }
+    }
+}
+
 fn show_anim_slot(
     ctx: &Context,
     ui: &mut Ui,
@@ -73,10 +211,12 @@ fn show_anim_slot(
     model_index: usize,
     slot: usize,
     slot_to_remove: &mut Option<usize>,
+    handle: Handle,
+    dark_mode: bool,
 ) -> bool {
     let mut update_animations = false;
 
-    let id = ui.make_persistent_id(model_index).with("slot").with(slot);
+    let id = anim_slot_id(model_index, slot);
     CollapsingState::load_with_default_open(ctx, id, false)
         .show_header(ui, |ui| {
             let name = anim_slot
@@ -87,6 +227,10 @@ fn show_anim_slot(
                 .unwrap_or_else(|| "Select an animation...");
 
             ui.horizontal(|ui| {
+                handle.ui(ui, |ui| {
+                    draggable_icon(ctx, ui, dark_mode);
+                });
+
                 // TODO: Disabling anims with visibility tracks has confusing behavior.
                 // Disabling a vis track currently only disables the effects on later frames.
                 if ui
@@ -99,11 +243,53 @@ fn show_anim_slot(
                     update_animations = true;
                 }
 
+                // Show the animation name for unlabeled slots.
+                ui.add(
+                    TextEdit::singleline(&mut anim_slot.label)
+                        .hint_text(name)
+                        .desired_width(80.0),
+                )
+                .on_hover_text("The label for this slot.");
+
+                if ui
+                    .add(
+                        DragValue::new(&mut anim_slot.speed_multiplier)
+                            .speed(0.01)
+                            .range(0.0..=4.0)
+                            .suffix("x"),
+                    )
+                    .on_hover_text("The playback speed multiplier for this slot.")
+                    .changed()
+                {
+                    update_animations = true;
+                }
+
+                if ui
+                    .add(DragValue::new(&mut anim_slot.frame_offset).speed(0.1))
+                    .on_hover_text(
+                        "The frame offset for this slot. Negative values start the slot later.",
+                    )
+                    .changed()
+                {
+                    update_animations = true;
+                }
+
                 if anim_combo_box(ui, available_folders, id.with("anim"), name, anim_slot) {
                     // Reflect selecting a new animation in the viewport.
                     update_animations = true;
                 }
 
+                if let Some((_, Ok(anim))) = anim_slot
+                    .animation
+                    .and_then(|anim_index| anim_index.get_animation(models))
+                {
+                    ui.label(format!(
+                        "{} frames ({:.2}s)",
+                        anim.final_frame_index + 1.0,
+                        animation_duration_seconds(anim)
+                    ));
+                }
+
                 // Use "Remove" since this doesn't delete the actual animation.
                 if ui.button("Remove").clicked() {
                     *slot_to_remove = Some(slot);
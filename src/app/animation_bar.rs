@@ -1,6 +1,6 @@
-use egui::{Button, DragValue, Ui};
+use egui::{Button, DragValue, TextEdit, Ui};
 
-use crate::AnimationState;
+use crate::{widgets::TimelineScrubber, AnimationState};
 
 pub fn display_animation_bar(
     ui: &mut Ui,
@@ -20,21 +20,86 @@ pub fn display_animation_bar(
 
             // TODO: Custom checkbox widget so label is on the left side.
             ui.checkbox(&mut animation_state.should_loop, "Loop");
+            ui.separator();
+
+            // Limit playback to a range of frames for reviewing part of an animation.
+            let (start, mut end) = animation_state.playback_range(final_frame_index);
+            ui.label("Range");
+            ui.add(
+                DragValue::new(&mut animation_state.play_start_frame)
+                    .range(0.0..=end)
+                    .speed(0.1),
+            )
+            .on_hover_text("The first frame to play.");
+            if ui
+                .add(
+                    DragValue::new(&mut end)
+                        .range(start..=final_frame_index)
+                        .speed(0.1),
+                )
+                .on_hover_text("The last frame to play.")
+                .changed()
+            {
+                animation_state.play_end_frame = Some(end);
+            }
+            if ui
+                .button("Reset")
+                .on_hover_text("Play all frames.")
+                .clicked()
+            {
+                animation_state.play_start_frame = 0.0;
+                animation_state.play_end_frame = None;
+            }
+            ui.separator();
+
+            ui.add(
+                TextEdit::singleline(&mut animation_state.new_marker_label)
+                    .hint_text("Marker label")
+                    .desired_width(100.0),
+            );
+            let frame = animation_state.current_frame.round();
+            if ui.button(format!("Add Marker at Frame {frame}")).clicked() {
+                let label = std::mem::take(&mut animation_state.new_marker_label);
+                animation_state.add_frame_marker(frame, label);
+            }
         });
         ui.horizontal_centered(|ui| {
             // TODO: How to fill available space?
             // TODO: Get the space that would normally be taken up by the central panel?
-            ui.spacing_mut().slider_width = (ui.available_width() - 520.0).max(0.0);
+            let width = (ui.available_width() - 520.0).max(0.0);
 
             // Only round frames when not playing to avoid messing up interpolation.
             let step = if animation_state.is_playing { 0.0 } else { 1.0 };
 
             let response = ui.add(
-                // TODO: Show ticks?
-                egui::Slider::new(&mut animation_state.current_frame, 0.0..=final_frame_index)
-                    .step_by(step)
-                    .show_value(false),
+                TimelineScrubber::new(&mut animation_state.current_frame, final_frame_index)
+                    .width(width)
+                    .step(step)
+                    .tick_interval(animation_state.tick_interval)
+                    .markers(&animation_state.frame_markers),
             );
+            response.context_menu(|ui| {
+                ui.horizontal(|ui| {
+                    ui.label("Tick Interval");
+                    ui.add(DragValue::new(&mut animation_state.tick_interval).range(1..=1000));
+                });
+
+                ui.add_enabled_ui(!animation_state.frame_markers.is_empty(), |ui| {
+                    ui.menu_button("Delete Marker", |ui| {
+                        let mut marker_to_remove = None;
+                        for (i, (frame, label)) in animation_state.frame_markers.iter().enumerate()
+                        {
+                            if ui.button(format!("{label} (frame {frame})")).clicked() {
+                                marker_to_remove = Some(i);
+                                ui.close_menu();
+                            }
+                        }
+                        if let Some(i) = marker_to_remove {
+                            animation_state.frame_markers.remove(i);
+                        }
+                    });
+                });
+            });
             if response.hovered() {
                 ui.ctx().input_mut(|i| {
                     if i.consume_key(egui::Modifiers::default(), egui::Key::ArrowLeft) {
@@ -106,6 +171,14 @@ pub fn display_animation_bar(
                 animation_state.should_update_animations = true;
             }
             ui.label(&format!("/ {final_frame_index}"));
+
+            if ui
+                .toggle_value(&mut animation_state.force_tpose, "T-Pose")
+                .on_hover_text("Render models in the skel's bind pose without any animations.")
+                .changed()
+            {
+                animation_state.should_update_animations = true;
+            }
         });
     });
 }
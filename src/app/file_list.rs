@@ -1,9 +1,9 @@
 use super::{
-    adj_icon, anim_icon, display_validation_errors, empty_icon, hlpb_icon, matl_icon, mesh_icon,
-    missing_icon, skel_icon, warning_icon, warning_icon_text, UiState, ERROR_COLOR, ICON_SIZE,
+    adj_icon, anim_icon, display_validation_errors, hlpb_icon, matl_icon, mesh_icon, missing_icon,
+    skel_icon, warning_icon, warning_icon_text, UiState, ICON_SIZE,
 };
 use crate::{validation::MatlValidationErrorKind, FileResult, ModelFolderState};
-use egui::{load::SizedTexture, Button, Context, Response, RichText, Ui};
+use egui::{load::SizedTexture, Button, Context, Label, Response, RichText, Sense, Ui};
 
 pub fn show_folder_files(
     ui_state: &mut UiState,
@@ -11,12 +11,26 @@ pub fn show_folder_files(
     ctx: &Context,
     ui: &mut Ui,
     folder_index: usize,
+    focused_file: Option<usize>,
     dark_mode: bool,
 ) {
+    // Convert the focused row to an index for each file list.
+    let mut row_offset = 0;
+    let mut focused = |count: usize| {
+        let index = focused_file
+            .and_then(|f| f.checked_sub(row_offset))
+            .filter(|f| *f < count);
+        row_offset += count;
+        index
+    };
+
     // Avoid a confusing missing file error for animation or texture folders.
     let is_model = model.is_model_folder();
     let required_file = |name| if is_model { Some(name) } else { None };
 
+    // Clicking a file that failed to load shows its messages in the log.
+    let mut error_file = None;
+
     // Clicking a file opens the corresponding editor.
     // Set selected index so the editor remains open for the file.
     list_files(
@@ -26,10 +40,12 @@ pub fn show_folder_files(
         folder_index,
         &mut ui_state.selected_folder_index,
         &mut ui_state.open_mesh,
+        focused(model.model.meshes.len()),
         required_file("model.numshb"),
         Some("model.numshb"),
         &model.validation.mesh_errors,
         |ui| mesh_icon(ctx, ui, dark_mode),
+        &mut error_file,
     );
     list_files(
         ui,
@@ -38,10 +54,12 @@ pub fn show_folder_files(
         folder_index,
         &mut ui_state.selected_folder_index,
         &mut ui_state.open_skel,
+        focused(model.model.skels.len()),
         required_file("model.nusktb"),
         Some("model.nusktb"),
         &model.validation.skel_errors,
         |ui| skel_icon(ctx, ui, dark_mode),
+        &mut error_file,
     );
     list_files(
         ui,
@@ -50,10 +68,12 @@ pub fn show_folder_files(
         folder_index,
         &mut ui_state.selected_folder_index,
         &mut ui_state.open_hlpb,
+        focused(model.model.hlpbs.len()),
         None,
         Some("model.nuhlpb"),
         &model.validation.hlpb_errors,
         |ui| hlpb_icon(ctx, ui, dark_mode),
+        &mut error_file,
     );
     list_files(
         ui,
@@ -62,10 +82,12 @@ pub fn show_folder_files(
         folder_index,
         &mut ui_state.selected_folder_index,
         &mut ui_state.open_matl,
+        focused(model.model.matls.len()),
         required_file("model.numatb"),
         Some("model.numatb"),
         &model.validation.matl_errors,
         |ui| matl_icon(ctx, ui, dark_mode),
+        &mut error_file,
     );
     list_files(
         ui,
@@ -74,10 +96,12 @@ pub fn show_folder_files(
         folder_index,
         &mut ui_state.selected_folder_index,
         &mut ui_state.open_modl,
+        focused(model.model.modls.len()),
         required_file("model.numdlb"),
         Some("model.numdlb"),
         &model.validation.modl_errors,
         |ui| mesh_icon(ctx, ui, dark_mode),
+        &mut error_file,
     );
     list_files(
         ui,
@@ -86,10 +110,12 @@ pub fn show_folder_files(
         folder_index,
         &mut ui_state.selected_folder_index,
         &mut ui_state.open_adj,
+        focused(model.model.adjs.len()),
         None,
         Some("model.adjb"),
         &model.validation.adj_errors,
         |ui| adj_icon(ctx, ui, dark_mode),
+        &mut error_file,
     );
     list_files(
         ui,
@@ -98,10 +124,12 @@ pub fn show_folder_files(
         folder_index,
         &mut ui_state.selected_folder_index,
         &mut ui_state.open_anim,
+        focused(model.model.anims.len()),
         None,
         None,
         &model.validation.anim_errors,
         |ui| anim_icon(ctx, ui, dark_mode),
+        &mut error_file,
     );
     list_files(
         ui,
@@ -110,10 +138,12 @@ pub fn show_folder_files(
         folder_index,
         &mut ui_state.selected_folder_index,
         &mut ui_state.open_meshex,
+        focused(model.model.meshexes.len()),
         None,
         Some("model.numshexb"),
         &model.validation.meshex_errors,
         |ui| mesh_icon(ctx, ui, dark_mode),
+        &mut error_file,
     );
     // TODO: Modify this to use the same function as above.
     list_nutexb_files(
@@ -122,7 +152,58 @@ pub fn show_folder_files(
         folder_index,
         &mut ui_state.selected_folder_index,
         &mut ui_state.open_nutexb,
+        focused(model.model.nutexbs.len()),
     );
+
+    if let Some(name) = error_file {
+        ui_state.log_window_open = true;
+        ui_state.log_window.filter = name;
+    }
+}
+
+/// The number of files that can be selected with keyboard navigation.
+pub fn file_row_count(model: &ModelFolderState) -> usize {
+    let files = &model.model;
+    files.meshes.len()
+        + files.skels.len()
+        + files.hlpbs.len()
+        + files.matls.len()
+        + files.modls.len()
+        + files.adjs.len()
+        + files.anims.len()
+        + files.meshexes.len()
+        + files.nutexbs.len()
+}
+
+/// Open the editor for the file at `row` using the same order as [show_folder_files].
+pub fn open_file_row(
+    ui_state: &mut UiState,
+    model: &ModelFolderState,
+    folder_index: usize,
+    row: usize,
+) {
+    let files = &model.model;
+    let rows = [
+        (files.meshes.len(), &mut ui_state.open_mesh),
+        (files.skels.len(), &mut ui_state.open_skel),
+        (files.hlpbs.len(), &mut ui_state.open_hlpb),
+        (files.matls.len(), &mut ui_state.open_matl),
+        (files.modls.len(), &mut ui_state.open_modl),
+        (files.adjs.len(), &mut ui_state.open_adj),
+        (files.anims.len(), &mut ui_state.open_anim),
+        (files.meshexes.len(), &mut ui_state.open_meshex),
+        (files.nutexbs.len(), &mut ui_state.open_nutexb),
+    ];
+
+    let mut row = row;
+    for (count, open_file) in rows {
+        if row < count {
+            *open_file = Some(row);
+            ui_state.selected_folder_index = Some(folder_index);
+            return;
+        }
+        row -= count;
+    }
 }
 
 fn list_nutexb_files(
@@ -131,6 +212,7 @@ fn list_nutexb_files(
     folder_index: usize,
     selected_folder_index: &mut Option<usize>,
     selected_file_index: &mut Option<usize>,
+    focused_index: Option<usize>,
 ) {
     // Show missing textures required by the matl.
     for e in &model.validation.matl_errors {
@@ -169,11 +251,16 @@ fn list_nutexb_files(
             } else {
                 ui.button(file)
             };
+            let response = highlight_focused(response, focused_index == Some(i));
 
             if response.clicked() {
                 *selected_folder_index = Some(folder_index);
                 *selected_file_index = Some(i);
             }
+
+            if let Some(true) = model.changed.nutexbs.get(i) {
+                ui.label("[Modified]");
+            }
         });
     }
 }
@@ -192,6 +279,7 @@ fn missing_nutexb(ui: &mut Ui, name: &str) {
     ));
 }
 
+#[allow(clippy::too_many_arguments)]
 fn list_files<T, E: std::fmt::Display, F: Fn(&mut Ui) -> Response>(
     ui: &mut Ui,
     files: &[(String, FileResult<T>)],
@@ -199,10 +287,12 @@ fn list_files<T, E: std::fmt::Display, F: Fn(&mut Ui) -> Response>(
     folder_index: usize,
     selected_folder_index: &mut Option<usize>,
     selected_file_index: &mut Option<usize>,
+    focused_index: Option<usize>,
     required_file: Option<&'static str>,
     validation_file: Option<&'static str>,
     validation_errors: &[E],
     file_icon: F,
+    error_file: &mut Option<String>,
 ) {
     // TODO: Should this be a grid instead?
     for (i, (name, file)) in files.iter().enumerate() {
@@ -220,6 +310,7 @@ fn list_files<T, E: std::fmt::Display, F: Fn(&mut Ui) -> Response>(
                     } else {
                         ui.button(name)
                     };
+                    let response = highlight_focused(response, focused_index == Some(i));
 
                     if response.clicked() {
                         *selected_folder_index = Some(folder_index);
@@ -231,14 +322,25 @@ fn list_files<T, E: std::fmt::Display, F: Fn(&mut Ui) -> Response>(
                         ui.label("[Modified]");
                     }
                 }
-                Err(_) => {
-                    // TODO: Investigate a cleaner way to summarize errors.
-                    // Don't show the full error for now to avoid showing lots of text.
-                    empty_icon(ui);
-                    ui.label(RichText::new("⚠ ".to_string() + name).color(ERROR_COLOR))
+                Err(e) => {
+                    // Keep the file type icon to make the list easier to scan.
+                    file_icon(ui);
+
+                    // Truncate the error to avoid showing lots of text.
+                    let message: String = e.to_string().chars().take(256).collect();
+                    let color = ui.visuals().error_fg_color;
+                    if ui
+                        .add(
+                            Label::new(RichText::new("❌ ".to_string() + name).color(color))
+                                .sense(Sense::click()),
+                        )
                         .on_hover_text(format!(
-                            "Error reading {name}. Check the application logs for details."
-                        ));
+                            "Error reading {name}: {message}\nClick to show the application logs for this file."
+                        ))
+                        .clicked()
+                    {
+                        *error_file = Some(name.clone());
+                    }
                 }
             }
         });
@@ -250,6 +352,15 @@ fn list_files<T, E: std::fmt::Display, F: Fn(&mut Ui) -> Response>(
     }
 }
 
+fn highlight_focused(response: Response, is_focused: bool) -> Response {
+    if is_focused {
+        response.scroll_to_me(None);
+        response.highlight()
+    } else {
+        response
+    }
+}
+
 fn file_button_with_errors<E: std::fmt::Display>(
     ui: &mut Ui,
     name: &str,
@@ -1,10 +1,14 @@
 use std::path::Path;
 
-use crate::CameraState;
+use crate::{
+    capture::{ComparisonLayout, ComparisonSplit},
+    update_text_styles, CameraState,
+};
 
-use super::{RenderAction, RenderModelAction, SsbhApp};
-use egui::{special_emojis::GITHUB, Button, KeyboardShortcut, TextWrapMode, Ui};
+use super::{EditorWindowState, RenderAction, RenderModelAction, SsbhApp};
+use egui::{special_emojis::GITHUB, Button, DragValue, KeyboardShortcut, TextWrapMode, Ui};
 use rfd::FileDialog;
+use strum::IntoEnumIterator;
 
 pub fn menu_bar(app: &mut SsbhApp, ui: &mut Ui) {
     let open_shortcut = egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::O);
@@ -13,6 +17,10 @@ pub fn menu_bar(app: &mut SsbhApp, ui: &mut Ui) {
         egui::Key::O,
     );
     let reload_shortcut = egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::R);
+    let compact_shortcut = egui::KeyboardShortcut::new(
+        egui::Modifiers::COMMAND | egui::Modifiers::SHIFT,
+        egui::Key::C,
+    );
 
     // Shortcuts need to be handled even while the menu is not open.
     if ui.input_mut(|i| i.consume_shortcut(&open_shortcut)) {
@@ -27,6 +35,11 @@ pub fn menu_bar(app: &mut SsbhApp, ui: &mut Ui) {
         app.reload_workspace();
     }
 
+    if ui.input_mut(|i| i.consume_shortcut(&compact_shortcut)) {
+        app.preferences.compact_ui = !app.preferences.compact_ui;
+        update_text_styles(&app.preferences, ui.ctx());
+    }
+
     egui::menu::bar(ui, |ui| {
         ui.menu_button("File", |ui| {
             let button =
@@ -128,6 +141,11 @@ pub fn menu_bar(app: &mut SsbhApp, ui: &mut Ui) {
                 ui.close_menu();
                 app.ui_state.device_info_window_open = true;
             }
+
+            if ui.button("Validation Plugins").clicked() {
+                ui.close_menu();
+                app.ui_state.validation_plugins_open = true;
+            }
         });
 
         ui.menu_button("Viewport", |ui| {
@@ -143,6 +161,24 @@ pub fn menu_bar(app: &mut SsbhApp, ui: &mut Ui) {
                 };
                 app.render_actions.push_back(RenderAction::UpdateCamera);
             }
+            let mut sync_cameras = app.per_folder_cameras.is_none();
+            if ui
+                .checkbox(&mut sync_cameras, "Sync Cameras")
+                .on_hover_text("Share the camera between folders. Disable to restore a separate camera when selecting each folder.")
+                .changed()
+            {
+                if sync_cameras {
+                    app.per_folder_cameras = None;
+                } else {
+                    app.per_folder_cameras =
+                        Some(vec![app.camera_state.values.clone(); app.models.len()]);
+                    app.camera_folder_index = app.ui_state.selected_folder_index;
+                }
+            }
+            ui.checkbox(&mut app.show_bounding_spheres, "Show Bounding Spheres")
+                .on_hover_text("Draw the bounding sphere for the selected mesh or all meshes if no mesh is selected.");
+            ui.checkbox(&mut app.split_viewport, "Split Viewport")
+                .on_hover_text("Show the first half of the folders on the left and the rest on the right. Click a pane to move its camera.");
             ui.separator();
 
             if ui.button("Save Screenshot...").clicked() {
@@ -155,6 +191,66 @@ pub fn menu_bar(app: &mut SsbhApp, ui: &mut Ui) {
                 }
             }
 
+            if ui
+                .button("Save Depth Screenshot...")
+                .on_hover_text("Save the depth of the visible meshes as a grayscale PNG.")
+                .clicked()
+            {
+                ui.close_menu();
+                if let Some(file) = FileDialog::new().add_filter("PNG", &["png"]).save_file() {
+                    app.depth_screenshot_to_render = Some(file);
+                }
+            }
+
+            ui.menu_button("Screenshot A/B", |ui| {
+                let comparison = &mut app.ui_state.comparison_capture;
+                ui.label("Layout");
+                for layout in ComparisonLayout::iter() {
+                    ui.radio_value(&mut comparison.layout, layout, layout.to_string());
+                }
+                ui.label("Split");
+                for split in ComparisonSplit::iter() {
+                    ui.radio_value(&mut comparison.split, split, split.to_string());
+                }
+                ui.separator();
+
+                let has_settings =
+                    comparison.settings_a.is_some() && comparison.settings_b.is_some();
+                if ui
+                    .add_enabled(
+                        has_settings,
+                        Button::new("Save Screenshot A/B...").wrap_mode(TextWrapMode::Extend),
+                    )
+                    .on_disabled_hover_text(
+                        "Store settings A and B from the Render Settings window to enable.",
+                    )
+                    .clicked()
+                {
+                    ui.close_menu();
+                    if let Some(file) = FileDialog::new()
+                        .add_filter("Image", &["png", "jpg", "tif", "bmp"])
+                        .save_file()
+                    {
+                        app.comparison_screenshot_to_render = Some(file);
+                    }
+                }
+            });
+
+            if ui
+                .add_enabled(
+                    !app.preferences.camera_bookmarks.is_empty(),
+                    Button::new("Save Multi-Angle Batch...").wrap_mode(TextWrapMode::Extend),
+                )
+                .on_hover_text("Save a screenshot for each camera bookmark.")
+                .on_disabled_hover_text("Add bookmarks in the camera settings to enable.")
+                .clicked()
+            {
+                ui.close_menu();
+                if let Some(folder) = FileDialog::new().pick_folder() {
+                    app.multiangle_batch_to_render = Some(folder);
+                }
+            }
+
             ui.menu_button("Render Animation", |ui| {
                 if ui
                     .add(Button::new("Render to Image Sequence...").wrap_mode(TextWrapMode::Extend))
@@ -178,6 +274,37 @@ pub fn menu_bar(app: &mut SsbhApp, ui: &mut Ui) {
                         app.animation_gif_to_render = Some(file);
                     }
                 }
+
+                if ui
+                    .add(Button::new("Render to WebM...").wrap_mode(TextWrapMode::Extend))
+                    .on_hover_text("Requires FFmpeg to be installed and on the PATH.")
+                    .clicked()
+                {
+                    ui.close_menu();
+                    if let Some(file) = FileDialog::new().add_filter("WebM", &["webm"]).save_file()
+                    {
+                        app.animation_webm_to_render = Some(file);
+                    }
+                }
+                ui.separator();
+
+                // A full rotation of the camera around the paused model.
+                ui.horizontal(|ui| {
+                    ui.label("Turntable Frames");
+                    ui.add(
+                        DragValue::new(&mut app.ui_state.turntable_capture.total_frames)
+                            .range(1..=720),
+                    );
+                });
+                if ui
+                    .add(Button::new("Render Turntable to GIF...").wrap_mode(TextWrapMode::Extend))
+                    .clicked()
+                {
+                    ui.close_menu();
+                    if let Some(file) = FileDialog::new().add_filter("GIF", &["gif"]).save_file() {
+                        app.turntable_gif_to_render = Some(file);
+                    }
+                }
             });
         });
 
@@ -226,6 +353,29 @@ pub fn menu_bar(app: &mut SsbhApp, ui: &mut Ui) {
             ui.checkbox(&mut app.show_left_panel, "Left Panel");
             ui.checkbox(&mut app.show_right_panel, "Right Panel");
             ui.checkbox(&mut app.show_bottom_panel, "Bottom Panel");
+            ui.checkbox(
+                &mut app.preferences.show_shading_shortcuts,
+                "Shading Shortcuts",
+            );
+            ui.separator();
+
+            if ui.button("Reset Layout").clicked() {
+                ui.close_menu();
+                app.ui_state.should_reset_layout = true;
+            }
+            ui.separator();
+
+            if ui
+                .add_enabled(
+                    app.ui_state.secondary_editors.is_none(),
+                    Button::new("Open Second Editor Window").wrap_mode(TextWrapMode::Extend),
+                )
+                .on_hover_text("Open files in a separate window to compare them side by side.")
+                .clicked()
+            {
+                ui.close_menu();
+                app.ui_state.secondary_editors = Some(EditorWindowState::default());
+            }
         });
 
         ui.menu_button("Help", |ui| {
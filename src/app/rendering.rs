@@ -1,10 +1,62 @@
-use ssbh_wgpu::{animation::camera::animate_camera, CameraTransforms, SsbhRenderer};
+use std::borrow::Cow;
 
-use crate::{CameraState, CameraValues, RenderState};
+use ssbh_data::skel_data::SkelData;
+use ssbh_wgpu::{animation::camera::animate_camera, CameraTransforms, RenderModel, SsbhRenderer};
 
-use super::SsbhApp;
+use crate::{
+    editors::anim::sample_anim, model_folder::ModelFolderState, plugins::run_validation_plugins,
+    CameraState, CameraValues, RenderState,
+};
+
+use super::{find_file, SsbhApp};
 
 impl SsbhApp {
+    /// Apply the current camera values immediately for rendering outside the viewport.
+    pub fn apply_camera(
+        &mut self,
+        queue: &wgpu::Queue,
+        render_state: &mut RenderState,
+        width: f32,
+        height: f32,
+    ) {
+        update_camera(
+            queue,
+            &mut render_state.renderer,
+            &mut self.camera_state,
+            width,
+            height,
+            1.0,
+        );
+    }
+
+    /// Resize and update the camera for the right pane of the split viewport.
+    pub fn update_split_renderer(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        render_state: &mut RenderState,
+        width: f32,
+        height: f32,
+    ) {
+        let Some(renderer) = render_state.split_renderer.as_mut() else {
+            return;
+        };
+
+        if self.split_state.previous_size != Some((width, height)) {
+            renderer.resize(device, width as u32, height as u32, 1.0);
+            self.split_state.previous_size = Some((width, height));
+        }
+
+        update_camera(
+            queue,
+            renderer,
+            &mut self.split_state.camera_state,
+            width,
+            height,
+            1.0,
+        );
+    }
+
     pub fn refresh_render_state(
         &mut self,
         device: &wgpu::Device,
@@ -44,6 +96,7 @@ impl SsbhApp {
             for model in &mut self.models {
                 model.validate(&render_state.shared_data)
             }
+            self.plugin_errors = run_validation_plugins(&self.validation_plugins, self);
             self.should_validate_models = false;
         }
 
@@ -115,20 +168,35 @@ impl SsbhApp {
             .zip(self.models.iter())
             .zip(self.animation_state.animations.iter())
         {
+            let current_frame = self.animation_state.current_frame;
+
             // Only render enabled animations.
-            let animations = model_animations
+            // Applying no animations resets the bones to the skel's transforms for the T-pose.
+            // TODO: Avoid allocating the sampled animations each frame?
+            let animations: Vec<_> = model_animations
                 .iter()
+                .filter(|_| !self.animation_state.force_tpose)
                 .filter(|anim_slot| anim_slot.is_enabled)
                 .filter_map(|anim_slot| {
-                    anim_slot
+                    let anim = anim_slot
                         .animation
                         .and_then(|anim_index| anim_index.get_animation(&self.models))
-                        .and_then(|(_, a)| a.as_ref().ok())
-                });
+                        .and_then(|(_, a)| a.as_ref().ok())?;
+
+                    // All animations are applied at the same frame.
+                    // Sample other frames to constant values to play them at a different rate.
+                    let frame = anim_slot.slot_frame(current_frame, anim.final_frame_index);
+                    if frame == current_frame {
+                        Some(Cow::Borrowed(anim))
+                    } else {
+                        Some(Cow::Owned(sample_anim(anim, frame)))
+                    }
+                })
+                .collect();
 
             render_model.apply_anims(
                 queue,
-                animations,
+                animations.iter().map(|a| a.as_ref()),
                 model
                     .model
                     .skels
@@ -166,20 +234,30 @@ fn update_camera(
     height: f32,
     scale_factor: f64,
 ) {
+    let transforms = camera_transforms(width, height, scale_factor, &camera_state.values);
+    renderer.update_camera(queue, transforms);
+
+    // Needed for bone name rendering.
+    camera_state.mvp_matrix = transforms.mvp_matrix;
+}
+
+/// Calculate the renderer's camera data for a viewport of size `width` x `height`.
+pub fn camera_transforms(
+    width: f32,
+    height: f32,
+    scale_factor: f64,
+    camera_values: &CameraValues,
+) -> CameraTransforms {
     let (camera_pos, model_view_matrix, projection_matrix, mvp_matrix) =
-        calculate_mvp(width, height, &camera_state.values);
-    let transforms = CameraTransforms {
+        calculate_mvp(width, height, camera_values);
+    CameraTransforms {
         model_view_matrix,
         mvp_matrix,
         projection_matrix,
         mvp_inv_matrix: mvp_matrix.inverse(),
         camera_pos,
         screen_dimensions: glam::Vec4::new(width, height, scale_factor as f32, 0.0),
-    };
-    renderer.update_camera(queue, transforms);
-
-    // Needed for bone name rendering.
-    camera_state.mvp_matrix = mvp_matrix;
+    }
 }
 
 // TODO: Separate module for camera + input handling?
@@ -213,3 +291,215 @@ pub fn calculate_mvp(
         projection_matrix * model_view_matrix,
     )
 }
+
+/// The projected position of a bone and its parent in screen space.
+pub struct ScreenBone {
+    pub name: String,
+    pub position: egui::Pos2,
+    pub parent_position: Option<egui::Pos2>,
+}
+
+/// Calculate the screen position in `rect` of each bone for the visible models.
+/// The `world_positions` for each folder index and skel should account for any playing animations.
+pub fn bone_screen_positions<F>(
+    models: &[ModelFolderState],
+    render_models: &[RenderModel],
+    rect: egui::Rect,
+    mvp_matrix: glam::Mat4,
+    world_positions: F,
+) -> Vec<ScreenBone>
+where
+    F: Fn(usize, &SkelData) -> Vec<glam::Vec3>,
+{
+    models
+        .iter()
+        .zip(render_models)
+        .enumerate()
+        .filter(|(_, (_, render_model))| render_model.is_visible)
+        .filter_map(|(i, (model, _))| Some((i, find_file(&model.model.skels, "model.nusktb")?)))
+        .flat_map(|(i, skel)| {
+            let positions: Vec<_> = world_positions(i, skel)
+                .into_iter()
+                .map(|p| project_to_screen(mvp_matrix, p.extend(1.0), rect))
+                .collect();
+
+            skel.bones
+                .iter()
+                .zip(positions.iter())
+                .filter_map(|(bone, position)| {
+                    Some(ScreenBone {
+                        name: bone.name.clone(),
+                        position: (*position)?,
+                        parent_position: bone
+                            .parent_index
+                            .and_then(|p| positions.get(p).copied().flatten()),
+                    })
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// Calculate the screen space line segments in `rect` for the local X, Y, and Z axes of each bone for the visible models.
+/// The axes have the given `length` before applying the scale from the world transform
+/// and use `axis_colors` for the X, Y, and Z axes.
+pub fn bone_axes_screen_lines<F>(
+    models: &[ModelFolderState],
+    render_models: &[RenderModel],
+    rect: egui::Rect,
+    mvp_matrix: glam::Mat4,
+    length: f32,
+    axis_colors: [egui::Color32; 3],
+    world_transforms: F,
+) -> Vec<([egui::Pos2; 2], egui::Color32)>
+where
+    F: Fn(usize, &SkelData) -> Vec<glam::Mat4>,
+{
+    let axes = [
+        (glam::Vec3::X, axis_colors[0]),
+        (glam::Vec3::Y, axis_colors[1]),
+        (glam::Vec3::Z, axis_colors[2]),
+    ];
+
+    models
+        .iter()
+        .zip(render_models)
+        .enumerate()
+        .filter(|(_, (_, render_model))| render_model.is_visible)
+        .filter_map(|(i, (model, _))| Some((i, find_file(&model.model.skels, "model.nusktb")?)))
+        .flat_map(|(i, skel)| world_transforms(i, skel))
+        .flat_map(|transform| {
+            let origin = transform.transform_point3(glam::Vec3::ZERO);
+            axes.iter().filter_map(move |(axis, color)| {
+                let end = transform.transform_point3(*axis * length);
+                let start = project_to_screen(mvp_matrix, origin.extend(1.0), rect)?;
+                let end = project_to_screen(mvp_matrix, end.extend(1.0), rect)?;
+                Some(([start, end], *color))
+            })
+        })
+        .collect()
+}
+
+/// Calculate the screen space line segments in `rect` for a circle in the XY, XZ, and YZ planes
+/// of the bounding sphere of each visible mesh object.
+/// Only the meshes matching `selected` by folder index and name are included if present.
+pub fn bounding_sphere_screen_lines(
+    models: &mut [ModelFolderState],
+    render_models: &[RenderModel],
+    rect: egui::Rect,
+    mvp_matrix: glam::Mat4,
+    selected: Option<(usize, &str)>,
+) -> Vec<[egui::Pos2; 2]> {
+    const SEGMENTS: usize = 32;
+
+    models
+        .iter_mut()
+        .zip(render_models)
+        .enumerate()
+        .filter(|(_, (_, render_model))| render_model.is_visible)
+        .flat_map(|(i, (model, render_model))| {
+            model
+                .mesh_bounds()
+                .iter()
+                .filter(|b| {
+                    selected
+                        .map(|(f, name)| f == i && b.name == name)
+                        .unwrap_or(true)
+                })
+                .filter(|b| {
+                    render_model
+                        .meshes
+                        .iter()
+                        .any(|m| m.name == b.name && m.subindex == b.subindex && m.is_visible)
+                })
+                .flat_map(|b| {
+                    let point = |axes: (glam::Vec3, glam::Vec3), s: usize| {
+                        let angle = s as f32 / SEGMENTS as f32 * std::f32::consts::TAU;
+                        let p = b.center + (axes.0 * angle.cos() + axes.1 * angle.sin()) * b.radius;
+                        project_to_screen(mvp_matrix, p.extend(1.0), rect)
+                    };
+
+                    [
+                        (glam::Vec3::X, glam::Vec3::Y),
+                        (glam::Vec3::X, glam::Vec3::Z),
+                        (glam::Vec3::Y, glam::Vec3::Z),
+                    ]
+                    .into_iter()
+                    .flat_map(move |axes| {
+                        (0..SEGMENTS)
+                            .filter_map(move |s| Some([point(axes, s)?, point(axes, s + 1)?]))
+                    })
+                    .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// Find the folder index and name of the closest visible mesh object under `position` in `rect`.
+/// This only tests the ray against each mesh's bounding box for performance.
+pub fn pick_mesh(
+    models: &mut [ModelFolderState],
+    render_models: &[RenderModel],
+    rect: egui::Rect,
+    mvp_matrix: glam::Mat4,
+    position: egui::Pos2,
+) -> Option<(usize, String)> {
+    let (origin, direction) = screen_ray(mvp_matrix.inverse(), position, rect);
+
+    models
+        .iter_mut()
+        .zip(render_models)
+        .enumerate()
+        .filter(|(_, (_, render_model))| render_model.is_visible)
+        .flat_map(|(i, (model, render_model))| {
+            model
+                .mesh_bounds()
+                .iter()
+                .filter(|b| {
+                    render_model
+                        .meshes
+                        .iter()
+                        .any(|m| m.name == b.name && m.subindex == b.subindex && m.is_visible)
+                })
+                .filter_map(|b| Some((b.intersect_ray(origin, direction)?, i, b.name.clone())))
+                .collect::<Vec<_>>()
+        })
+        .min_by(|(a, _, _), (b, _, _)| a.total_cmp(b))
+        .map(|(_, i, name)| (i, name))
+}
+
+/// Calculate the world space origin and direction of the ray through `position` in `rect`.
+pub fn screen_ray(
+    mvp_inv_matrix: glam::Mat4,
+    position: egui::Pos2,
+    rect: egui::Rect,
+) -> (glam::Vec3, glam::Vec3) {
+    // Convert screen coordinates with y pointing down to normalized device coordinates.
+    let x = (position.x - rect.min.x) / rect.width() * 2.0 - 1.0;
+    let y = 1.0 - (position.y - rect.min.y) / rect.height() * 2.0;
+
+    // Unproject points on the near and far planes using the wgpu depth range of 0.0 to 1.0.
+    let near = mvp_inv_matrix.project_point3(glam::vec3(x, y, 0.0));
+    let far = mvp_inv_matrix.project_point3(glam::vec3(x, y, 1.0));
+    (near, (far - near).normalize())
+}
+
+fn project_to_screen(
+    mvp_matrix: glam::Mat4,
+    position: glam::Vec4,
+    rect: egui::Rect,
+) -> Option<egui::Pos2> {
+    let clip = mvp_matrix * position;
+    // Skip points behind the camera.
+    if clip.w <= 0.0 {
+        return None;
+    }
+
+    // Convert normalized device coordinates to screen coordinates with y pointing down.
+    let ndc = clip.truncate() / clip.w;
+    Some(egui::pos2(
+        rect.min.x + (ndc.x * 0.5 + 0.5) * rect.width(),
+        rect.min.y + (0.5 - ndc.y * 0.5) * rect.height(),
+    ))
+}
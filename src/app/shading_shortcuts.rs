@@ -0,0 +1,81 @@
+use egui::{Align2, Area, Context, Frame, Id, Rect};
+use ssbh_wgpu::{DebugMode, ModelRenderOptions, RenderSettings};
+
+use crate::preferences::{ShadingShortcut, ShadingShortcutButton};
+
+/// Show the enabled shortcut buttons centered at the bottom of the viewport `rect`.
+pub fn shading_shortcut_bar(
+    ctx: &Context,
+    rect: Rect,
+    shortcuts: &[ShadingShortcutButton],
+    settings: &mut RenderSettings,
+    options: &mut ModelRenderOptions,
+) -> bool {
+    let mut changed = false;
+
+    Area::new(Id::new("shading_shortcut_bar"))
+        .pivot(Align2::CENTER_BOTTOM)
+        .fixed_pos(rect.center_bottom() - egui::vec2(0.0, 8.0))
+        .show(ctx, |ui| {
+            Frame::popup(ui.style()).show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    for button in shortcuts.iter().filter(|b| b.visible) {
+                        let selected = is_selected(button.shortcut, settings, options);
+                        if ui
+                            .selectable_label(selected, button.shortcut.to_string())
+                            .clicked()
+                        {
+                            apply_shortcut(button.shortcut, settings, options);
+                            changed = true;
+                        }
+                    }
+                });
+            });
+        });
+
+    changed
+}
+
+fn shortcut_debug_mode(shortcut: ShadingShortcut) -> DebugMode {
+    match shortcut {
+        ShadingShortcut::Shaded => DebugMode::Shaded,
+        // Wireframe rendering is only supported for debug modes.
+        ShadingShortcut::Wireframe => DebugMode::Basic,
+        ShadingShortcut::Normal => DebugMode::Normals,
+        ShadingShortcut::Uv => DebugMode::Map1,
+        ShadingShortcut::VertexColor => DebugMode::ColorSet1,
+        ShadingShortcut::Silhouette => DebugMode::Basic,
+    }
+}
+
+// The silhouette shows only the alpha channel to render meshes as solid white.
+const SILHOUETTE_RGBA: [bool; 4] = [false, false, false, true];
+
+fn is_selected(
+    shortcut: ShadingShortcut,
+    settings: &RenderSettings,
+    options: &ModelRenderOptions,
+) -> bool {
+    let is_silhouette = settings.render_rgba == SILHOUETTE_RGBA;
+    settings.debug_mode == shortcut_debug_mode(shortcut)
+        && match shortcut {
+            ShadingShortcut::Wireframe => options.draw_wireframe && !is_silhouette,
+            ShadingShortcut::Silhouette => is_silhouette,
+            ShadingShortcut::Shaded => true,
+            _ => !is_silhouette && !options.draw_wireframe,
+        }
+}
+
+fn apply_shortcut(
+    shortcut: ShadingShortcut,
+    settings: &mut RenderSettings,
+    options: &mut ModelRenderOptions,
+) {
+    settings.debug_mode = shortcut_debug_mode(shortcut);
+    settings.render_rgba = if shortcut == ShadingShortcut::Silhouette {
+        SILHOUETTE_RGBA
+    } else {
+        [true; 4]
+    };
+    options.draw_wireframe = shortcut == ShadingShortcut::Wireframe;
+}
@@ -1,7 +1,10 @@
 mod camera;
 mod device_info;
+mod folder_notes;
 mod log;
+mod model_render_options;
 mod new_release;
+mod plugins;
 mod preferences;
 mod render_settings;
 mod stage_lighting;
@@ -9,7 +12,10 @@ mod stage_lighting;
 pub use self::log::log_window;
 pub use camera::camera_settings_window;
 pub use device_info::device_info_window;
+pub use folder_notes::folder_notes_window;
+pub use model_render_options::model_render_options_window;
 pub use new_release::new_release_window;
+pub use plugins::validation_plugins_window;
 pub use preferences::preferences_window;
 pub use render_settings::render_settings_window;
 pub use stage_lighting::stage_lighting_window;
@@ -3,13 +3,17 @@ use std::path::PathBuf;
 use egui::{Button, DragValue, Label, TextWrapMode, Ui};
 use rfd::FileDialog;
 
-use crate::{horizontal_separator_empty, CameraState, CameraValues};
+use crate::{
+    horizontal_separator_empty, preferences::CameraBookmark, widgets::tooltip_text, CameraState,
+    CameraValues,
+};
 
 pub fn camera_settings_window(
     ctx: &egui::Context,
     open: &mut bool,
     camera_state: &mut CameraState,
     default_camera: &mut CameraValues,
+    bookmarks: &mut Vec<CameraBookmark>,
 ) -> bool {
     let mut changed = false;
 
@@ -59,35 +63,35 @@ pub fn camera_settings_window(
             ui.separator();
 
             egui::Grid::new("camera_grid").show(ui, |ui| {
-                ui.label("Translation X");
+                tooltip_text(ui, "Translation X", "The horizontal camera position.");
                 changed |= ui
                     .add(DragValue::new(&mut camera_state.values.translation.x))
                     .changed();
                 ui.end_row();
 
-                ui.label("Translation Y");
+                tooltip_text(ui, "Translation Y", "The vertical camera position.");
                 changed |= ui
                     .add(DragValue::new(&mut camera_state.values.translation.y))
                     .changed();
                 ui.end_row();
 
-                ui.label("Translation Z");
+                tooltip_text(ui, "Translation Z", "The camera distance from the origin. Use negative values to move away from the model.");
                 changed |= ui
                     .add(DragValue::new(&mut camera_state.values.translation.z))
                     .changed();
                 ui.end_row();
 
-                ui.label("Rotation X");
+                tooltip_text(ui, "Rotation X", "The camera pitch in degrees.");
                 changed |= edit_angle_degrees(ui, &mut camera_state.values.rotation_radians.x);
                 ui.end_row();
 
-                ui.label("Rotation Y");
+                tooltip_text(ui, "Rotation Y", "The camera yaw in degrees.");
                 changed |= edit_angle_degrees(ui, &mut camera_state.values.rotation_radians.y);
                 ui.end_row();
 
                 // All three axes are necessary to decompose in game animations.
                 // Most users won't touch this value.
-                ui.label("Rotation Z");
+                tooltip_text(ui, "Rotation Z", "The camera roll in degrees.");
                 changed |= edit_angle_degrees(ui, &mut camera_state.values.rotation_radians.z);
                 ui.end_row();
 
@@ -130,7 +134,7 @@ pub fn camera_settings_window(
             horizontal_separator_empty(ui);
 
             ui.horizontal(|ui| {
-                ui.label("Camera Anim");
+                tooltip_text(ui, "Camera Anim", "The camera .nuanmb to apply when playing animations.");
                 path_label(ui, &camera_state.anim_path);
                 if ui.button("Select file...").clicked() {
                     if let Some(file) = FileDialog::new()
@@ -144,6 +148,53 @@ pub fn camera_settings_window(
             });
             horizontal_separator_empty(ui);
 
+            ui.heading("Bookmarks");
+            let mut bookmark_to_remove = None;
+            let duplicate_names = duplicate_bookmark_names(bookmarks);
+            egui::Grid::new("camera_bookmarks_grid").show(ui, |ui| {
+                for (i, bookmark) in bookmarks.iter_mut().enumerate() {
+                    if duplicate_names.contains(&bookmark.name) {
+                        let color = ui.visuals().error_fg_color;
+                        ui.add(egui::TextEdit::singleline(&mut bookmark.name).text_color(color))
+                        .on_hover_text(
+                            "Bookmarks with the same name overwrite each other's batch screenshots.",
+                        );
+                    } else {
+                        ui.text_edit_singleline(&mut bookmark.name);
+                    }
+                    if ui.button("Load").clicked() {
+                        camera_state.values = bookmark.values.clone();
+                        changed = true;
+                    }
+                    if ui
+                        .button("Update")
+                        .on_hover_text("Replace the bookmark with the current settings.")
+                        .clicked()
+                    {
+                        bookmark.values = camera_state.values.clone();
+                    }
+                    if ui.button("Delete").clicked() {
+                        bookmark_to_remove = Some(i);
+                    }
+                    ui.end_row();
+                }
+            });
+            if let Some(i) = bookmark_to_remove {
+                bookmarks.remove(i);
+            }
+
+            if ui
+                .button("Add Bookmark")
+                .on_hover_text("Save the current settings as a new bookmark.")
+                .clicked()
+            {
+                bookmarks.push(CameraBookmark {
+                    name: new_bookmark_name(bookmarks),
+                    values: camera_state.values.clone(),
+                });
+            }
+            horizontal_separator_empty(ui);
+
             if ui
                 .button("Reset")
                 .on_hover_text("Reset settings to their configured defaults.")
@@ -183,3 +234,65 @@ fn path_label(ui: &mut Ui, path: &Option<PathBuf>) {
         }
     }
 }
+
+/// The first name like "camera0" not already used by any of the `bookmarks`.
+fn new_bookmark_name(bookmarks: &[CameraBookmark]) -> String {
+    (0..)
+        .map(|i| format!("camera{i}"))
+        .find(|name| bookmarks.iter().all(|b| &b.name != name))
+        .unwrap_or_default()
+}
+
+fn duplicate_bookmark_names(bookmarks: &[CameraBookmark]) -> Vec<String> {
+    let mut duplicates = Vec::new();
+    for (i, bookmark) in bookmarks.iter().enumerate() {
+        if bookmarks[..i].iter().any(|b| b.name == bookmark.name)
+            && !duplicates.contains(&bookmark.name)
+        {
+            duplicates.push(bookmark.name.clone());
+        }
+    }
+    duplicates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bookmark(name: &str) -> CameraBookmark {
+        CameraBookmark {
+            name: name.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn new_bookmark_name_empty() {
+        assert_eq!("camera0", new_bookmark_name(&[]));
+    }
+
+    #[test]
+    fn new_bookmark_name_after_delete() {
+        // Using the length would reuse "camera1" after deleting "camera0".
+        let bookmarks = [bookmark("camera1")];
+        assert_eq!("camera0", new_bookmark_name(&bookmarks));
+
+        let bookmarks = [bookmark("camera0"), bookmark("camera1")];
+        assert_eq!("camera2", new_bookmark_name(&bookmarks));
+    }
+
+    #[test]
+    fn duplicate_bookmark_names_repeated() {
+        let bookmarks = [
+            bookmark("front"),
+            bookmark("side"),
+            bookmark("front"),
+            bookmark("front"),
+        ];
+        assert_eq!(
+            vec!["front".to_owned()],
+            duplicate_bookmark_names(&bookmarks)
+        );
+        assert!(duplicate_bookmark_names(&bookmarks[..2]).is_empty());
+    }
+}
@@ -1,4 +1,9 @@
-pub fn device_info_window(ctx: &egui::Context, open: &mut bool, info: &wgpu::AdapterInfo) {
+pub fn device_info_window(
+    ctx: &egui::Context,
+    open: &mut bool,
+    info: &wgpu::AdapterInfo,
+    sample_count: u32,
+) {
     egui::Window::new("Device Info")
         .resizable(false)
         .open(open)
@@ -31,6 +36,11 @@ pub fn device_info_window(ctx: &egui::Context, open: &mut bool, info: &wgpu::Ada
                 ui.label("Backend");
                 ui.label(format!("{:?}", info.backend));
                 ui.end_row();
+
+                ui.label("Sample Count")
+                    .on_hover_text("The effective number of samples per pixel for screenshots.");
+                ui.label(sample_count.to_string());
+                ui.end_row();
             });
         });
 }
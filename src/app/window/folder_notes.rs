@@ -0,0 +1,37 @@
+use egui::{Context, TextEdit, Window};
+
+use crate::project::ProjectFile;
+
+pub fn folder_notes_window(ctx: &Context, folder: &mut Option<String>, project: &mut ProjectFile) {
+    let mut open = folder.is_some();
+    if let Some(folder_path) = folder {
+        Window::new("Folder Notes")
+            .open(&mut open)
+            .resizable(true)
+            .show(ctx, |ui| {
+                ui.label(folder_path.as_str());
+                ui.label("Notes are saved with the editor and don't modify any files.");
+                ui.separator();
+
+                let notes = project.folder_notes.entry(folder_path.clone()).or_default();
+                ui.add(
+                    TextEdit::multiline(notes)
+                        .desired_width(f32::INFINITY)
+                        .desired_rows(8),
+                );
+            });
+
+        // Avoid storing entries for folders without notes.
+        if project
+            .folder_notes
+            .get(folder_path)
+            .is_some_and(|n| n.is_empty())
+        {
+            project.folder_notes.remove(folder_path);
+        }
+    }
+
+    if !open {
+        *folder = None;
+    }
+}
@@ -1,24 +1,128 @@
-use egui::{Context, Label, ScrollArea, Window};
+use std::sync::atomic::Ordering;
 
-use crate::app::{log_level_icon, LOGGER};
+use egui::{Button, Context, Label, RichText, ScrollArea, Window};
+use log::error;
+use rfd::FileDialog;
 
-pub fn log_window(ctx: &Context, open: &mut bool) {
+use crate::{
+    app::{log_level_icon, LogWindowState, LOGGER},
+    log::{clean_message, write_log_file},
+};
+
+pub fn log_window(ctx: &Context, open: &mut bool, state: &mut LogWindowState) {
     Window::new("Application Log")
         .open(open)
         .resizable(true)
         .show(ctx, |ui| {
-            ScrollArea::vertical()
+            let message_count = LOGGER.messages.lock().unwrap().len();
+            let max_messages = LOGGER.max_messages.load(Ordering::Relaxed);
+            ui.label(format!("{message_count}/{max_messages} messages"));
+
+            let mut filter_changed = false;
+            let mut messages_changed = false;
+            let mut scroll_to_bottom = false;
+            ui.horizontal(|ui| {
+                ui.label("Filter");
+                filter_changed |= ui.text_edit_singleline(&mut state.filter).changed();
+                if ui.button("Clear Filter").clicked() {
+                    state.filter.clear();
+                    filter_changed = true;
+                }
+            });
+            ui.horizontal(|ui| {
+                if ui.button("Clear").clicked() {
+                    // Keep a copy of the messages to allow restoring them.
+                    let mut messages = LOGGER.messages.lock().unwrap();
+                    state.cleared_messages = Some(std::mem::take(&mut *messages));
+                    messages_changed = true;
+                }
+
+                if ui
+                    .add_enabled(state.cleared_messages.is_some(), Button::new("Restore"))
+                    .on_hover_text("Restore the messages removed by the last clear.")
+                    .clicked()
+                {
+                    if let Some(cleared_messages) = state.cleared_messages.take() {
+                        LOGGER.restore_messages(cleared_messages);
+                        messages_changed = true;
+                    }
+                }
+
+                if ui
+                    .button("Export Log...")
+                    .on_hover_text("Save all messages to a text file, ignoring any filters.")
+                    .clicked()
+                {
+                    if let Some(file) = FileDialog::new().add_filter("Text", &["txt"]).save_file() {
+                        // Release the lock before logging any errors to avoid a deadlock.
+                        let result = write_log_file(&file, &LOGGER.messages.lock().unwrap());
+                        if let Err(e) = result {
+                            error!("Failed to save {:?}: {}", file, e);
+                        }
+                    }
+                }
+                ui.separator();
+
+                filter_changed |= ui.checkbox(&mut state.show_debug, "Debug").changed();
+                filter_changed |= ui.checkbox(&mut state.show_info, "Info").changed();
+                filter_changed |= ui.checkbox(&mut state.show_warn, "Warn").changed();
+                filter_changed |= ui.checkbox(&mut state.show_error, "Error").changed();
+                ui.separator();
+
+                ui.checkbox(&mut state.show_timestamps, "Show Timestamps");
+                let scroll_changed = ui
+                    .checkbox(&mut state.auto_scroll, "Auto-scroll")
+                    .on_hover_text("Keep the most recent messages visible.")
+                    .changed();
+                scroll_to_bottom = scroll_changed && state.auto_scroll;
+            });
+            ui.separator();
+
+            let mut scroll_area = ScrollArea::vertical()
                 .auto_shrink([false; 2])
-                .show(ui, |ui| {
-                    for (level, message) in LOGGER.messages.lock().unwrap().iter() {
-                        ui.horizontal(|ui| {
-                            log_level_icon(ui, level);
-                            // binrw formats backtraces, which isn't supported by egui font rendering.
-                            let clean_message = strip_ansi_escapes::strip(message);
-                            let clean_message = String::from_utf8_lossy(&clean_message);
-                            ui.add(Label::new(clean_message).wrap());
-                        });
+                .stick_to_bottom(state.auto_scroll);
+            if filter_changed {
+                // Show the start of the filtered messages.
+                scroll_area = scroll_area.vertical_scroll_offset(0.0);
+            } else if scroll_to_bottom {
+                // The offset is clamped to the end of the content.
+                scroll_area = scroll_area.vertical_scroll_offset(f32::MAX);
+            }
+
+            let filter = state.filter.to_lowercase();
+            let output = scroll_area.show(ui, |ui| {
+                for (time, level, message) in LOGGER.messages.lock().unwrap().iter() {
+                    if !state.is_level_visible(level) {
+                        continue;
                     }
-                });
+
+                    let clean_message = clean_message(message);
+                    if !clean_message.to_lowercase().contains(&filter) {
+                        continue;
+                    }
+
+                    ui.horizontal(|ui| {
+                        if state.show_timestamps {
+                            // Monospace keeps the timestamps aligned between rows.
+                            let timestamp = format!("[{}]", time.format("%H:%M:%S%.3f"));
+                            ui.label(RichText::new(timestamp).monospace());
+                        }
+                        log_level_icon(ui, level);
+                        ui.add(Label::new(clean_message).wrap());
+                    });
+                }
+            });
+
+            // Scrolling up pauses auto-scroll until scrolling back to the bottom.
+            // Clearing or filtering also moves the offset without the user scrolling.
+            let offset = output.state.offset.y;
+            let max_offset = (output.content_size.y - output.inner_rect.height()).max(0.0);
+            let user_scrolled = !filter_changed && !messages_changed;
+            if offset > state.previous_scroll_offset && offset >= max_offset - 1.0 {
+                state.auto_scroll = true;
+            } else if user_scrolled && offset < state.previous_scroll_offset {
+                state.auto_scroll = false;
+            }
+            state.previous_scroll_offset = offset;
         });
 }
@@ -0,0 +1,55 @@
+use std::collections::HashMap;
+
+use egui::{Context, Window};
+use ssbh_wgpu::ModelRenderOptions;
+
+pub fn model_render_options_window(
+    ctx: &Context,
+    folder_index: &mut Option<usize>,
+    folder_name: &str,
+    global_options: &ModelRenderOptions,
+    per_model_options: &mut HashMap<usize, ModelRenderOptions>,
+) {
+    let mut open = folder_index.is_some();
+    if let Some(folder_index) = folder_index {
+        Window::new("Folder Render Settings")
+            .open(&mut open)
+            .resizable(false)
+            .show(ctx, |ui| {
+                ui.label(folder_name);
+                ui.label(
+                    "These settings apply when this folder is the only visible model \
+                    in the viewport or in a pane of the split viewport. \
+                    Models in the same pane can't be rendered with different settings.",
+                );
+                ui.separator();
+
+                match per_model_options.get_mut(folder_index) {
+                    Some(options) => {
+                        ui.checkbox(&mut options.draw_wireframe, "Wireframe");
+                        ui.checkbox(&mut options.draw_floor_grid, "Floor Grid");
+                        ui.checkbox(&mut options.draw_bones, "Draw Bones");
+                        ui.checkbox(&mut options.draw_bone_axes, "Draw Bone Axes");
+
+                        if ui
+                            .button("Reset to Global")
+                            .on_hover_text("Remove the override and use the render settings.")
+                            .clicked()
+                        {
+                            per_model_options.remove(folder_index);
+                        }
+                    }
+                    None => {
+                        ui.label("This folder uses the global render settings.");
+                        if ui.button("Override").clicked() {
+                            per_model_options.insert(*folder_index, global_options.clone());
+                        }
+                    }
+                }
+            });
+    }
+
+    if !open {
+        *folder_index = None;
+    }
+}
@@ -0,0 +1,50 @@
+use egui::{Context, Label, ScrollArea, Window};
+
+use crate::{
+    app::warning_icon_text,
+    model_folder::ModelFolderState,
+    path::{folder_display_name, plugins_dir},
+    plugins::{ValidationError, ValidationPlugin},
+};
+
+pub fn validation_plugins_window(
+    ctx: &Context,
+    open: &mut bool,
+    plugins: &[Box<dyn ValidationPlugin>],
+    errors: &[ValidationError],
+    models: &[ModelFolderState],
+) {
+    Window::new("Validation Plugins")
+        .open(open)
+        .resizable(true)
+        .show(ctx, |ui| {
+            ui.heading("Plugins");
+            for plugin in plugins {
+                ui.label(plugin.name());
+            }
+            if ui
+                .button("Open Plugins Directory...")
+                .on_hover_text("Shared libraries in this directory are loaded on startup.")
+                .clicked()
+            {
+                let path = plugins_dir();
+                if let Err(e) = std::fs::create_dir_all(&path).and_then(|_| open::that(&path)) {
+                    log::error!("Failed to open {path:?}: {e}");
+                }
+            }
+            ui.separator();
+
+            ui.heading(format!("Errors ({})", errors.len()));
+            ScrollArea::vertical()
+                .auto_shrink([false; 2])
+                .show(ui, |ui| {
+                    for error in errors {
+                        let folder = models
+                            .get(error.folder_index)
+                            .map(folder_display_name)
+                            .unwrap_or_default();
+                        ui.add(Label::new(warning_icon_text(&format!("{folder}/{error}"))).wrap());
+                    }
+                });
+        });
+}
@@ -1,15 +1,22 @@
 use std::str::FromStr;
 
 use crate::{
+    app::draggable_icon,
+    horizontal_separator_empty,
     path::application_dir,
-    preferences::{AppPreferences, GraphicsBackend},
+    preferences::{
+        AppPreferences, ColorBlindMode, GraphicsBackend, ScreenshotResolution,
+        ShadingShortcutButton,
+    },
+    widgets_dark,
 };
 
 use egui::{
     special_emojis::{OS_APPLE, OS_LINUX, OS_WINDOWS},
     DragValue, TextWrapMode, Ui, Window,
 };
-use strum::VariantNames;
+use egui_dnd::dnd;
+use strum::{IntoEnumIterator, VariantNames};
 
 pub fn preferences_window(
     ctx: &egui::Context,
@@ -54,22 +61,58 @@ pub fn preferences_window(
             ui.separator();
 
             changed |= edit_preferences(ui, preferences);
+            horizontal_separator_empty(ui);
+
+            let dark_mode = preferences.dark_mode;
+            changed |=
+                edit_shading_shortcuts(ctx, ui, &mut preferences.shading_shortcuts, dark_mode);
         });
     changed
 }
 
 fn edit_preferences(ui: &mut Ui, preferences: &mut AppPreferences) -> bool {
     let mut changed = false;
-    // TODO: Add a toggle widget instead.
-    changed |= ui
-        .checkbox(&mut preferences.dark_mode, "Dark Mode")
-        .changed();
+    ui.horizontal(|ui| {
+        ui.label("Theme");
+        egui::ComboBox::from_id_salt("theme")
+            .selected_text(if preferences.dark_mode {
+                "Dark"
+            } else {
+                "Light"
+            })
+            .show_ui(ui, |ui| {
+                changed |= ui
+                    .selectable_value(&mut preferences.dark_mode, true, "Dark")
+                    .changed();
+                changed |= ui
+                    .selectable_value(&mut preferences.dark_mode, false, "Light")
+                    .changed();
+            });
+    });
     ui.horizontal(|ui| {
         changed |= ui
             .color_edit_button_srgb(&mut preferences.viewport_color)
             .changed();
         ui.label("Viewport Background");
     });
+    ui.horizontal(|ui| {
+        let mut use_accent_color = preferences.accent_color.is_some();
+        if ui
+            .checkbox(&mut use_accent_color, "Custom Accent Color")
+            .on_hover_text("Use a custom color for hovered and clicked widgets.")
+            .changed()
+        {
+            // Start from the highlight color of the dark theme.
+            let color = widgets_dark().active.fg_stroke.color;
+            preferences.accent_color =
+                use_accent_color.then_some([color.r(), color.g(), color.b()]);
+            changed = true;
+        }
+
+        if let Some(color) = &mut preferences.accent_color {
+            changed |= ui.color_edit_button_srgb(color).changed();
+        }
+    });
     changed |= ui
         .checkbox(
             &mut preferences.autohide_expressions,
@@ -82,6 +125,56 @@ fn edit_preferences(ui: &mut Ui, preferences: &mut AppPreferences) -> bool {
             "Automatically Hide Ink Meshes",
         )
         .changed();
+    changed |= ui
+        .checkbox(&mut preferences.clear_log_on_load, "Clear Log on Load")
+        .on_hover_text("Clear the application log when opening a new folder.")
+        .changed();
+    ui.horizontal(|ui| {
+        ui.label("Max Log Messages")
+            .on_hover_text("The oldest log messages are removed after reaching this limit.");
+        changed |= ui
+            .add(
+                DragValue::new(&mut preferences.max_log_messages)
+                    .update_while_editing(false)
+                    .range(100..=100000),
+            )
+            .changed();
+    });
+    ui.horizontal(|ui| {
+        let mut use_custom_resolution = preferences.screenshot_resolution.is_some();
+        if ui
+            .checkbox(&mut use_custom_resolution, "Custom Screenshot Resolution")
+            .on_hover_text("Save screenshots at a fixed size instead of the viewport size.")
+            .changed()
+        {
+            preferences.screenshot_resolution =
+                use_custom_resolution.then(ScreenshotResolution::default);
+            changed = true;
+        }
+
+        if let Some(resolution) = &mut preferences.screenshot_resolution {
+            changed |= ui
+                .add(DragValue::new(&mut resolution.width).range(1..=8192))
+                .changed();
+            ui.label("x");
+            changed |= ui
+                .add(DragValue::new(&mut resolution.height).range(1..=8192))
+                .changed();
+        }
+    });
+    ui.horizontal(|ui| {
+        ui.label("Bone Axes Length")
+            .on_hover_text("The length of each axis for Show Bone Axes in the render settings.");
+        changed |= ui
+            .add(
+                DragValue::new(&mut preferences.bone_axes_length)
+                    .update_while_editing(false)
+                    .speed(0.1)
+                    .range(0.01..=100.0),
+            )
+            .changed();
+    });
+
     ui.horizontal(|ui| {
         ui.label("Graphics Backend").on_hover_text(
             "The preferred graphics backend. Requires an application restart to take effect.",
@@ -102,6 +195,43 @@ fn edit_preferences(ui: &mut Ui, preferences: &mut AppPreferences) -> bool {
             .changed();
     });
 
+    ui.horizontal(|ui| {
+        ui.label("Font Scale")
+            .on_hover_text("Scale the size of text and padding in the UI.");
+        changed |= ui
+            .add(
+                DragValue::new(&mut preferences.font_scale)
+                    .update_while_editing(false)
+                    .speed(0.05)
+                    .range(0.75..=2.0),
+            )
+            .changed();
+    });
+
+    ui.horizontal(|ui| {
+        ui.label("Color Blind Mode").on_hover_text(
+            "Use alternative colors for errors, the Show Bone Axes overlay, \
+            and the XYZ curves of animated material parameters. \
+            This doesn't change the colors of Draw Bone Axes.",
+        );
+        egui::ComboBox::from_id_salt("color_blind_mode")
+            .selected_text(preferences.color_blind_mode.to_string())
+            .show_ui(ui, |ui| {
+                for mode in ColorBlindMode::iter() {
+                    changed |= ui
+                        .selectable_value(&mut preferences.color_blind_mode, mode, mode.to_string())
+                        .changed();
+                }
+            });
+    });
+
+    changed |= ui
+        .checkbox(&mut preferences.compact_ui, "Compact UI")
+        .on_hover_text(
+            "Use smaller text and padding to fit more on screen. Toggle with Ctrl+Shift+C.",
+        )
+        .changed();
+
     if ui.button("Reset Preferences").clicked() {
         *preferences = AppPreferences::default();
         changed = true;
@@ -110,6 +240,41 @@ fn edit_preferences(ui: &mut Ui, preferences: &mut AppPreferences) -> bool {
     changed
 }
 
+fn edit_shading_shortcuts(
+    ctx: &egui::Context,
+    ui: &mut Ui,
+    shortcuts: &mut Vec<ShadingShortcutButton>,
+    dark_mode: bool,
+) -> bool {
+    let mut changed = false;
+
+    ui.heading("Shading Shortcuts").on_hover_text(
+        "The buttons shown at the bottom of the viewport. Drag to reorder the buttons.",
+    );
+
+    // TODO: Avoid allocating here.
+    let mut items: Vec<_> = (0..shortcuts.len()).collect();
+    let response = dnd(ui, "shading_shortcuts_dnd").show_vec(&mut items, |ui, item, handle, _| {
+        ui.horizontal(|ui| {
+            handle.ui(ui, |ui| {
+                draggable_icon(ctx, ui, dark_mode);
+            });
+
+            let button = &mut shortcuts[*item];
+            changed |= ui
+                .checkbox(&mut button.visible, button.shortcut.to_string())
+                .changed();
+        });
+    });
+
+    if let Some(response) = response.final_update() {
+        egui_dnd::utils::shift_vec(response.from, response.to, shortcuts);
+        changed = true;
+    }
+
+    changed
+}
+
 fn edit_graphics_backend(graphics_backend: &mut GraphicsBackend, ui: &mut Ui) -> bool {
     let backend_label = |b: &GraphicsBackend| match b {
         GraphicsBackend::Auto => "Auto".to_owned(),
@@ -1,21 +1,37 @@
-use crate::{horizontal_separator_empty, widgets::*};
+use crate::{capture::ComparisonCapture, horizontal_separator_empty, widgets::*, AntialiasingMode};
 use egui::ScrollArea;
 use ssbh_wgpu::{DebugMode, ModelRenderOptions, RenderSettings, SkinningSettings};
 
+#[allow(clippy::too_many_arguments)]
 pub fn render_settings_window(
     ctx: &egui::Context,
     settings: &mut RenderSettings,
+    antialiasing: &mut AntialiasingMode,
     options: &mut ModelRenderOptions,
     skinning_settings: &mut SkinningSettings,
     open: &mut bool,
     draw_bone_names: &mut bool,
+    show_bone_axes: &mut bool,
     enable_helper_bones: &mut bool,
+    comparison: &mut ComparisonCapture,
 ) {
     egui::Window::new("Render Settings")
         .open(open)
         .resizable(true)
         .show(ctx, |ui| {
             egui::menu::bar(ui, |ui| {
+                ui.menu_button("Compare", |ui| {
+                    // Used for Viewport > Screenshot A/B.
+                    if ui.button("Store Settings as A").clicked() {
+                        ui.close_menu();
+                        comparison.settings_a = Some(settings.clone());
+                    }
+                    if ui.button("Store Settings as B").clicked() {
+                        ui.close_menu();
+                        comparison.settings_b = Some(settings.clone());
+                    }
+                });
+
                 ui.menu_button("Help", |ui| {
                     if ui.button("Render Settings Wiki").clicked() {
                         ui.close_menu();
@@ -36,42 +52,59 @@ pub fn render_settings_window(
                     edit_render_settings(
                         ui,
                         settings,
+                        antialiasing,
                         options,
                         skinning_settings,
                         enable_helper_bones,
                         draw_bone_names,
+                        show_bone_axes,
                     );
                 });
         });
 }
 
+#[allow(clippy::too_many_arguments)]
 fn edit_render_settings(
     ui: &mut egui::Ui,
     settings: &mut RenderSettings,
+    antialiasing: &mut AntialiasingMode,
     options: &mut ModelRenderOptions,
     skinning_settings: &mut SkinningSettings,
     enable_helper_bones: &mut bool,
     draw_bone_names: &mut bool,
+    show_bone_axes: &mut bool,
 ) {
     ui.heading("Debug Shading");
     egui::Grid::new("debug_shading_grid").show(ui, |ui| {
-        // TODO: Add descriptions.
-        ui.label("Debug Mode");
+        tooltip_text(
+            ui,
+            "Debug Mode",
+            "Render a specific attribute or shading component.",
+        );
 
         edit_debug_mode(settings, ui);
 
         ui.end_row();
 
         if settings.debug_mode == ssbh_wgpu::DebugMode::Shaded {
-            ui.label("Transition Material");
+            tooltip_text(
+                ui,
+                "Transition Material",
+                "The material effect like metal box or gold.",
+            );
             enum_combo_box(ui, "Transition Material", &mut settings.transition_material);
             ui.end_row();
 
-            ui.label("Transition Factor");
-            ui.add(DragSlider::new(
-                "transition_factor",
-                &mut settings.transition_factor,
-            ));
+            tooltip_text(
+                ui,
+                "Transition Factor",
+                "The blend amount between the model's materials and the transition material.",
+            );
+            ui.add(
+                DragSlider::new("transition_factor", &mut settings.transition_factor)
+                    .min(0.0)
+                    .max(1.0),
+            );
             ui.end_row();
         }
     });
@@ -96,6 +129,15 @@ fn edit_render_settings(
     ui.checkbox(&mut settings.render_shadows, "Enable Shadows");
     horizontal_separator_empty(ui);
 
+    ui.heading("Antialiasing");
+    egui::Grid::new("antialiasing_grid").show(ui, |ui| {
+        ui.label("Antialiasing Mode")
+            .on_hover_text("The antialiasing method for screenshots and rendered animations.");
+        enum_combo_box(ui, "antialiasing_mode", antialiasing);
+        ui.end_row();
+    });
+    horizontal_separator_empty(ui);
+
     ui.heading("Materials");
     ui.checkbox(&mut settings.render_vertex_color, "Enable Vertex Color")
         .on_hover_text("Render vertex color attributes like colorSet3 or colorSet5.");
@@ -103,7 +145,7 @@ fn edit_render_settings(
         .on_hover_text("Scale color sets by their in game scaling values. Disabling this will use the raw color values." );
 
     egui::Grid::new("enable_texture_channels").show(ui, |ui| {
-        ui.label("Enable Nor Channels");
+        tooltip_text(ui, "Enable Nor Channels", "Toggle individual channels of the normal map textures.");
         ui.horizontal(|ui| {
             ui.toggle_value(&mut settings.render_nor[0], "R");
             ui.toggle_value(&mut settings.render_nor[1], "G");
@@ -112,7 +154,7 @@ fn edit_render_settings(
         });
         ui.end_row();
 
-        ui.label("Enable Prm Channels");
+        tooltip_text(ui, "Enable Prm Channels", "Toggle individual channels of the PRM (metalness, roughness, ambient occlusion, specular) textures.");
         ui.horizontal(|ui| {
             ui.toggle_value(&mut settings.render_prm[0], "R");
             ui.toggle_value(&mut settings.render_prm[1], "G");
@@ -142,8 +184,15 @@ fn edit_render_settings(
 
     ui.heading("Skeleton");
     ui.checkbox(&mut options.draw_bones, "Draw Bones");
-    ui.checkbox(&mut options.draw_bone_axes, "Draw Bone Axes");
+    ui.checkbox(&mut options.draw_bone_axes, "Draw Bone Axes")
+        .on_hover_text("Draw fixed size axes for each bone as part of the bone shapes.");
     ui.checkbox(draw_bone_names, "Draw Bone Names");
+    ui.checkbox(show_bone_axes, "Show Bone Axes").on_hover_text(
+        "Draw thin lines for the local XYZ axes of each bone on top of the viewport \
+        independently from the bone shapes. The axes are scaled by the bone's transform \
+        and use the colors from Color Blind Mode. \
+        The axis length can be changed in the preferences.",
+    );
 }
 
 fn debug_mode_options(
@@ -3,7 +3,7 @@ use std::path::PathBuf;
 use egui::{Grid, Label, TextWrapMode, Ui, Window};
 use rfd::FileDialog;
 
-use crate::app::StageLightingState;
+use crate::{app::StageLightingState, widgets::tooltip_text};
 
 pub fn stage_lighting_window(
     ctx: &egui::Context,
@@ -36,7 +36,11 @@ pub fn stage_lighting_window(
 
             Grid::new("stage_lighting").show(ui, |ui| {
                 // TODO: Make the files buttons to load corresponding editors?
-                ui.label("Lighting");
+                tooltip_text(
+                    ui,
+                    "Lighting",
+                    "The stage lighting .nuanmb like light_set_liveAnimation.nuanmb.",
+                );
                 path_label(ui, &state.light);
                 if ui.button("Select file...").clicked() {
                     if let Some(file) = FileDialog::new()
@@ -49,7 +53,11 @@ pub fn stage_lighting_window(
                 }
                 ui.end_row();
 
-                ui.label("Reflection Cube Map");
+                tooltip_text(
+                    ui,
+                    "Reflection Cube Map",
+                    "The specular cube map used for reflections.",
+                );
                 path_label(ui, &state.reflection_cube_map);
                 if ui.button("Select file...").clicked() {
                     if let Some(file) = FileDialog::new()
@@ -62,7 +70,11 @@ pub fn stage_lighting_window(
                 };
                 ui.end_row();
 
-                ui.label("Color Grading LUT");
+                tooltip_text(
+                    ui,
+                    "Color Grading LUT",
+                    "The 3D texture used to adjust the final rendered colors.",
+                );
                 path_label(ui, &state.color_grading_lut);
                 if ui.button("Select file...").clicked() {
                     if let Some(file) = FileDialog::new()
@@ -1,6 +1,28 @@
-use crate::{app::SsbhApp, RenderState};
+use std::{
+    collections::HashSet,
+    io::Write,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use crate::{
+    app::SsbhApp,
+    preferences::{CameraBookmark, ScreenshotResolution},
+    widgets::{show_toast, ToastLevel},
+    AntialiasingMode, FileResult, RenderState, TextureDimension,
+};
 use futures::executor::block_on;
 use log::error;
+use nutexb::NutexbFile;
+use nutexb_wgpu::TextureRenderer;
+use ssbh_data::{
+    matl_data::{MatlEntryData, ParamId},
+    mesh_data::{MeshObjectData, VectorData},
+    skel_data::SkelData,
+};
+use ssbh_wgpu::RenderSettings;
+use strum::{Display, EnumIter};
+use wgpu::util::DeviceExt;
 
 pub fn render_screenshot(
     device: &wgpu::Device,
@@ -10,27 +32,232 @@ pub fn render_screenshot(
     height: u32,
     surface_format: wgpu::TextureFormat,
 ) -> image::ImageBuffer<image::Rgba<u8>, Vec<u8>> {
+    let screenshot =
+        submit_screenshot_buffer(device, queue, render_state, width, height, surface_format);
+    let image = read_buffer_to_image(
+        &screenshot.buffer,
+        device,
+        screenshot.render_width,
+        screenshot.render_height,
+        screenshot.format,
+    );
+    screenshot.buffer.unmap();
+
+    screenshot.finish(image)
+}
+
+/// A screenshot that has been submitted to the GPU but not yet read back.
+/// Check for completion with [PendingCapture::try_save] after polling the device.
+pub struct PendingCapture {
+    pub file: PathBuf,
+    screenshot: ScreenshotBuffer,
+    map_result: Arc<Mutex<Option<Result<(), wgpu::BufferAsyncError>>>>,
+}
+
+impl PendingCapture {
+    /// Save the screenshot on a separate thread once the GPU work is finished.
+    /// Returns `false` if the GPU work isn't finished yet.
+    pub fn try_save(&self, ctx: &egui::Context) -> bool {
+        let Some(result) = self.map_result.lock().unwrap().take() else {
+            return false;
+        };
+        if let Err(e) = result {
+            error!("Error reading screenshot for {:?}: {}", self.file, e);
+            return true;
+        }
+
+        // The buffer can only be read on this thread before it's unmapped.
+        let screenshot = &self.screenshot;
+        let data = mapped_buffer_data(
+            &screenshot.buffer,
+            screenshot.render_width,
+            screenshot.render_height,
+        );
+        screenshot.buffer.unmap();
+
+        // Converting, downscaling, and encoding large images would freeze the UI.
+        let (render_width, render_height, format) = (
+            screenshot.render_width,
+            screenshot.render_height,
+            screenshot.format,
+        );
+        let (width, height, antialiasing) =
+            (screenshot.width, screenshot.height, screenshot.antialiasing);
+        let file = self.file.clone();
+        let ctx = ctx.clone();
+        std::thread::spawn(move || {
+            let image = rgba_image(data, render_width, render_height, format);
+            let image = finish_screenshot(image, width, height, antialiasing);
+            match image.save(&file) {
+                Ok(()) => {
+                    show_toast(
+                        &ctx,
+                        ToastLevel::Info,
+                        format!("Saved screenshot to {file:?}"),
+                    );
+                    // Show the message without waiting for user input.
+                    ctx.request_repaint();
+                }
+                Err(e) => error!("Error saving screenshot to {:?}: {}", file, e),
+            }
+        });
+
+        true
+    }
+}
+
+/// Submit a screenshot without waiting for the GPU to avoid freezing the UI.
+pub fn submit_screenshot(
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+    render_state: &mut RenderState,
+    width: u32,
+    height: u32,
+    surface_format: wgpu::TextureFormat,
+    file: PathBuf,
+) -> PendingCapture {
+    let screenshot =
+        submit_screenshot_buffer(device, queue, render_state, width, height, surface_format);
+
+    let map_result = Arc::new(Mutex::new(None));
+    let sender = map_result.clone();
+    screenshot
+        .buffer
+        .slice(..)
+        .map_async(wgpu::MapMode::Read, move |result| {
+            *sender.lock().unwrap() = Some(result);
+        });
+
+    PendingCapture {
+        file,
+        screenshot,
+        map_result,
+    }
+}
+
+/// Submit a screenshot at `resolution` instead of the viewport's size.
+/// The camera and renderer are restored to `viewport_width` and `viewport_height` after rendering.
+pub fn submit_screenshot_at_resolution(
+    app: &mut SsbhApp,
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+    render_state: &mut RenderState,
+    resolution: ScreenshotResolution,
+    viewport_width: u32,
+    viewport_height: u32,
+    surface_format: wgpu::TextureFormat,
+    file: PathBuf,
+) -> PendingCapture {
+    let (width, height) = (resolution.width, resolution.height);
+
+    // Update the aspect ratio to avoid stretching the image.
+    render_state.renderer.resize(device, width, height, 1.0);
+    app.apply_camera(queue, render_state, width as f32, height as f32);
+
+    let capture = submit_screenshot(
+        device,
+        queue,
+        render_state,
+        width,
+        height,
+        surface_format,
+        file,
+    );
+
+    // Restore the viewport resolution.
+    render_state
+        .renderer
+        .resize(device, viewport_width, viewport_height, 1.0);
+    app.apply_camera(
+        queue,
+        render_state,
+        viewport_width as f32,
+        viewport_height as f32,
+    );
+
+    capture
+}
+
+struct ScreenshotBuffer {
+    buffer: wgpu::Buffer,
+    format: wgpu::TextureFormat,
+    render_width: u32,
+    render_height: u32,
+    width: u32,
+    height: u32,
+    antialiasing: AntialiasingMode,
+}
+
+impl ScreenshotBuffer {
+    /// Apply antialiasing to the rendered `image`.
+    fn finish(
+        &self,
+        image: image::ImageBuffer<image::Rgba<u8>, Vec<u8>>,
+    ) -> image::ImageBuffer<image::Rgba<u8>, Vec<u8>> {
+        finish_screenshot(image, self.width, self.height, self.antialiasing)
+    }
+}
+
+/// Apply `antialiasing` to the rendered `image` with an output size of `width` and `height`.
+fn finish_screenshot(
+    image: image::ImageBuffer<image::Rgba<u8>, Vec<u8>>,
+    width: u32,
+    height: u32,
+    antialiasing: AntialiasingMode,
+) -> image::ImageBuffer<image::Rgba<u8>, Vec<u8>> {
+    match antialiasing {
+        AntialiasingMode::None => image,
+        AntialiasingMode::Ssaa2x | AntialiasingMode::Ssaa4x => {
+            image::imageops::resize(&image, width, height, image::imageops::FilterType::Triangle)
+        }
+        AntialiasingMode::EdgeBlur => blur_edges(&image),
+    }
+}
+
+fn submit_screenshot_buffer(
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+    render_state: &mut RenderState,
+    width: u32,
+    height: u32,
+    surface_format: wgpu::TextureFormat,
+) -> ScreenshotBuffer {
     // Force transparent for screenshots.
+    // Offscreen textures store alpha even for formats presented as opaque.
+    // The renderer's pipelines require the texture to use the surface format.
     render_state.renderer.set_clear_color([0.0; 4]);
 
-    // Round up to satisfy alignment requirements for texture copies.
-    let round_up = |x, n| ((x + n - 1) / n) * n;
-    let screenshot_width = round_up(width, 64);
-    let screenshot_height = height;
+    // Other formats are converted to 8-bit RGBA with alpha before reading them back.
+    let convert_to_rgba = !is_rgba8_or_bgra8(surface_format);
+
+    // Supersampling is done by rendering at a higher resolution and downscaling.
+    // This avoids needing to recreate the renderer's pipelines for each sample count.
+    // Scale both dimensions equally to preserve the aspect ratio.
+    let sample_count = render_state.antialiasing.sample_count();
+    let scale = (sample_count as f32).sqrt();
+    let render_width = (width as f32 * scale).round() as u32;
+    let render_height = (height as f32 * scale).round() as u32;
+    if sample_count > 1 {
+        render_state
+            .renderer
+            .resize(device, render_width, render_height, 1.0);
+    }
 
     // Use a separate texture for drawing since the swapchain isn't COPY_SRC.
     let screenshot_texture = device.create_texture(&wgpu::TextureDescriptor {
         label: Some("screenshot texture"),
         size: wgpu::Extent3d {
-            width: screenshot_width,
-            height: screenshot_height,
+            width: render_width,
+            height: render_height,
             depth_or_array_layers: 1,
         },
         mip_level_count: 1,
         sample_count: 1,
         dimension: wgpu::TextureDimension::D2,
         format: surface_format,
-        usage: wgpu::TextureUsages::COPY_SRC | wgpu::TextureUsages::RENDER_ATTACHMENT,
+        usage: wgpu::TextureUsages::COPY_SRC
+            | wgpu::TextureUsages::RENDER_ATTACHMENT
+            | wgpu::TextureUsages::TEXTURE_BINDING,
         view_formats: &[],
     });
     let screenshot_view = screenshot_texture.create_view(&wgpu::TextureViewDescriptor::default());
@@ -42,21 +269,350 @@ pub fn render_screenshot(
         &screenshot_view,
         &render_state.render_models,
         render_state.shared_data.database(),
-        &render_state.model_render_options,
+        &render_state.model_render_options_for(0..render_state.render_models.len()),
     );
     drop(final_pass);
 
-    read_texture_to_image(
+    let (buffer, format) = if convert_to_rgba {
+        // The models need to finish rendering before converting the texture.
+        queue.submit([encoder.finish()]);
+
+        let rgba_texture = render_state.texture_renderer.render_to_texture_2d_rgba(
+            device,
+            queue,
+            &screenshot_texture,
+            wgpu::TextureViewDimension::D2,
+            (render_width, render_height, 1),
+            render_width,
+            render_height,
+            &nutexb_wgpu::RenderSettings::default(),
+        );
+
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("Screenshot Copy Encoder"),
+        });
+        let buffer = copy_texture_to_buffer(
+            &mut encoder,
+            device,
+            &rgba_texture,
+            render_width,
+            render_height,
+        );
+        queue.submit([encoder.finish()]);
+        (buffer, wgpu::TextureFormat::Rgba8Unorm)
+    } else {
+        let buffer = copy_texture_to_buffer(
+            &mut encoder,
+            device,
+            &screenshot_texture,
+            render_width,
+            render_height,
+        );
+        queue.submit([encoder.finish()]);
+        (buffer, surface_format)
+    };
+
+    if sample_count > 1 {
+        // Restore the viewport resolution.
+        render_state.renderer.resize(device, width, height, 1.0);
+    }
+
+    ScreenshotBuffer {
+        buffer,
+        format,
+        render_width,
+        render_height,
+        width,
+        height,
+        antialiasing: render_state.antialiasing,
+    }
+}
+
+/// Settings for comparing two sets of render settings in a single screenshot.
+#[derive(Default)]
+pub struct ComparisonCapture {
+    /// The settings for the left or top part of the image.
+    pub settings_a: Option<RenderSettings>,
+    /// The settings for the right or bottom part of the image.
+    pub settings_b: Option<RenderSettings>,
+    pub layout: ComparisonLayout,
+    pub split: ComparisonSplit,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Display, EnumIter)]
+pub enum ComparisonLayout {
+    /// Split the image with a vertical line.
+    #[default]
+    Horizontal,
+    /// Split the image with a horizontal line.
+    Vertical,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Display, EnumIter)]
+pub enum ComparisonSplit {
+    #[default]
+    Center,
+    /// Use one third of the image for A.
+    #[strum(serialize = "One Third")]
+    Third,
+}
+
+impl ComparisonSplit {
+    fn fraction(self) -> f32 {
+        match self {
+            ComparisonSplit::Center => 0.5,
+            ComparisonSplit::Third => 1.0 / 3.0,
+        }
+    }
+}
+
+/// Render the scene with `settings_a` and `settings_b` and combine the images at the split.
+pub fn render_comparison_screenshot(
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+    render_state: &mut RenderState,
+    settings_a: &RenderSettings,
+    settings_b: &RenderSettings,
+    layout: ComparisonLayout,
+    split: ComparisonSplit,
+    width: u32,
+    height: u32,
+    surface_format: wgpu::TextureFormat,
+) -> image::ImageBuffer<image::Rgba<u8>, Vec<u8>> {
+    render_state
+        .renderer
+        .update_render_settings(queue, settings_a);
+    let image_a = render_screenshot(device, queue, render_state, width, height, surface_format);
+
+    render_state
+        .renderer
+        .update_render_settings(queue, settings_b);
+    let image_b = render_screenshot(device, queue, render_state, width, height, surface_format);
+
+    // Restore any state we modified while rendering.
+    render_state
+        .renderer
+        .update_render_settings(queue, &render_state.render_settings);
+
+    stitch_comparison(&image_a, &image_b, layout, split)
+}
+
+fn stitch_comparison(
+    image_a: &image::ImageBuffer<image::Rgba<u8>, Vec<u8>>,
+    image_b: &image::ImageBuffer<image::Rgba<u8>, Vec<u8>>,
+    layout: ComparisonLayout,
+    split: ComparisonSplit,
+) -> image::ImageBuffer<image::Rgba<u8>, Vec<u8>> {
+    const DIVIDER_COLOR: image::Rgba<u8> = image::Rgba([255, 255, 255, 255]);
+
+    let (width, height) = image_a.dimensions();
+    let mut output = image_a.clone();
+    match layout {
+        ComparisonLayout::Horizontal => {
+            let x = (width as f32 * split.fraction()) as u32;
+            let right = image::imageops::crop_imm(image_b, x, 0, width - x, height).to_image();
+            image::imageops::replace(&mut output, &right, x as i64, 0);
+
+            for y in 0..height {
+                output.put_pixel(x.min(width.saturating_sub(1)), y, DIVIDER_COLOR);
+            }
+        }
+        ComparisonLayout::Vertical => {
+            let y = (height as f32 * split.fraction()) as u32;
+            let bottom = image::imageops::crop_imm(image_b, 0, y, width, height - y).to_image();
+            image::imageops::replace(&mut output, &bottom, 0, y as i64);
+
+            for x in 0..width {
+                output.put_pixel(x, y.min(height.saturating_sub(1)), DIVIDER_COLOR);
+            }
+        }
+    }
+
+    output
+}
+
+// Blend pixels along high contrast luminance edges with their neighbors.
+fn blur_edges(
+    image: &image::ImageBuffer<image::Rgba<u8>, Vec<u8>>,
+) -> image::ImageBuffer<image::Rgba<u8>, Vec<u8>> {
+    const EDGE_THRESHOLD: f32 = 0.125;
+
+    let luma = |x: u32, y: u32| {
+        let [r, g, b, _] = image.get_pixel(x, y).0;
+        (0.299 * r as f32 + 0.587 * g as f32 + 0.114 * b as f32) / 255.0
+    };
+
+    let mut output = image.clone();
+    for y in 1..image.height().saturating_sub(1) {
+        for x in 1..image.width().saturating_sub(1) {
+            let neighbors = [(x, y - 1), (x, y + 1), (x - 1, y), (x + 1, y)];
+
+            let center = luma(x, y);
+            let (min, max) = neighbors
+                .iter()
+                .map(|(nx, ny)| luma(*nx, *ny))
+                .fold((center, center), |(min, max), l| (min.min(l), max.max(l)));
+
+            if max - min > EDGE_THRESHOLD {
+                let mut sum = image.get_pixel(x, y).0.map(|c| c as u32 * 4);
+                for (nx, ny) in neighbors {
+                    for (s, c) in sum.iter_mut().zip(image.get_pixel(nx, ny).0) {
+                        *s += c as u32;
+                    }
+                }
+                output.put_pixel(x, y, image::Rgba(sum.map(|s| (s / 8) as u8)));
+            }
+        }
+    }
+
+    output
+}
+
+/// Render a sphere preview for `entry` using the material's color texture.
+pub fn render_material_swatch(
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+    render_state: &RenderState,
+    model_index: usize,
+    entry: &MatlEntryData,
+    size: u32,
+) -> Option<image::ImageBuffer<image::Rgba<u8>, Vec<u8>>> {
+    // The first color layer best represents the overall appearance of most materials.
+    let texture_name = entry
+        .textures
+        .iter()
+        .find(|t| t.param_id == ParamId::Texture0)
+        .or_else(|| entry.textures.first())
+        .map(|t| t.data.as_str())?;
+
+    let render_model = render_state.render_models.get(model_index)?;
+    let (texture, dimension) = render_model
+        .get_texture(&format!("{texture_name}.nutexb"))
+        .or_else(|| {
+            render_state
+                .shared_data
+                .default_textures()
+                .iter()
+                .find(|(name, _, _)| name.eq_ignore_ascii_case(texture_name))
+                .map(|(_, texture, dimension)| (texture, dimension))
+        })?;
+
+    const TEXTURE_SIZE: u32 = 256;
+    let rgba_texture = render_state.texture_renderer.render_to_texture_2d_rgba(
+        device,
+        queue,
+        texture,
+        *dimension,
+        (texture.width(), texture.height(), 1),
+        TEXTURE_SIZE,
+        TEXTURE_SIZE,
+        &nutexb_wgpu::RenderSettings::default(),
+    );
+
+    let encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+        label: Some("Material Swatch Encoder"),
+    });
+    let texture_image = read_texture_to_image(
         encoder,
         device,
         queue,
-        &screenshot_texture,
-        screenshot_width,
-        screenshot_height,
-        surface_format,
-    )
+        &rgba_texture,
+        TEXTURE_SIZE,
+        TEXTURE_SIZE,
+        wgpu::TextureFormat::Rgba8Unorm,
+    );
+
+    Some(shade_sphere(&texture_image, size))
+}
+
+/// Decode `nutexb` to RGBA images with one image for each cube map face or 3D texture layer.
+pub fn render_nutexb_to_images(
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+    texture_renderer: &TextureRenderer,
+    nutexb: &NutexbFile,
+) -> Result<Vec<image::ImageBuffer<image::Rgba<u8>, Vec<u8>>>, Box<dyn std::error::Error>> {
+    let texture = nutexb_wgpu::create_texture(nutexb, device, queue)?;
+    let (width, height, depth) = (
+        nutexb.footer.width,
+        nutexb.footer.height,
+        nutexb.footer.depth,
+    );
+    let (dimension, layer_count) = match TextureDimension::from_nutexb(nutexb) {
+        TextureDimension::TextureCube => (wgpu::TextureViewDimension::Cube, 6),
+        TextureDimension::Texture3d => (wgpu::TextureViewDimension::D3, depth),
+        _ => (wgpu::TextureViewDimension::D2, 1),
+    };
+
+    let images = (0..layer_count)
+        .map(|layer| {
+            let settings = nutexb_wgpu::RenderSettings {
+                layer,
+                ..Default::default()
+            };
+            let rgba_texture = texture_renderer.render_to_texture_2d_rgba(
+                device,
+                queue,
+                &texture,
+                dimension,
+                (width, height, depth),
+                width,
+                height,
+                &settings,
+            );
+
+            let encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("Nutexb Export Encoder"),
+            });
+            // Render at the native size so each pixel matches a texel.
+            read_texture_to_image(
+                encoder,
+                device,
+                queue,
+                &rgba_texture,
+                width,
+                height,
+                wgpu::TextureFormat::Rgba8Unorm,
+            )
+        })
+        .collect();
+
+    Ok(images)
 }
 
+// Map the texture onto a sphere with simple diffuse lighting.
+fn shade_sphere(
+    texture: &image::ImageBuffer<image::Rgba<u8>, Vec<u8>>,
+    size: u32,
+) -> image::ImageBuffer<image::Rgba<u8>, Vec<u8>> {
+    let light = glam::Vec3::new(-0.5, 0.5, 1.0).normalize();
+
+    image::ImageBuffer::from_fn(size, size, |x, y| {
+        // Convert pixel coordinates to the range -1.0 to 1.0.
+        let u = 2.0 * (x as f32 + 0.5) / size as f32 - 1.0;
+        let v = 1.0 - 2.0 * (y as f32 + 0.5) / size as f32;
+        let r2 = u * u + v * v;
+        if r2 > 1.0 {
+            return image::Rgba([0, 0, 0, 0]);
+        }
+
+        let normal = glam::Vec3::new(u, v, (1.0 - r2).sqrt());
+        let diffuse = 0.25 + 0.75 * normal.dot(light).max(0.0);
+
+        // Spherical UV coordinates.
+        let tex_u = 0.5 + normal.x.atan2(normal.z) / (2.0 * std::f32::consts::PI);
+        let tex_v = 0.5 - normal.y.asin() / std::f32::consts::PI;
+        let tex_x = ((tex_u * texture.width() as f32) as u32).min(texture.width() - 1);
+        let tex_y = ((tex_v * texture.height() as f32) as u32).min(texture.height() - 1);
+
+        let [r, g, b, _] = texture.get_pixel(tex_x, tex_y).0;
+        let shade = |c: u8| (c as f32 * diffuse).min(255.0) as u8;
+        image::Rgba([shade(r), shade(g), shade(b), 255])
+    })
+}
+
+/// Read `output` to an RGBA image.
+/// `format` must be the format of `output`, so channels are only swapped for BGRA textures.
 fn read_texture_to_image(
     mut encoder: wgpu::CommandEncoder,
     device: &wgpu::Device,
@@ -64,33 +620,70 @@ fn read_texture_to_image(
     output: &wgpu::Texture,
     width: u32,
     height: u32,
-    surface_format: wgpu::TextureFormat,
+    format: wgpu::TextureFormat,
 ) -> image::ImageBuffer<image::Rgba<u8>, Vec<u8>> {
+    let output_buffer = copy_texture_to_buffer(&mut encoder, device, output, width, height);
+    queue.submit([encoder.finish()]);
+
+    let image = read_buffer_to_image(&output_buffer, device, width, height, format);
+    output_buffer.unmap();
+
+    image
+}
+
+fn copy_texture_to_buffer(
+    encoder: &mut wgpu::CommandEncoder,
+    device: &wgpu::Device,
+    output: &wgpu::Texture,
+    width: u32,
+    height: u32,
+) -> wgpu::Buffer {
+    copy_texture_aspect_to_buffer(
+        encoder,
+        device,
+        output,
+        width,
+        height,
+        wgpu::TextureAspect::All,
+    )
+}
+
+/// The size in bytes of each row in a buffer copied from a texture with 4 bytes per pixel.
+/// Texture copies require rows to be aligned to [wgpu::COPY_BYTES_PER_ROW_ALIGNMENT].
+fn padded_bytes_per_row(width: u32) -> u32 {
+    (width * 4).next_multiple_of(wgpu::COPY_BYTES_PER_ROW_ALIGNMENT)
+}
+
+/// Remove the padding from each row of `data` copied with [padded_bytes_per_row].
+fn unpadded_rows(data: &[u8], width: u32, height: u32) -> Vec<u8> {
+    let row_size = width as usize * 4;
+    data.chunks_exact(padded_bytes_per_row(width) as usize)
+        .take(height as usize)
+        .flat_map(|row| &row[..row_size])
+        .copied()
+        .collect()
+}
+
+/// Copy `aspect` of a texture with 4 bytes per pixel to a new buffer.
+/// Rows in the buffer are padded to [padded_bytes_per_row].
+fn copy_texture_aspect_to_buffer(
+    encoder: &mut wgpu::CommandEncoder,
+    device: &wgpu::Device,
+    output: &wgpu::Texture,
+    width: u32,
+    height: u32,
+    aspect: wgpu::TextureAspect,
+) -> wgpu::Buffer {
     let output_buffer = device.create_buffer(&wgpu::BufferDescriptor {
-        size: width as u64 * height as u64 * 4,
+        size: padded_bytes_per_row(width) as u64 * height as u64,
         usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
         label: None,
         mapped_at_creation: false,
     });
 
-    let texture_desc = wgpu::TextureDescriptor {
-        label: None,
-        size: wgpu::Extent3d {
-            width,
-            height,
-            depth_or_array_layers: 1,
-        },
-        mip_level_count: 1,
-        sample_count: 1,
-        dimension: wgpu::TextureDimension::D2,
-        format: surface_format,
-        usage: wgpu::TextureUsages::COPY_SRC | wgpu::TextureUsages::RENDER_ATTACHMENT,
-        view_formats: &[],
-    };
-
     encoder.copy_texture_to_buffer(
         wgpu::ImageCopyTexture {
-            aspect: wgpu::TextureAspect::All,
+            aspect,
             texture: output,
             mip_level: 0,
             origin: wgpu::Origin3d::ZERO,
@@ -99,20 +692,18 @@ fn read_texture_to_image(
             buffer: &output_buffer,
             layout: wgpu::ImageDataLayout {
                 offset: 0,
-                // TODO: This needs to be aligned to 256 bytes?
-                bytes_per_row: Some(width * 4),
+                bytes_per_row: Some(padded_bytes_per_row(width)),
                 rows_per_image: None,
             },
         },
-        texture_desc.size,
+        wgpu::Extent3d {
+            width,
+            height,
+            depth_or_array_layers: 1,
+        },
     );
 
-    queue.submit([encoder.finish()]);
-
-    let image = read_buffer_to_image(&output_buffer, device, width, height);
-    output_buffer.unmap();
-
-    image
+    output_buffer
 }
 
 fn read_buffer_to_image(
@@ -120,10 +711,21 @@ fn read_buffer_to_image(
     device: &wgpu::Device,
     width: u32,
     height: u32,
+    format: wgpu::TextureFormat,
 ) -> image::ImageBuffer<image::Rgba<u8>, Vec<u8>> {
     // Save the output texture.
     // Adapted from WGPU Example https://github.com/gfx-rs/wgpu/tree/master/wgpu/examples/capture
     // TODO: Find ways to optimize this?
+    map_buffer(output_buffer, device).unwrap();
+
+    mapped_buffer_to_image(output_buffer, width, height, format)
+}
+
+/// Map `output_buffer` for reading and wait for the GPU to finish.
+fn map_buffer(
+    output_buffer: &wgpu::Buffer,
+    device: &wgpu::Device,
+) -> Result<(), wgpu::BufferAsyncError> {
     let buffer_slice = output_buffer.slice(..);
     // TODO: Do this without another crate?
     let (tx, rx) = futures_intrusive::channel::shared::oneshot_channel();
@@ -131,17 +733,74 @@ fn read_buffer_to_image(
         tx.send(result).unwrap();
     });
     device.poll(wgpu::Maintain::Wait);
-    block_on(rx.receive()).unwrap().unwrap();
-    let data = buffer_slice.get_mapped_range();
+    block_on(rx.receive()).unwrap_or(Err(wgpu::BufferAsyncError))
+}
+
+fn mapped_buffer_to_image(
+    output_buffer: &wgpu::Buffer,
+    width: u32,
+    height: u32,
+    format: wgpu::TextureFormat,
+) -> image::ImageBuffer<image::Rgba<u8>, Vec<u8>> {
+    let data = mapped_buffer_data(output_buffer, width, height);
+    rgba_image(data, width, height, format)
+}
+
+/// Copy the pixels from the mapped `output_buffer` without row padding.
+fn mapped_buffer_data(output_buffer: &wgpu::Buffer, width: u32, height: u32) -> Vec<u8> {
+    let data = output_buffer.slice(..).get_mapped_range();
+    unpadded_rows(&data, width, height)
+}
+
+/// Convert 4 byte per pixel `data` in `format` to an RGBA image.
+fn rgba_image(
+    data: Vec<u8>,
+    width: u32,
+    height: u32,
+    format: wgpu::TextureFormat,
+) -> image::ImageBuffer<image::Rgba<u8>, Vec<u8>> {
     let mut buffer =
-        image::ImageBuffer::<image::Rgba<u8>, _>::from_raw(width, height, data.to_owned()).unwrap();
+        image::ImageBuffer::<image::Rgba<u8>, _>::from_raw(width, height, data).unwrap();
 
-    // Convert BGRA to RGBA.
-    buffer.pixels_mut().for_each(|p| p.0.swap(0, 2));
+    // Convert BGRA to RGBA without affecting alpha.
+    if matches!(
+        format,
+        wgpu::TextureFormat::Bgra8Unorm | wgpu::TextureFormat::Bgra8UnormSrgb
+    ) {
+        buffer.pixels_mut().for_each(|p| p.0.swap(0, 2));
+    }
 
     buffer
 }
 
+/// Returns `true` if `format` can be read as 8-bit RGBA after swapping channels.
+fn is_rgba8_or_bgra8(format: wgpu::TextureFormat) -> bool {
+    matches!(
+        format,
+        wgpu::TextureFormat::Rgba8Unorm
+            | wgpu::TextureFormat::Rgba8UnormSrgb
+            | wgpu::TextureFormat::Bgra8Unorm
+            | wgpu::TextureFormat::Bgra8UnormSrgb
+    )
+}
+
+/// Settings for rotating the camera a full turn instead of advancing the animation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TurntableCapture {
+    pub total_frames: u32,
+    /// The camera rotation axis in the camera's euler angle space.
+    pub axis: glam::Vec3,
+}
+
+impl Default for TurntableCapture {
+    fn default() -> Self {
+        Self {
+            total_frames: 120,
+            axis: glam::Vec3::Y,
+        }
+    }
+}
+
 pub fn render_animation_to_gif(
     app: &mut SsbhApp,
     device: &wgpu::Device,
@@ -151,6 +810,7 @@ pub fn render_animation_to_gif(
     height: u32,
     file: std::path::PathBuf,
     surface_format: wgpu::TextureFormat,
+    turntable: Option<&TurntableCapture>,
 ) {
     // TODO: Rendering modifies the app, so this needs to be on the UI thread for now.
     let images = render_animation_sequence(
@@ -161,6 +821,7 @@ pub fn render_animation_to_gif(
         width,
         height,
         surface_format,
+        turntable,
     );
 
     // TODO: Add progress indication.
@@ -194,6 +855,7 @@ pub fn render_animation_to_image_sequence(
         width,
         height,
         surface_format,
+        None,
     );
 
     // TODO: Add progress indication.
@@ -219,6 +881,199 @@ pub fn render_animation_to_image_sequence(
     });
 }
 
+#[allow(clippy::too_many_arguments)]
+pub fn render_animation_to_webm(
+    ctx: &egui::Context,
+    app: &mut SsbhApp,
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+    render_state: &mut RenderState,
+    width: u32,
+    height: u32,
+    file: std::path::PathBuf,
+    surface_format: wgpu::TextureFormat,
+) {
+    // TODO: Rendering modifies the app, so this needs to be on the UI thread for now.
+    let images = render_animation_sequence(
+        app,
+        device,
+        queue,
+        render_state,
+        width,
+        height,
+        surface_format,
+        None,
+    );
+
+    // TODO: Add progress indication.
+    let ctx = ctx.clone();
+    std::thread::spawn(move || {
+        // Smash Ultimate animations are designed to play at 60 fps.
+        match export_animation_webm(&images, &file, 60.0) {
+            Ok(()) => {
+                show_toast(&ctx, ToastLevel::Info, format!("Saved WebM to {file:?}"));
+                // Show the message without waiting for user input.
+                ctx.request_repaint();
+            }
+            Err(e) => error!("Error saving WebM to {file:?}: {e}"),
+        }
+    });
+}
+
+/// Encode `frames` to a WebM video using VP8 at `fps` frames per second.
+///
+/// This requires an `ffmpeg` executable with libvpx support on the system `PATH`.
+pub fn export_animation_webm(
+    frames: &[image::ImageBuffer<image::Rgba<u8>, Vec<u8>>],
+    path: &Path,
+    fps: f32,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let Some((width, height)) = frames.first().map(|f| f.dimensions()) else {
+        return Err("No frames to export".into());
+    };
+
+    let mut child = std::process::Command::new("ffmpeg")
+        .args([
+            "-y",
+            "-hide_banner",
+            "-loglevel",
+            "error",
+            "-f",
+            "rawvideo",
+            "-pixel_format",
+            "rgba",
+            "-video_size",
+        ])
+        .arg(format!("{width}x{height}"))
+        .arg("-framerate")
+        .arg(fps.to_string())
+        .args(["-i", "pipe:0", "-c:v", "libvpx", "-auto-alt-ref", "0"])
+        .arg(path)
+        .stdin(std::process::Stdio::piped())
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::piped())
+        .spawn()
+        .map_err(|e| -> Box<dyn std::error::Error> {
+            if e.kind() == std::io::ErrorKind::NotFound {
+                "ffmpeg was not found. Install FFmpeg and add it to the PATH to export WebM videos."
+                    .into()
+            } else {
+                format!("Failed to start ffmpeg: {e}").into()
+            }
+        })?;
+
+    // Read stderr while writing frames to avoid blocking ffmpeg on a full pipe.
+    let stderr_thread = child.stderr.take().map(|mut stderr| {
+        std::thread::spawn(move || {
+            let mut output = String::new();
+            let _ = std::io::Read::read_to_string(&mut stderr, &mut output);
+            output
+        })
+    });
+
+    // Dropping stdin after writing signals the end of the input to ffmpeg.
+    if let Some(mut stdin) = child.stdin.take() {
+        for frame in frames {
+            stdin.write_all(frame.as_raw())?;
+        }
+    }
+
+    let status = child.wait()?;
+    let stderr = stderr_thread
+        .and_then(|t| t.join().ok())
+        .unwrap_or_default();
+    if status.success() {
+        Ok(())
+    } else {
+        // The last line of ffmpeg's output usually describes the error.
+        let message = stderr.lines().last().unwrap_or_default();
+        Err(format!("ffmpeg exited with {status}: {message}").into())
+    }
+}
+
+/// Save a screenshot for each of the camera `bookmarks` to `output_dir` as `{name}.png`.
+pub fn render_multiangle_batch(
+    app: &mut SsbhApp,
+    bookmarks: &[CameraBookmark],
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+    render_state: &mut RenderState,
+    width: u32,
+    height: u32,
+    surface_format: wgpu::TextureFormat,
+    output_dir: &Path,
+) -> FileResult<()> {
+    let saved_camera = app.camera_state.values.clone();
+
+    // TODO: Rendering modifies the app, so this needs to be on the UI thread for now.
+    let mut images = Vec::new();
+    for bookmark in bookmarks {
+        app.camera_state.values = bookmark.values.clone();
+        app.apply_camera(queue, render_state, width as f32, height as f32);
+
+        let image = render_screenshot(device, queue, render_state, width, height, surface_format);
+        images.push(image);
+    }
+
+    // Restore any state we modified while rendering.
+    app.camera_state.values = saved_camera;
+    app.apply_camera(queue, render_state, width as f32, height as f32);
+
+    std::fs::create_dir_all(output_dir)?;
+    let file_names = bookmark_file_names(bookmarks.iter().map(|b| b.name.as_str()));
+    for (file_name, image) in file_names.into_iter().zip(images) {
+        image.save(output_dir.join(file_name))?;
+    }
+    Ok(())
+}
+
+/// Unique PNG file names for each bookmark name.
+/// Later duplicates have a numbered suffix like `front_1.png` to avoid overwriting images.
+fn bookmark_file_names<'a>(names: impl IntoIterator<Item = &'a str>) -> Vec<String> {
+    let stems: Vec<_> = names
+        .into_iter()
+        .enumerate()
+        .map(|(i, name)| bookmark_file_stem(name, i))
+        .collect();
+
+    // Compare names ignoring case since some file systems are case insensitive.
+    let original: HashSet<_> = stems.iter().map(|s| s.to_lowercase()).collect();
+    let mut used = HashSet::new();
+    stems
+        .iter()
+        .map(|stem| {
+            let mut name = stem.clone();
+            let mut n = 1;
+            while used.contains(&name.to_lowercase())
+                || (name != *stem && original.contains(&name.to_lowercase()))
+            {
+                name = format!("{stem}_{n}");
+                n += 1;
+            }
+            used.insert(name.to_lowercase());
+            format!("{name}.png")
+        })
+        .collect()
+}
+
+fn bookmark_file_stem(name: &str, index: usize) -> String {
+    // Bookmark names may not be valid file names.
+    let name: String = name
+        .trim()
+        .chars()
+        .map(|c| match c {
+            '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' => '_',
+            _ => c,
+        })
+        .collect();
+
+    if name.is_empty() {
+        format!("camera{index}")
+    } else {
+        name
+    }
+}
+
 fn render_animation_sequence(
     app: &mut SsbhApp,
     device: &wgpu::Device,
@@ -227,7 +1082,21 @@ fn render_animation_sequence(
     width: u32,
     height: u32,
     surface_format: wgpu::TextureFormat,
+    turntable: Option<&TurntableCapture>,
 ) -> Vec<image::ImageBuffer<image::Rgba<u8>, Vec<u8>>> {
+    if let Some(turntable) = turntable {
+        return render_turntable_sequence(
+            app,
+            turntable,
+            device,
+            queue,
+            render_state,
+            width,
+            height,
+            surface_format,
+        );
+    }
+
     let saved_frame = app.animation_state.current_frame;
 
     let mut frames = Vec::new();
@@ -248,3 +1117,366 @@ fn render_animation_sequence(
 
     frames
 }
+
+fn render_turntable_sequence(
+    app: &mut SsbhApp,
+    turntable: &TurntableCapture,
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+    render_state: &mut RenderState,
+    width: u32,
+    height: u32,
+    surface_format: wgpu::TextureFormat,
+) -> Vec<image::ImageBuffer<image::Rgba<u8>, Vec<u8>>> {
+    let saved_rotation = app.camera_state.values.rotation_radians;
+
+    // Rotate the camera by an equal amount each frame without repeating the first frame.
+    // The animations stay on the current frame.
+    let total_frames = turntable.total_frames.max(1);
+    let axis = turntable.axis.normalize_or_zero();
+    let mut frames = Vec::new();
+    for i in 0..total_frames {
+        let angle = std::f32::consts::TAU * i as f32 / total_frames as f32;
+        app.camera_state.values.rotation_radians = saved_rotation + axis * angle;
+        app.apply_camera(queue, render_state, width as f32, height as f32);
+
+        let frame = render_screenshot(device, queue, render_state, width, height, surface_format);
+        frames.push(frame);
+    }
+
+    // Restore any state we modified while rendering.
+    app.camera_state.values.rotation_radians = saved_rotation;
+    app.apply_camera(queue, render_state, width as f32, height as f32);
+
+    frames
+}
+
+// Draw the model's depth using only vertex positions.
+const DEPTH_SHADER: &str = "
+@group(0) @binding(0)
+var<uniform> mvp: mat4x4<f32>;
+
+@vertex
+fn vs_main(@location(0) position: vec3<f32>) -> @builtin(position) vec4<f32> {
+    return mvp * vec4(position, 1.0);
+}
+";
+
+/// The world space positions and triangle indices of a mesh object for a depth screenshot.
+pub struct DepthMesh {
+    pub positions: Vec<glam::Vec3>,
+    pub vertex_indices: Vec<u32>,
+}
+
+/// Render the depth of `meshes` and convert depth values from 0.0 to 1.0 to grayscale.
+// ssbh_wgpu doesn't expose the depth of its render passes, so draw the positions separately.
+pub fn render_depth_screenshot(
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+    meshes: &[DepthMesh],
+    mvp_matrix: glam::Mat4,
+    width: u32,
+    height: u32,
+) -> Result<image::GrayImage, wgpu::BufferAsyncError> {
+    let depth_texture = device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("depth screenshot texture"),
+        size: wgpu::Extent3d {
+            width,
+            height,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: wgpu::TextureFormat::Depth32Float,
+        usage: wgpu::TextureUsages::COPY_SRC | wgpu::TextureUsages::RENDER_ATTACHMENT,
+        view_formats: &[],
+    });
+    let depth_view = depth_texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+    let mvp_bytes: Vec<u8> = mvp_matrix
+        .to_cols_array()
+        .iter()
+        .flat_map(|f| f.to_le_bytes())
+        .collect();
+    let mvp_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+        label: Some("depth screenshot mvp"),
+        contents: &mvp_bytes,
+        usage: wgpu::BufferUsages::UNIFORM,
+    });
+
+    let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+        label: Some("depth screenshot bind group layout"),
+        entries: &[wgpu::BindGroupLayoutEntry {
+            binding: 0,
+            visibility: wgpu::ShaderStages::VERTEX,
+            ty: wgpu::BindingType::Buffer {
+                ty: wgpu::BufferBindingType::Uniform,
+                has_dynamic_offset: false,
+                min_binding_size: None,
+            },
+            count: None,
+        }],
+    });
+    let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+        label: Some("depth screenshot bind group"),
+        layout: &bind_group_layout,
+        entries: &[wgpu::BindGroupEntry {
+            binding: 0,
+            resource: mvp_buffer.as_entire_binding(),
+        }],
+    });
+
+    let pipeline = depth_pipeline(device, &bind_group_layout);
+    let buffers = depth_mesh_buffers(device, meshes);
+
+    let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+        label: Some("Depth Screenshot Render Encoder"),
+    });
+    {
+        let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("Depth Screenshot Pass"),
+            color_attachments: &[],
+            depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                view: &depth_view,
+                depth_ops: Some(wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(1.0),
+                    store: wgpu::StoreOp::Store,
+                }),
+                stencil_ops: None,
+            }),
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+        pass.set_pipeline(&pipeline);
+        pass.set_bind_group(0, &bind_group, &[]);
+        for (vertex_buffer, index_buffer, index_count) in &buffers {
+            pass.set_vertex_buffer(0, vertex_buffer.slice(..));
+            pass.set_index_buffer(index_buffer.slice(..), wgpu::IndexFormat::Uint32);
+            pass.draw_indexed(0..*index_count, 0, 0..1);
+        }
+    }
+
+    // Depth textures can only copy the depth aspect.
+    let output_buffer = copy_texture_aspect_to_buffer(
+        &mut encoder,
+        device,
+        &depth_texture,
+        width,
+        height,
+        wgpu::TextureAspect::DepthOnly,
+    );
+    queue.submit([encoder.finish()]);
+    map_buffer(&output_buffer, device)?;
+
+    let data = output_buffer.slice(..).get_mapped_range();
+    let pixels = unpadded_rows(&data, width, height)
+        .chunks_exact(4)
+        .map(|b| {
+            let depth = f32::from_le_bytes([b[0], b[1], b[2], b[3]]);
+            (depth.clamp(0.0, 1.0) * 255.0).round() as u8
+        })
+        .collect();
+    drop(data);
+    output_buffer.unmap();
+
+    Ok(image::GrayImage::from_raw(width, height, pixels).unwrap())
+}
+
+/// The positions of `mesh_object` deformed by the animated bone `world_transforms`.
+/// `bind_transforms` are the world transforms of each bone in `skel` without animation.
+/// Positions are unchanged if there is no `skel`.
+pub fn skinned_positions(
+    mesh_object: &MeshObjectData,
+    skel: Option<&SkelData>,
+    bind_transforms: &[glam::Mat4],
+    world_transforms: &[glam::Mat4],
+) -> Vec<glam::Vec3> {
+    let positions = mesh_object
+        .positions
+        .first()
+        .map(|a| vector3_positions(&a.data))
+        .unwrap_or_default();
+    let bone_index = |name: &str| skel?.bones.iter().position(|b| b.name == name);
+
+    if mesh_object.bone_influences.is_empty() {
+        // Meshes without influences are positioned relative to their parent bone.
+        return match bone_index(&mesh_object.parent_bone_name).and_then(|i| world_transforms.get(i))
+        {
+            Some(transform) => positions
+                .iter()
+                .map(|p| transform.transform_point3(*p))
+                .collect(),
+            None => positions,
+        };
+    }
+
+    // Linear blend skinning relative to the bind pose.
+    let mut skinned = vec![glam::Vec3::ZERO; positions.len()];
+    let mut weights = vec![0.0; positions.len()];
+    for influence in &mesh_object.bone_influences {
+        let Some(skin_transform) = bone_index(&influence.bone_name)
+            .and_then(|i| Some(*world_transforms.get(i)? * bind_transforms.get(i)?.inverse()))
+        else {
+            continue;
+        };
+
+        for w in &influence.vertex_weights {
+            let i = w.vertex_index as usize;
+            if let Some(position) = positions.get(i) {
+                skinned[i] += skin_transform.transform_point3(*position) * w.vertex_weight;
+                weights[i] += w.vertex_weight;
+            }
+        }
+    }
+
+    // Keep unweighted vertices in place.
+    skinned
+        .into_iter()
+        .zip(weights)
+        .zip(positions)
+        .map(|((s, w), p)| if w > 0.0 { s / w } else { p })
+        .collect()
+}
+
+fn vector3_positions(data: &VectorData) -> Vec<glam::Vec3> {
+    match data {
+        VectorData::Vector2(v) => v.iter().map(|[x, y]| glam::vec3(*x, *y, 0.0)).collect(),
+        VectorData::Vector3(v) => v.iter().map(|p| glam::Vec3::from(*p)).collect(),
+        VectorData::Vector4(v) => v
+            .iter()
+            .map(|[x, y, z, _]| glam::vec3(*x, *y, *z))
+            .collect(),
+    }
+}
+
+fn depth_pipeline(
+    device: &wgpu::Device,
+    bind_group_layout: &wgpu::BindGroupLayout,
+) -> wgpu::RenderPipeline {
+    let module = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+        label: Some("depth screenshot shader"),
+        source: wgpu::ShaderSource::Wgsl(DEPTH_SHADER.into()),
+    });
+    let layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+        label: Some("depth screenshot pipeline layout"),
+        bind_group_layouts: &[bind_group_layout],
+        push_constant_ranges: &[],
+    });
+
+    device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+        label: Some("depth screenshot pipeline"),
+        layout: Some(&layout),
+        vertex: wgpu::VertexState {
+            module: &module,
+            entry_point: "vs_main",
+            compilation_options: Default::default(),
+            buffers: &[wgpu::VertexBufferLayout {
+                array_stride: 12,
+                step_mode: wgpu::VertexStepMode::Vertex,
+                attributes: &wgpu::vertex_attr_array![0 => Float32x3],
+            }],
+        },
+        primitive: wgpu::PrimitiveState::default(),
+        depth_stencil: Some(wgpu::DepthStencilState {
+            format: wgpu::TextureFormat::Depth32Float,
+            depth_write_enabled: true,
+            depth_compare: wgpu::CompareFunction::Less,
+            stencil: wgpu::StencilState::default(),
+            bias: wgpu::DepthBiasState::default(),
+        }),
+        multisample: wgpu::MultisampleState::default(),
+        fragment: None,
+        multiview: None,
+        cache: None,
+    })
+}
+
+fn depth_mesh_buffers(
+    device: &wgpu::Device,
+    meshes: &[DepthMesh],
+) -> Vec<(wgpu::Buffer, wgpu::Buffer, u32)> {
+    meshes
+        .iter()
+        .filter(|m| !m.vertex_indices.is_empty())
+        .map(|mesh| {
+            let bytes: Vec<u8> = mesh
+                .positions
+                .iter()
+                .flat_map(|p| p.to_array())
+                .flat_map(f32::to_le_bytes)
+                .collect();
+            let index_bytes: Vec<u8> = mesh
+                .vertex_indices
+                .iter()
+                .flat_map(|i| i.to_le_bytes())
+                .collect();
+
+            let vertex_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("depth screenshot vertex buffer"),
+                contents: &bytes,
+                usage: wgpu::BufferUsages::VERTEX,
+            });
+            let index_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("depth screenshot index buffer"),
+                contents: &index_bytes,
+                usage: wgpu::BufferUsages::INDEX,
+            });
+            (
+                vertex_buffer,
+                index_buffer,
+                mesh.vertex_indices.len() as u32,
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bookmark_file_names_sanitized() {
+        assert_eq!(
+            vec!["front.png", "a_b.png", "camera2.png"],
+            bookmark_file_names([" front ", "a/b", "  "])
+        );
+    }
+
+    #[test]
+    fn bookmark_file_names_duplicates() {
+        assert_eq!(
+            vec!["front.png", "front_1.png", "Front_2.png", "side.png"],
+            bookmark_file_names(["front", "front", "Front", "side"])
+        );
+    }
+
+    #[test]
+    fn bookmark_file_names_suffix_in_use() {
+        // The suffixed name shouldn't replace a later bookmark's image.
+        assert_eq!(
+            vec!["a.png", "a_2.png", "a_1.png"],
+            bookmark_file_names(["a", "a", "a_1"])
+        );
+    }
+
+    #[test]
+    fn unpadded_rows_removes_row_padding() {
+        // Rows of 3 pixels are padded from 12 to 256 bytes.
+        let row_size = padded_bytes_per_row(3) as usize;
+        assert_eq!(256, row_size);
+
+        let mut data = vec![0u8; row_size * 2];
+        data[..12].copy_from_slice(&[1; 12]);
+        data[row_size..row_size + 12].copy_from_slice(&[2; 12]);
+
+        let expected: Vec<u8> = [[1u8; 12], [2u8; 12]].concat();
+        assert_eq!(expected, unpadded_rows(&data, 3, 2));
+    }
+
+    #[test]
+    fn unpadded_rows_aligned_width() {
+        let data: Vec<u8> = (0..=255).cycle().take(256 * 2).collect();
+        assert_eq!(data, unpadded_rows(&data, 64, 2));
+    }
+}
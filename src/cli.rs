@@ -0,0 +1,522 @@
+//! Command line modes that run without opening the application window.
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Context};
+use futures::executor::block_on;
+use nutexb::NutexbFile;
+use nutexb_wgpu::TextureRenderer;
+use ssbh_data::matl_data::{MatlData, ParamId};
+use ssbh_wgpu::{BoneNameRenderer, RenderSettings, SharedRenderData, SsbhRenderer};
+
+use crate::{
+    app::camera_transforms,
+    capture::{render_nutexb_to_images, render_screenshot},
+    csv::{csv_line, skel_csv_rows, SKEL_CSV_HEADER},
+    validation::{ModelValidationErrors, Severity},
+    CameraValues, RenderState, TextureDimension,
+};
+
+// The texture format doesn't need to match a window surface.
+const HEADLESS_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Bgra8Unorm;
+
+/// Run the command line mode for `args` if present and return the process exit code.
+pub fn run(args: &mut pico_args::Arguments) -> Option<i32> {
+    if args.contains("--headless") {
+        Some(exit_code(headless_screenshot(args).map(|_| 0)))
+    } else if args.contains("--validate") {
+        // Distinguish failing to validate from finding errors.
+        Some(exit_code_or(validate_folders(args), 3))
+    } else if args.contains("--export-matl-csv") {
+        Some(exit_code(export_matl_csv(args).map(|_| 0)))
+    } else if args.contains("--convert-nutexb") {
+        Some(exit_code(convert_nutexbs(args)))
+    } else if args.contains("--export-skel-csv") {
+        Some(exit_code(export_skel_csv(args).map(|_| 0)))
+    } else {
+        None
+    }
+}
+
+fn exit_code(result: anyhow::Result<i32>) -> i32 {
+    exit_code_or(result, 1)
+}
+
+fn exit_code_or(result: anyhow::Result<i32>, error_code: i32) -> i32 {
+    match result {
+        Ok(code) => code,
+        Err(e) => {
+            eprintln!("Error: {e:?}");
+            error_code
+        }
+    }
+}
+
+/// Load [RenderSettings] from a JSON object of setting names and values like `{"render_bloom": false}`.
+/// Missing settings use the default values.
+fn load_render_settings(path: &Path) -> anyhow::Result<RenderSettings> {
+    let data = std::fs::read(path)?;
+    render_settings_from_json(&data)
+}
+
+fn render_settings_from_json(data: &[u8]) -> anyhow::Result<RenderSettings> {
+    let values: std::collections::BTreeMap<String, bool> = serde_json::from_slice(data)?;
+
+    let mut settings = RenderSettings::default();
+    for (name, value) in values {
+        let setting = match name.as_str() {
+            "render_diffuse" => &mut settings.render_diffuse,
+            "render_specular" => &mut settings.render_specular,
+            "render_emission" => &mut settings.render_emission,
+            "render_rim_lighting" => &mut settings.render_rim_lighting,
+            "render_bloom" => &mut settings.render_bloom,
+            "render_shadows" => &mut settings.render_shadows,
+            "render_vertex_color" => &mut settings.render_vertex_color,
+            "scale_vertex_color" => &mut settings.scale_vertex_color,
+            _ => return Err(anyhow!("Unsupported render setting {name:?}")),
+        };
+        *setting = value;
+    }
+    Ok(settings)
+}
+
+/// `--headless --folder <path> --output <file.png> [--width N] [--height N] [--settings <file.json>] [--camera <file.json>]`
+fn headless_screenshot(args: &mut pico_args::Arguments) -> anyhow::Result<()> {
+    let folder: PathBuf = args.value_from_str("--folder")?;
+    let output: PathBuf = args.value_from_str("--output")?;
+    let width: u32 = args.opt_value_from_str("--width")?.unwrap_or(1280);
+    let height: u32 = args.opt_value_from_str("--height")?.unwrap_or(720);
+    let settings_file: Option<PathBuf> = args.opt_value_from_str("--settings")?;
+    let camera_file: Option<PathBuf> = args.opt_value_from_str("--camera")?;
+
+    let settings = match settings_file {
+        Some(path) => load_render_settings(&path)
+            .with_context(|| format!("Failed to load render settings from {path:?}"))?,
+        None => RenderSettings::default(),
+    };
+
+    // Don't use the application's camera to make the output the same on every machine.
+    let camera = match camera_file {
+        Some(path) => std::fs::read(&path)
+            .map_err(anyhow::Error::from)
+            .and_then(|data| Ok(serde_json::from_slice::<CameraValues>(&data)?))
+            .with_context(|| format!("Failed to load camera from {path:?}"))?,
+        None => CameraValues::default(),
+    };
+
+    let (device, queue, adapter_info) = headless_device()?;
+    let mut render_state = headless_render_state(&device, &queue, adapter_info, width, height);
+    render_state.render_settings = settings;
+    render_state
+        .renderer
+        .update_render_settings(&queue, &render_state.render_settings);
+
+    let models = ssbh_wgpu::load_model_folders(&folder);
+    if models.is_empty() {
+        return Err(anyhow!("No model folders found in {folder:?}"));
+    }
+    render_state.render_models = ssbh_wgpu::load_render_models(
+        &device,
+        &queue,
+        models.iter().map(|(_, m)| m),
+        &render_state.shared_data,
+    );
+
+    let transforms = camera_transforms(width as f32, height as f32, 1.0, &camera);
+    render_state.renderer.update_camera(&queue, transforms);
+
+    let image = render_screenshot(
+        &device,
+        &queue,
+        &mut render_state,
+        width,
+        height,
+        HEADLESS_FORMAT,
+    );
+    image
+        .save(&output)
+        .with_context(|| format!("Failed to save screenshot to {output:?}"))?;
+
+    println!("Saved screenshot to {output:?}");
+    Ok(())
+}
+
+/// `--validate --folder <path1> [--folder <path2>]... --output <report.txt>`
+///
+/// Returns 0 if there are no issues, 1 if there are any errors, and 2 if there are only warnings.
+/// Folders that don't exist or contain no models are returned as errors.
+fn validate_folders(args: &mut pico_args::Arguments) -> anyhow::Result<i32> {
+    let folders: Vec<PathBuf> = args.values_from_str("--folder")?;
+    let output: PathBuf = args.value_from_str("--output")?;
+    if folders.is_empty() {
+        return Err(anyhow!("Expected at least one --folder"));
+    }
+
+    // The shader database and default textures are needed for some checks.
+    let (device, queue, _) = headless_device()?;
+    let shared_data = SharedRenderData::new(&device, &queue);
+
+    let mut report = String::from("folder\tfile\tseverity\tmessage\n");
+    let mut max_severity = None;
+    for folder in &folders {
+        if !folder.is_dir() {
+            return Err(anyhow!("Folder {folder:?} does not exist"));
+        }
+
+        let models = ssbh_wgpu::load_model_folders(folder);
+        if models.is_empty() {
+            return Err(anyhow!("No model folders found in {folder:?}"));
+        }
+
+        for (path, model) in models {
+            let validation = ModelValidationErrors::from_model(
+                &model,
+                shared_data.database(),
+                shared_data
+                    .default_textures()
+                    .iter()
+                    .map(|(f, _, d)| (f, d.into())),
+            );
+
+            for message in validation.messages() {
+                // Keep one message per line.
+                let text = message.message.replace('\n', " ");
+                report += &format!(
+                    "{}\t{}\t{}\t{}\n",
+                    path.display(),
+                    message.file_name,
+                    message.severity,
+                    text
+                );
+                max_severity = max_severity.max(Some(message.severity));
+            }
+        }
+    }
+
+    std::fs::write(&output, report)
+        .with_context(|| format!("Failed to write report to {output:?}"))?;
+    println!("Saved validation report to {output:?}");
+
+    Ok(match max_severity {
+        Some(Severity::Error) => 1,
+        Some(Severity::Warning) => 2,
+        None => 0,
+    })
+}
+
+/// `--convert-nutexb --folder <path> --output-dir <dir> [--overwrite]`
+///
+/// Returns 1 if any texture failed to read, decode, or save.
+fn convert_nutexbs(args: &mut pico_args::Arguments) -> anyhow::Result<i32> {
+    let overwrite = args.contains("--overwrite");
+    let folder: PathBuf = args.value_from_str("--folder")?;
+    let output_dir: PathBuf = args.value_from_str("--output-dir")?;
+
+    std::fs::create_dir_all(&output_dir)
+        .with_context(|| format!("Failed to create output folder {output_dir:?}"))?;
+
+    let (device, queue, _) = headless_device()?;
+    let texture_renderer = TextureRenderer::new(&device, &queue, HEADLESS_FORMAT);
+
+    let mut converted = 0;
+    let mut skipped = 0;
+    let mut failed = 0;
+    for entry in std::fs::read_dir(&folder)
+        .with_context(|| format!("Failed to read folder {folder:?}"))?
+        .filter_map(|e| e.ok())
+    {
+        let path = entry.path();
+        if path.extension().and_then(|e| e.to_str()) != Some("nutexb") {
+            continue;
+        }
+        let Some(name) = path.file_stem().map(|n| n.to_string_lossy().to_string()) else {
+            continue;
+        };
+
+        let nutexb = match NutexbFile::read_from_file(&path) {
+            Ok(nutexb) => nutexb,
+            Err(e) => {
+                eprintln!("Failed to read {path:?}: {e}");
+                failed += 1;
+                continue;
+            }
+        };
+
+        let outputs = nutexb_output_files(
+            &output_dir,
+            &name,
+            TextureDimension::from_nutexb(&nutexb),
+            nutexb.footer.depth,
+        );
+        if !overwrite && outputs.iter().all(|p| p.exists()) {
+            skipped += 1;
+            continue;
+        }
+
+        match render_nutexb_to_images(&device, &queue, &texture_renderer, &nutexb) {
+            Ok(images) => {
+                let mut saved = true;
+                for (image, output) in images.iter().zip(&outputs) {
+                    if let Err(e) = image.save(output) {
+                        eprintln!("Failed to save PNG to {output:?}: {e}");
+                        saved = false;
+                    }
+                }
+                if saved {
+                    converted += 1;
+                } else {
+                    failed += 1;
+                }
+            }
+            Err(e) => {
+                eprintln!("Failed to decode {path:?}: {e}");
+                failed += 1;
+            }
+        }
+    }
+
+    println!(
+        "Converted {converted} textures to {output_dir:?} and skipped {skipped} existing textures"
+    );
+    if failed > 0 {
+        eprintln!("Failed to convert {failed} textures");
+        Ok(1)
+    } else {
+        Ok(0)
+    }
+}
+
+/// The PNG files for each cube map face or 3D texture layer.
+fn nutexb_output_files(
+    output_dir: &Path,
+    name: &str,
+    dimension: TextureDimension,
+    depth: u32,
+) -> Vec<PathBuf> {
+    match dimension {
+        TextureDimension::TextureCube => (0..6)
+            .map(|face| output_dir.join(format!("{name}_face{face}.png")))
+            .collect(),
+        TextureDimension::Texture3d => (0..depth)
+            .map(|layer| output_dir.join(format!("{name}_layer{layer}.png")))
+            .collect(),
+        _ => vec![output_dir.join(format!("{name}.png"))],
+    }
+}
+
+/// `--export-matl-csv --folder <path> --output <params.csv>`
+fn export_matl_csv(args: &mut pico_args::Arguments) -> anyhow::Result<()> {
+    let folder: PathBuf = args.value_from_str("--folder")?;
+    let output: PathBuf = args.value_from_str("--output")?;
+
+    let mut csv = String::from("folder,file,material_label,param_id,value,value2,value3,value4\n");
+    for (path, model) in ssbh_wgpu::load_model_folders(&folder) {
+        let folder_name = path.to_string_lossy();
+        for (file_name, matl) in &model.matls {
+            match matl {
+                Ok(matl) => {
+                    for row in matl_csv_rows(matl) {
+                        let fields = [folder_name.to_string(), file_name.clone()];
+                        csv += &csv_line(fields.into_iter().chain(row));
+                    }
+                }
+                Err(e) => eprintln!("Failed to read {:?}: {}", path.join(file_name), e),
+            }
+        }
+    }
+
+    std::fs::write(&output, csv).with_context(|| format!("Failed to write CSV to {output:?}"))?;
+    println!("Saved material parameters to {output:?}");
+    Ok(())
+}
+
+/// The material label, param ID, and values for each parameter in `matl`.
+fn matl_csv_rows(matl: &MatlData) -> Vec<Vec<String>> {
+    let mut rows = Vec::new();
+    for entry in &matl.entries {
+        let mut add = |param_id: ParamId, values: Vec<String>| {
+            let mut row = vec![entry.material_label.clone(), param_id.to_string()];
+            row.extend(values);
+            rows.push(row);
+        };
+
+        for p in &entry.booleans {
+            add(p.param_id, vec![p.data.to_string()]);
+        }
+        for p in &entry.floats {
+            add(p.param_id, vec![p.data.to_string()]);
+        }
+        for p in &entry.vectors {
+            add(
+                p.param_id,
+                p.data.to_array().map(|v| v.to_string()).to_vec(),
+            );
+        }
+        for p in &entry.textures {
+            add(p.param_id, vec![p.data.clone()]);
+        }
+        // Use a single column for parameters with many fields.
+        for p in &entry.samplers {
+            add(p.param_id, vec![format!("{:?}", p.data)]);
+        }
+        for p in &entry.blend_states {
+            add(p.param_id, vec![format!("{:?}", p.data)]);
+        }
+        for p in &entry.rasterizer_states {
+            add(p.param_id, vec![format!("{:?}", p.data)]);
+        }
+        for p in &entry.uv_transforms {
+            add(p.param_id, vec![format!("{:?}", p.data)]);
+        }
+    }
+    rows
+}
+
+/// `--export-skel-csv --folder <path> --output <bones.csv>`
+fn export_skel_csv(args: &mut pico_args::Arguments) -> anyhow::Result<()> {
+    let folder: PathBuf = args.value_from_str("--folder")?;
+    let output: PathBuf = args.value_from_str("--output")?;
+
+    let mut csv = format!("folder,file,{SKEL_CSV_HEADER}\n");
+    for (path, model) in ssbh_wgpu::load_model_folders(&folder) {
+        let folder_name = path.to_string_lossy();
+        for (file_name, skel) in &model.skels {
+            match skel {
+                Ok(skel) => {
+                    for row in skel_csv_rows(skel) {
+                        let fields = [folder_name.to_string(), file_name.clone()];
+                        csv += &csv_line(fields.into_iter().chain(row));
+                    }
+                }
+                Err(e) => eprintln!("Failed to read {:?}: {}", path.join(file_name), e),
+            }
+        }
+    }
+
+    std::fs::write(&output, csv).with_context(|| format!("Failed to write CSV to {output:?}"))?;
+    println!("Saved bones to {output:?}");
+    Ok(())
+}
+
+fn headless_device() -> anyhow::Result<(wgpu::Device, wgpu::Queue, wgpu::AdapterInfo)> {
+    let instance = wgpu::Instance::new(wgpu::InstanceDescriptor {
+        backends: wgpu::Backends::all(),
+        ..Default::default()
+    });
+
+    // Rendering to textures doesn't require a surface or window.
+    let adapter = block_on(instance.request_adapter(&wgpu::RequestAdapterOptions {
+        power_preference: wgpu::PowerPreference::HighPerformance,
+        compatible_surface: None,
+        force_fallback_adapter: false,
+    }))
+    .ok_or_else(|| anyhow!("Failed to find a compatible graphics adapter"))?;
+
+    let (device, queue) = block_on(adapter.request_device(
+        &wgpu::DeviceDescriptor {
+            label: None,
+            required_features: wgpu::Features::default() | ssbh_wgpu::REQUIRED_FEATURES,
+            required_limits: wgpu::Limits::default(),
+            memory_hints: wgpu::MemoryHints::default(),
+        },
+        None,
+    ))?;
+
+    Ok((device, queue, adapter.get_info()))
+}
+
+fn headless_render_state(
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+    adapter_info: wgpu::AdapterInfo,
+    width: u32,
+    height: u32,
+) -> RenderState {
+    let renderer = SsbhRenderer::new(device, queue, width, height, 1.0, [0.0; 4], HEADLESS_FORMAT);
+    let texture_renderer = TextureRenderer::new(device, queue, HEADLESS_FORMAT);
+    let bone_name_renderer = BoneNameRenderer::new(
+        device,
+        queue,
+        Some(crate::FONT_BYTES.to_vec()),
+        HEADLESS_FORMAT,
+    );
+
+    RenderState::new(
+        device,
+        queue,
+        adapter_info,
+        renderer,
+        texture_renderer,
+        bone_name_renderer,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nutexb_output_files_2d() {
+        assert_eq!(
+            vec![Path::new("out").join("def_mario_001_col.png")],
+            nutexb_output_files(
+                Path::new("out"),
+                "def_mario_001_col",
+                TextureDimension::Texture2d,
+                1
+            )
+        );
+    }
+
+    #[test]
+    fn nutexb_output_files_cube() {
+        let files = nutexb_output_files(
+            Path::new("out"),
+            "reflection_cubemap",
+            TextureDimension::TextureCube,
+            1,
+        );
+        assert_eq!(6, files.len());
+        assert_eq!(
+            Path::new("out").join("reflection_cubemap_face0.png"),
+            files[0]
+        );
+        assert_eq!(
+            Path::new("out").join("reflection_cubemap_face5.png"),
+            files[5]
+        );
+    }
+
+    #[test]
+    fn nutexb_output_files_3d() {
+        assert_eq!(
+            vec![
+                Path::new("out").join("color_grading_lut_layer0.png"),
+                Path::new("out").join("color_grading_lut_layer1.png"),
+            ],
+            nutexb_output_files(
+                Path::new("out"),
+                "color_grading_lut",
+                TextureDimension::Texture3d,
+                2
+            )
+        );
+    }
+
+    #[test]
+    fn render_settings_from_json_overrides() {
+        let settings =
+            render_settings_from_json(br#"{"render_bloom": false, "render_shadows": false}"#)
+                .unwrap();
+        assert!(!settings.render_bloom);
+        assert!(!settings.render_shadows);
+        assert_eq!(
+            RenderSettings::default().render_diffuse,
+            settings.render_diffuse
+        );
+    }
+
+    #[test]
+    fn render_settings_from_json_unsupported() {
+        assert!(render_settings_from_json(br#"{"render_everything": true}"#).is_err());
+    }
+}
@@ -0,0 +1,125 @@
+//! CSV exports shared by the editors and the command line.
+use ssbh_data::skel_data::SkelData;
+
+/// The column names for [skel_csv_rows].
+pub const SKEL_CSV_HEADER: &str =
+    "index,name,parent_index,parent_name,tx,ty,tz,rx,ry,rz,rw,sx,sy,sz";
+
+/// The hierarchy and decomposed local transform for each bone in `skel`.
+/// Root bones use `-1` for the parent index and `root` for the parent name.
+/// Quaternion rotations use the xyzw order.
+pub fn skel_csv_rows(skel: &SkelData) -> Vec<Vec<String>> {
+    skel.bones
+        .iter()
+        .enumerate()
+        .map(|(i, bone)| {
+            let (parent_index, parent_name) = match bone.parent_index {
+                Some(p) => (
+                    p.to_string(),
+                    skel.bones.get(p).map(|b| b.name.as_str()).unwrap_or("root"),
+                ),
+                None => ("-1".to_owned(), "root"),
+            };
+            let (s, r, t) =
+                glam::Mat4::from_cols_array_2d(&bone.transform).to_scale_rotation_translation();
+            [
+                i.to_string(),
+                bone.name.clone(),
+                parent_index,
+                parent_name.to_owned(),
+            ]
+            .into_iter()
+            .chain(
+                [t.x, t.y, t.z, r.x, r.y, r.z, r.w, s.x, s.y, s.z]
+                    .iter()
+                    .map(|v| v.to_string()),
+            )
+            .collect()
+        })
+        .collect()
+}
+
+/// The bone list for `skel` as CSV with a header row.
+pub fn skel_csv(skel: &SkelData) -> String {
+    let mut csv = format!("{SKEL_CSV_HEADER}\n");
+    for row in skel_csv_rows(skel) {
+        csv += &csv_line(row);
+    }
+    csv
+}
+
+/// A single CSV row from `fields` with quoting as needed and a trailing newline.
+pub fn csv_line<S: AsRef<str>>(fields: impl IntoIterator<Item = S>) -> String {
+    let mut line = fields
+        .into_iter()
+        .map(|f| csv_field(f.as_ref()))
+        .collect::<Vec<_>>()
+        .join(",");
+    line.push('\n');
+    line
+}
+
+/// Quote `field` if it contains characters with special meaning in CSV.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_bone;
+
+    #[test]
+    fn skel_csv_rows_parents() {
+        let mut skel = SkelData {
+            major_version: 1,
+            minor_version: 0,
+            bones: vec![test_bone("Trans", None), test_bone("Rot", Some(0))],
+        };
+        skel.bones[0].transform[3] = [1.0, 2.0, 3.0, 1.0];
+        skel.bones[1].transform[3] = [0.5, 2.0, 3.0, 1.0];
+
+        assert_eq!(
+            vec![
+                vec!["0", "Trans", "-1", "root", "1", "2", "3", "0", "0", "0", "1", "1", "1", "1"],
+                vec!["1", "Rot", "0", "Trans", "0.5", "2", "3", "0", "0", "0", "1", "1", "1", "1"],
+            ],
+            skel_csv_rows(&skel)
+        );
+    }
+
+    #[test]
+    fn skel_csv_quoted_names() {
+        let skel = SkelData {
+            major_version: 1,
+            minor_version: 0,
+            bones: vec![test_bone("a,b", None)],
+        };
+
+        assert_eq!(
+            "index,name,parent_index,parent_name,tx,ty,tz,rx,ry,rz,rw,sx,sy,sz\n\
+             0,\"a,b\",-1,root,0,0,0,0,0,0,1,1,1,1\n",
+            skel_csv(&skel)
+        );
+    }
+
+    #[test]
+    fn csv_line_fields() {
+        assert_eq!("a,\"b,c\",\n", csv_line(["a", "b,c", ""]));
+    }
+
+    #[test]
+    fn csv_field_without_quotes() {
+        assert_eq!("alp_mario_002", csv_field("alp_mario_002"));
+    }
+
+    #[test]
+    fn csv_field_with_quotes() {
+        assert_eq!("\"a,b\"", csv_field("a,b"));
+        assert_eq!("\"a \"\"b\"\"\"", csv_field("a \"b\""));
+    }
+}
@@ -2,7 +2,7 @@ use crate::{
     path::folder_editor_title, save_file, save_file_as, validation::AdjValidationError,
     EditorResponse,
 };
-use egui::{special_emojis::GITHUB, ScrollArea};
+use egui::{special_emojis::GITHUB, RichText, ScrollArea};
 
 use ssbh_data::{adj_data::AdjEntryData, prelude::*};
 use std::path::Path;
@@ -51,9 +51,13 @@ pub fn adj_editor(
             ui.separator();
 
             // TODO: Add button to remove unused entries.
-            if !validation_errors.is_empty()
+            let missing_count = validation_errors
+                .iter()
+                .filter(|e| matches!(e, AdjValidationError::MissingRenormalEntry { .. }))
+                .count();
+            if missing_count > 0
                 && ui
-                    .button(format!("Add {} missing entries", validation_errors.len()))
+                    .button(format!("Add {missing_count} missing entries"))
                     .clicked()
             {
                 changed |= add_missing_adj_entries(adj, validation_errors, mesh);
@@ -64,21 +68,37 @@ pub fn adj_editor(
                 .show(ui, |ui| {
                     egui::Grid::new("adj_grid").show(ui, |ui| {
                         // TODO: How to best display adjacency data?
-                        ui.heading("Mesh Object Index");
+                        ui.heading("Mesh Object");
                         ui.heading("Vertex Adjacency Count");
                         ui.end_row();
 
-                        for entry in &adj.entries {
-                            // TODO: Make this a combobox or an index in advanced mode?
-                            // TODO: Fallback to indices if the mesh is missing?
-                            if let Some(o) =
-                                mesh.and_then(|mesh| mesh.objects.get(entry.mesh_object_index))
-                            {
-                                ui.label(format!("{} ({})", entry.mesh_object_index, o.name));
+                        for (i, entry) in adj.entries.iter_mut().enumerate() {
+                            let errors: Vec<_> = validation_errors
+                                .iter()
+                                .filter(|e| e.entry_index() == Some(i))
+                                .collect();
+
+                            if let Some(mesh) = mesh {
+                                changed |= mesh_object_combo_box(
+                                    ui,
+                                    &mut entry.mesh_object_index,
+                                    ("adj_mesh_object", i),
+                                    mesh,
+                                );
                             } else {
-                                ui.label(entry.mesh_object_index.to_string());
+                                // Fall back to editing the index directly without a mesh.
+                                changed |= ui
+                                    .add(egui::DragValue::new(&mut entry.mesh_object_index))
+                                    .changed();
                             }
                             ui.label(entry.vertex_adjacency.len().to_string());
+
+                            // Show errors next to the entry since there aren't many fields.
+                            ui.vertical(|ui| {
+                                for e in errors {
+                                    ui.colored_label(ui.visuals().error_fg_color, e.to_string());
+                                }
+                            });
                             ui.end_row();
                         }
                     });
@@ -93,6 +113,39 @@ pub fn adj_editor(
     }
 }
 
+fn mesh_object_combo_box(
+    ui: &mut egui::Ui,
+    mesh_object_index: &mut usize,
+    id: impl std::hash::Hash,
+    mesh: &MeshData,
+) -> bool {
+    let mut changed = false;
+    // Highlight references to missing mesh objects.
+    let mut text = RichText::new(mesh_object_text(*mesh_object_index, mesh));
+    if *mesh_object_index >= mesh.objects.len() {
+        text = text.color(ui.visuals().error_fg_color);
+    }
+
+    egui::ComboBox::from_id_salt(id)
+        .selected_text(text)
+        .width(300.0)
+        .show_ui(ui, |ui| {
+            for i in 0..mesh.objects.len() {
+                changed |= ui
+                    .selectable_value(mesh_object_index, i, mesh_object_text(i, mesh))
+                    .changed();
+            }
+        });
+    changed
+}
+
+fn mesh_object_text(mesh_object_index: usize, mesh: &MeshData) -> String {
+    match mesh.objects.get(mesh_object_index) {
+        Some(o) => format!("{mesh_object_index} ({}, {})", o.name, o.subindex),
+        None => mesh_object_index.to_string(),
+    }
+}
+
 pub fn add_missing_adj_entries(
     adj: &mut AdjData,
     validation_errors: &[AdjValidationError],
@@ -114,6 +167,8 @@ pub fn add_missing_adj_entries(
                         changed = true;
                     }
                 }
+                AdjValidationError::InvalidMeshObjectIndex { .. }
+                | AdjValidationError::VertexAdjacencyLengthMismatch { .. } => (),
             }
         }
     }
@@ -1,17 +1,22 @@
 use crate::{
-    app::{AnimEditorState, AnimEditorTab},
+    app::{AnimEditorState, AnimEditorTab, RetargetState, WARNING_COLOR},
+    horizontal_separator_empty,
     path::folder_editor_title,
-    save_file, save_file_as, EditorResponse,
+    save_file, save_file_as,
+    widgets::clipboard_buttons,
+    EditorResponse,
 };
 use egui::{
-    special_emojis::GITHUB, CentralPanel, CollapsingHeader, DragValue, Grid, RichText, ScrollArea,
-    SidePanel,
+    special_emojis::GITHUB, Button, CentralPanel, CollapsingHeader, DragValue, Grid, RichText,
+    ScrollArea, SidePanel,
 };
-use egui_plot::{Legend, Line, Plot, PlotPoint};
+use egui_plot::{Legend, Line, Plot, PlotPoint, PlotTransform, Points};
+use rfd::FileDialog;
 
 use ssbh_data::{
-    anim_data::{GroupData, TrackData, TrackValues},
+    anim_data::{GroupData, GroupType, NodeData, TrackData, TrackValues, Transform, UvTransform},
     prelude::*,
+    Vector3, Vector4,
 };
 use std::path::Path;
 
@@ -20,6 +25,7 @@ pub fn anim_editor(
     folder_name: &Path,
     file_name: &str,
     anim: &mut AnimData,
+    skel: Option<&SkelData>,
     state: &mut AnimEditorState,
 ) -> EditorResponse {
     let mut open = true;
@@ -37,15 +43,34 @@ pub fn anim_editor(
                 ui.menu_button("File", |ui| {
                     if ui.button("Save").clicked() {
                         ui.close_menu();
+                        truncate_keyframes(anim);
                         saved |= save_file(anim, folder_name, file_name);
                     }
 
                     if ui.button("Save As...").clicked() {
                         ui.close_menu();
+                        truncate_keyframes(anim);
                         saved |= save_file_as(anim, folder_name, file_name, "Anim", "nuanmb");
                     }
                 });
 
+                ui.menu_button("Edit", |ui| {
+                    changed |= frame_range_menu(ui, anim, state);
+                    ui.separator();
+
+                    if ui
+                        .button("Retarget Animation...")
+                        .on_hover_text("Rename the bones in the animation to match another skeleton.")
+                        .clicked()
+                    {
+                        ui.close_menu();
+                        state.retarget = Some(RetargetState::new(
+                            anim,
+                            skel.map(skel_bone_names).unwrap_or_default(),
+                        ));
+                    }
+                });
+
                 ui.menu_button("Help", |ui| {
                     if ui.button(format!("{GITHUB} Anim Editor Wiki")).clicked() {
                         ui.close_menu();
@@ -59,6 +84,28 @@ pub fn anim_editor(
             });
             ui.separator();
 
+            ui.horizontal(|ui| {
+                ui.label("Final Frame Index");
+                changed |= ui
+                    .add(
+                        DragValue::new(&mut anim.final_frame_index)
+                            .range(0.0..=f32::MAX)
+                            .speed(1.0)
+                            .max_decimals(0),
+                    )
+                    .on_hover_text("The index of the last frame. Keyframes after this frame are removed when saving.")
+                    .changed();
+
+                let truncated = truncated_keyframe_count(anim);
+                if truncated > 0 {
+                    ui.label(
+                        RichText::new(format!("⚠ {truncated} keyframes will be truncated"))
+                            .color(WARNING_COLOR),
+                    );
+                }
+            });
+            ui.separator();
+
             ui.horizontal(|ui| {
                 ui.selectable_value(
                     &mut state.editor_tab,
@@ -77,6 +124,8 @@ pub fn anim_editor(
                 );
             });
 
+            track_filter_bar(ui, state);
+
             changed |= match state.editor_tab {
                 AnimEditorTab::Hierarchy => hierarchy_view(ui, anim, state),
                 AnimEditorTab::Graph => graph_view(ui, anim, state),
@@ -84,6 +133,8 @@ pub fn anim_editor(
             };
         });
 
+    changed |= retarget_window(ctx, &mut state.retarget, anim);
+
     EditorResponse {
         open,
         changed,
@@ -92,22 +143,463 @@ pub fn anim_editor(
     }
 }
 
-fn hierarchy_view(ui: &mut egui::Ui, anim: &mut AnimData, _state: &mut AnimEditorState) -> bool {
+fn skel_bone_names(skel: &SkelData) -> Vec<String> {
+    skel.bones.iter().map(|b| b.name.clone()).collect()
+}
+
+fn retarget_window(
+    ctx: &egui::Context,
+    retarget: &mut Option<RetargetState>,
+    anim: &mut AnimData,
+) -> bool {
     let mut changed = false;
+
+    if let Some(state) = retarget {
+        let mut open = true;
+        let mut close = false;
+        egui::Window::new("Retarget Animation")
+            .open(&mut open)
+            .default_height(500.0)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    if ui.button("Load Target Skel...").clicked() {
+                        if let Some(file) = FileDialog::new()
+                            .add_filter("Skel", &["nusktb"])
+                            .pick_file()
+                        {
+                            match SkelData::from_file(&file) {
+                                Ok(skel) => state.set_target_bones(skel_bone_names(&skel)),
+                                Err(e) => {
+                                    log::error!("Failed to read skel {:?}: {}", file, e)
+                                }
+                            }
+                        }
+                    }
+                    ui.label(format!("{} target bones", state.target_bone_names.len()));
+                });
+                ui.separator();
+
+                ScrollArea::vertical()
+                    .max_height(400.0)
+                    .auto_shrink([false, true])
+                    .show(ui, |ui| {
+                        Grid::new("retarget_grid").striped(true).show(ui, |ui| {
+                            ui.heading("Anim Bone");
+                            ui.heading("Target Bone");
+                            ui.end_row();
+
+                            for (i, (name, target)) in state.mapping.iter_mut().enumerate() {
+                                ui.label(name.as_str());
+                                egui::ComboBox::from_id_salt(("retarget", i))
+                                    .selected_text(target.as_deref().unwrap_or("None"))
+                                    .width(250.0)
+                                    .show_ui(ui, |ui| {
+                                        ui.selectable_value(target, None, "None");
+                                        for bone in &state.target_bone_names {
+                                            ui.selectable_value(target, Some(bone.clone()), bone);
+                                        }
+                                    });
+                                ui.end_row();
+                            }
+                        });
+                    });
+                horizontal_separator_empty(ui);
+
+                ui.horizontal(|ui| {
+                    if ui
+                        .button("Retarget")
+                        .on_hover_text("Bones without a target bone are not renamed.")
+                        .clicked()
+                    {
+                        changed |= retarget_bones(anim, &state.mapping);
+                        close = true;
+                    }
+                    if ui.button("Cancel").clicked() {
+                        close = true;
+                    }
+                });
+            });
+
+        if !open || close {
+            *retarget = None;
+        }
+    }
+
+    changed
+}
+
+/// Renames the transform nodes using the (name, target name) pairs in `mapping`.
+fn retarget_bones(anim: &mut AnimData, mapping: &[(String, Option<String>)]) -> bool {
+    let mut changed = false;
+    for node in anim
+        .groups
+        .iter_mut()
+        .filter(|g| g.group_type == GroupType::Transform)
+        .flat_map(|g| g.nodes.iter_mut())
+    {
+        if let Some((_, Some(target))) = mapping.iter().find(|(name, _)| *name == node.name) {
+            if node.name != *target {
+                node.name.clone_from(target);
+                changed = true;
+            }
+        }
+    }
+    changed
+}
+
+/// The number of keyframes after the final frame index for all tracks.
+fn truncated_keyframe_count(anim: &AnimData) -> usize {
+    let frame_count = anim.final_frame_index.max(0.0) as usize + 1;
+    anim.groups
+        .iter()
+        .flat_map(|g| g.nodes.iter().flat_map(|n| n.tracks.iter()))
+        .map(|t| t.values.len().saturating_sub(frame_count))
+        .sum()
+}
+
+/// Removes keyframes after the final frame index for all tracks.
+fn truncate_keyframes(anim: &mut AnimData) {
+    let frame_count = anim.final_frame_index.max(0.0) as usize + 1;
+    for track in anim
+        .groups
+        .iter_mut()
+        .flat_map(|g| g.nodes.iter_mut().flat_map(|n| n.tracks.iter_mut()))
+    {
+        match &mut track.values {
+            TrackValues::Transform(v) => v.truncate(frame_count),
+            TrackValues::UvTransform(v) => v.truncate(frame_count),
+            TrackValues::Float(v) => v.truncate(frame_count),
+            TrackValues::PatternIndex(v) => v.truncate(frame_count),
+            TrackValues::Boolean(v) => v.truncate(frame_count),
+            TrackValues::Vector4(v) => v.truncate(frame_count),
+        }
+    }
+}
+
+fn frame_range_menu(ui: &mut egui::Ui, anim: &mut AnimData, state: &mut AnimEditorState) -> bool {
+    let mut changed = false;
+    let final_frame_index = anim.final_frame_index.max(0.0) as usize;
+
+    ui.label("Copy Frame Range");
+    ui.horizontal(|ui| {
+        ui.label("Start");
+        ui.add(DragValue::new(&mut state.frame_range_start).range(0..=final_frame_index));
+        ui.label("End");
+        ui.add(
+            DragValue::new(&mut state.frame_range_end)
+                .range(state.frame_range_start..=final_frame_index),
+        );
+    });
+    if ui
+        .button("Copy Frame Range")
+        .on_hover_text("Copy the frames for all tracks matching the current filter.")
+        .clicked()
+    {
+        ui.close_menu();
+        state.frame_range_clipboard = Some(copy_frame_range(
+            anim,
+            state.frame_range_start..=state.frame_range_end,
+            &state.node_filter,
+            &state.hidden_group_types,
+        ));
+    }
+    ui.separator();
+
+    ui.label("Paste Frame Range");
+    ui.horizontal(|ui| {
+        ui.label("Target Frame");
+        ui.add(DragValue::new(&mut state.paste_target_frame));
+    });
+    ui.checkbox(&mut state.paste_shifts_frames, "Shift Existing Frames")
+        .on_hover_text(
+            "Insert the copied frames instead of overwriting the existing frames. \
+            Later frames are shifted for all tracks to keep the animation in sync.",
+        );
+    if ui
+        .add_enabled(
+            state.frame_range_clipboard.is_some(),
+            Button::new("Paste Frame Range"),
+        )
+        .on_hover_text("Paste to the tracks with the same group, node, and track name.")
+        .on_disabled_hover_text("Copy a frame range to enable pasting.")
+        .clicked()
+    {
+        ui.close_menu();
+        if let Some(clipboard) = &state.frame_range_clipboard {
+            changed |= paste_frame_range(
+                anim,
+                clipboard,
+                state.paste_target_frame,
+                state.paste_shifts_frames,
+            );
+        }
+    }
+
+    changed
+}
+
+/// The values in a frame range for each track as (group type, node name, track name, values).
+pub type FrameRangeClipboard = Vec<(GroupType, String, String, TrackValues)>;
+
+fn copy_frame_range(
+    anim: &AnimData,
+    frames: std::ops::RangeInclusive<usize>,
+    node_filter: &str,
+    hidden_types: &[GroupType],
+) -> FrameRangeClipboard {
+    fn slice<T: Clone>(values: &[T], frames: &std::ops::RangeInclusive<usize>) -> Vec<T> {
+        let end = (*frames.end() + 1).min(values.len());
+        values
+            .get(*frames.start()..end)
+            .map(|v| v.to_vec())
+            .unwrap_or_default()
+    }
+
+    let mut clipboard = Vec::new();
+    for group in anim
+        .groups
+        .iter()
+        .filter(|group| group_matches_filter(group, node_filter, hidden_types))
+    {
+        for node in group
+            .nodes
+            .iter()
+            .filter(|node| node_matches_filter(&node.name, node_filter))
+        {
+            for track in &node.tracks {
+                let values = match &track.values {
+                    TrackValues::Transform(v) => TrackValues::Transform(slice(v, &frames)),
+                    TrackValues::UvTransform(v) => TrackValues::UvTransform(slice(v, &frames)),
+                    TrackValues::Float(v) => TrackValues::Float(slice(v, &frames)),
+                    TrackValues::PatternIndex(v) => TrackValues::PatternIndex(slice(v, &frames)),
+                    TrackValues::Boolean(v) => TrackValues::Boolean(slice(v, &frames)),
+                    TrackValues::Vector4(v) => TrackValues::Vector4(slice(v, &frames)),
+                };
+                clipboard.push((
+                    group.group_type,
+                    node.name.clone(),
+                    track.name.clone(),
+                    values,
+                ));
+            }
+        }
+    }
+    clipboard
+}
+
+/// Paste the copied values starting at `target_frame`.
+/// Existing frames are overwritten unless `shift_frames` is `true`.
+/// Shifting inserts the frame range into every track to keep the tracks aligned.
+/// Tracks without copied values hold their value from the frame before `target_frame`.
+fn paste_frame_range(
+    anim: &mut AnimData,
+    clipboard: &FrameRangeClipboard,
+    target_frame: usize,
+    shift_frames: bool,
+) -> bool {
+    fn paste<T: Clone>(
+        values: &mut Vec<T>,
+        copied: &[T],
+        target_frame: usize,
+        shift: Option<usize>,
+    ) {
+        // Hold the last value for any frames between the end of the track and the target.
+        if let Some(last) = values.last().cloned() {
+            if values.len() < target_frame {
+                values.resize(target_frame, last);
+            }
+        }
+        let target_frame = target_frame.min(values.len());
+
+        match shift {
+            Some(count) => {
+                // Pad shorter copied tracks to shift by the same number of frames as other tracks.
+                let padding = copied
+                    .last()
+                    .map(|last| vec![last.clone(); count.saturating_sub(copied.len())])
+                    .unwrap_or_default();
+                values.splice(
+                    target_frame..target_frame,
+                    copied.iter().cloned().chain(padding),
+                );
+            }
+            None => {
+                let end = (target_frame + copied.len()).min(values.len());
+                values.splice(target_frame..end, copied.iter().cloned());
+            }
+        }
+    }
+
+    fn shift<T: Clone>(values: &mut Vec<T>, target_frame: usize, count: usize) -> bool {
+        // Tracks that end before the target have no later frames to shift.
+        if target_frame < values.len() {
+            let held = values[target_frame.saturating_sub(1)].clone();
+            values.splice(target_frame..target_frame, vec![held; count]);
+            true
+        } else {
+            false
+        }
+    }
+
+    let shift_count = shift_frames.then(|| {
+        clipboard
+            .iter()
+            .map(|(_, _, _, copied)| copied.len())
+            .max()
+            .unwrap_or_default()
+    });
+
+    let mut changed = false;
+    for group in &mut anim.groups {
+        let group_type = group.group_type;
+        for node in &mut group.nodes {
+            for track in &mut node.tracks {
+                let copied = clipboard
+                    .iter()
+                    .find(|(g, n, t, _)| *g == group_type && *n == node.name && *t == track.name)
+                    .map(|(_, _, _, copied)| copied);
+
+                changed |= match (&mut track.values, copied) {
+                    (TrackValues::Transform(v), Some(TrackValues::Transform(c))) => {
+                        paste(v, c, target_frame, shift_count);
+                        true
+                    }
+                    (TrackValues::UvTransform(v), Some(TrackValues::UvTransform(c))) => {
+                        paste(v, c, target_frame, shift_count);
+                        true
+                    }
+                    (TrackValues::Float(v), Some(TrackValues::Float(c))) => {
+                        paste(v, c, target_frame, shift_count);
+                        true
+                    }
+                    (TrackValues::PatternIndex(v), Some(TrackValues::PatternIndex(c))) => {
+                        paste(v, c, target_frame, shift_count);
+                        true
+                    }
+                    (TrackValues::Boolean(v), Some(TrackValues::Boolean(c))) => {
+                        paste(v, c, target_frame, shift_count);
+                        true
+                    }
+                    (TrackValues::Vector4(v), Some(TrackValues::Vector4(c))) => {
+                        paste(v, c, target_frame, shift_count);
+                        true
+                    }
+                    (values, _) => match (values, shift_count) {
+                        (_, None | Some(0)) => false,
+                        (TrackValues::Transform(v), Some(count)) => shift(v, target_frame, count),
+                        (TrackValues::UvTransform(v), Some(count)) => shift(v, target_frame, count),
+                        (TrackValues::Float(v), Some(count)) => shift(v, target_frame, count),
+                        (TrackValues::PatternIndex(v), Some(count)) => {
+                            shift(v, target_frame, count)
+                        }
+                        (TrackValues::Boolean(v), Some(count)) => shift(v, target_frame, count),
+                        (TrackValues::Vector4(v), Some(count)) => shift(v, target_frame, count),
+                    },
+                };
+            }
+        }
+    }
+
+    // Lengthen the animation to include the pasted frames.
+    if let Some(max_len) = anim
+        .groups
+        .iter()
+        .flat_map(|g| g.nodes.iter().flat_map(|n| n.tracks.iter()))
+        .map(|t| t.values.len())
+        .max()
+    {
+        anim.final_frame_index = anim.final_frame_index.max(max_len.saturating_sub(1) as f32);
+    }
+
+    changed
+}
+
+fn track_filter_bar(ui: &mut egui::Ui, state: &mut AnimEditorState) {
+    ui.horizontal(|ui| {
+        ui.label("Filter");
+        ui.add(
+            egui::TextEdit::singleline(&mut state.node_filter)
+                .hint_text("Node name")
+                .desired_width(200.0),
+        );
+
+        for group_type in [
+            GroupType::Transform,
+            GroupType::Visibility,
+            GroupType::Material,
+            GroupType::Camera,
+        ] {
+            let mut visible = !state.hidden_group_types.contains(&group_type);
+            if ui.checkbox(&mut visible, group_type.to_string()).changed() {
+                if visible {
+                    state.hidden_group_types.retain(|t| *t != group_type);
+                } else {
+                    state.hidden_group_types.push(group_type);
+                }
+            }
+        }
+    });
+}
+
+fn group_matches_filter(group: &GroupData, filter: &str, hidden_types: &[GroupType]) -> bool {
+    // Hide groups with no matching nodes to make filtered results easier to find.
+    !hidden_types.contains(&group.group_type)
+        && group
+            .nodes
+            .iter()
+            .any(|node| node_matches_filter(&node.name, filter))
+}
+
+fn node_matches_filter(name: &str, filter: &str) -> bool {
+    filter.is_empty() || name.to_lowercase().contains(&filter.to_lowercase())
+}
+
+fn hierarchy_view(ui: &mut egui::Ui, anim: &mut AnimData, state: &mut AnimEditorState) -> bool {
+    let mut changed = false;
+    let node_filter = state.node_filter.clone();
+    let hidden_types = state.hidden_group_types.clone();
     ScrollArea::vertical()
         .auto_shrink([false; 2])
         .show(ui, |ui| {
             // TODO: Make names editable?
-            for group in &mut anim.groups {
+            for group in anim
+                .groups
+                .iter_mut()
+                .filter(|group| group_matches_filter(group, &node_filter, &hidden_types))
+            {
                 CollapsingHeader::new(group.group_type.to_string())
-                    .default_open(false)
+                    .open((!node_filter.is_empty()).then_some(true))
                     .show(ui, |ui| {
-                        for node in &mut group.nodes {
+                        for node in group
+                            .nodes
+                            .iter_mut()
+                            .filter(|node| node_matches_filter(&node.name, &node_filter))
+                        {
                             CollapsingHeader::new(&node.name)
                                 .default_open(true)
                                 .show(ui, |ui| {
                                     for track in &mut node.tracks {
                                         changed |= edit_track(ui, track);
+
+                                        // Show the values for each frame below the track flags.
+                                        ui.indent("keyframes_indent", |ui| {
+                                            CollapsingHeader::new(format!(
+                                                "Keyframes ({})",
+                                                track.values.len()
+                                            ))
+                                            .id_salt(ui.id().with(&track.name))
+                                            .default_open(false)
+                                            .show(
+                                                ui,
+                                                |ui| {
+                                                    ScrollArea::horizontal()
+                                                        .id_salt(ui.id().with("scroll"))
+                                                        .show(ui, |ui| {
+                                                            changed |= track_value_grid(ui, track);
+                                                        });
+                                                },
+                                            );
+                                        });
                                     }
                                 });
                         }
@@ -118,7 +610,7 @@ fn hierarchy_view(ui: &mut egui::Ui, anim: &mut AnimData, _state: &mut AnimEdito
 }
 
 fn graph_view(ui: &mut egui::Ui, anim: &mut AnimData, state: &mut AnimEditorState) -> bool {
-    select_track_panel(ui, anim, state);
+    let mut changed = select_track_panel(ui, anim, state);
 
     CentralPanel::default().show_inside(ui, |ui| {
         let label_fmt = |name: &str, value: &PlotPoint| {
@@ -138,143 +630,189 @@ fn graph_view(ui: &mut egui::Ui, anim: &mut AnimData, state: &mut AnimEditorStat
             .label_formatter(label_fmt)
             .legend(Legend::default());
 
-        let mut shapes = Vec::new();
-
-        if let Some(track) = selected_track(&mut anim.groups, state) {
-            match &track.values {
-                TrackValues::Transform(values) => {
-                    let mut translation_x = Vec::new();
-                    let mut translation_y = Vec::new();
-                    let mut translation_z = Vec::new();
-
-                    let mut rotation_x = Vec::new();
-                    let mut rotation_y = Vec::new();
-                    let mut rotation_z = Vec::new();
-                    let mut rotation_w = Vec::new();
-
-                    let mut scale_x = Vec::new();
-                    let mut scale_y = Vec::new();
-                    let mut scale_z = Vec::new();
-
-                    for (i, t) in values.iter().enumerate() {
-                        translation_x.push([i as f64, t.translation.x as f64]);
-                        translation_y.push([i as f64, t.translation.y as f64]);
-                        translation_z.push([i as f64, t.translation.z as f64]);
-
-                        rotation_x.push([i as f64, t.rotation.x as f64]);
-                        rotation_y.push([i as f64, t.rotation.y as f64]);
-                        rotation_z.push([i as f64, t.rotation.z as f64]);
-                        rotation_w.push([i as f64, t.rotation.w as f64]);
-
-                        scale_x.push([i as f64, t.scale.x as f64]);
-                        scale_y.push([i as f64, t.scale.y as f64]);
-                        scale_z.push([i as f64, t.scale.z as f64]);
+        let components = selected_track(&mut anim.groups, state)
+            .map(|track| track_components(&track.values))
+            .unwrap_or_default();
+        let is_boolean = selected_track(&mut anim.groups, state)
+            .map(|track| matches!(track.values, TrackValues::Boolean(_)))
+            .unwrap_or_default();
+
+        let response = plot.show(ui, |plot_ui| {
+            for (name, values) in &components {
+                let points: Vec<_> = values
+                    .iter()
+                    .enumerate()
+                    .map(|(i, v)| [i as f64, *v])
+                    .collect();
+
+                if is_boolean {
+                    // Each value lasts until the next frame.
+                    let mut steps = Vec::new();
+                    for (i, [x, y]) in points.iter().enumerate() {
+                        steps.push([*x, *y]);
+                        if i < points.len() - 1 {
+                            steps.push([*x + 1.0, *y]);
+                        }
                     }
-                    shapes.push(Line::new(translation_x).name("translation.x"));
-                    shapes.push(Line::new(translation_y).name("translation.y"));
-                    shapes.push(Line::new(translation_z).name("translation.z"));
-
-                    shapes.push(Line::new(rotation_x).name("rotation.x"));
-                    shapes.push(Line::new(rotation_y).name("rotation.y"));
-                    shapes.push(Line::new(rotation_z).name("rotation.z"));
-                    shapes.push(Line::new(rotation_w).name("rotation.w"));
-
-                    shapes.push(Line::new(scale_x).name("scale.x"));
-                    shapes.push(Line::new(scale_y).name("scale.y"));
-                    shapes.push(Line::new(scale_z).name("scale.z"));
+                    plot_ui.line(Line::new(steps).name(name));
+                } else {
+                    plot_ui.line(Line::new(points.clone()).name(name));
                 }
-                TrackValues::UvTransform(values) => {
-                    let mut scale_us = Vec::new();
-                    let mut scale_vs = Vec::new();
-                    let mut rotations = Vec::new();
-                    let mut translate_us = Vec::new();
-                    let mut translate_vs = Vec::new();
+                plot_ui.points(Points::new(points).name(name).radius(3.0));
+            }
+        });
 
-                    for (i, v) in values.iter().enumerate() {
-                        scale_us.push([i as f64, v.scale_u as f64]);
-                        scale_vs.push([i as f64, v.scale_v as f64]);
+        // Drag keyframe points vertically to edit their values.
+        let response_ui = &response.response;
+        if response_ui.drag_started() {
+            state.dragged_keyframe = response_ui
+                .interact_pointer_pos()
+                .and_then(|pos| nearest_keyframe(&components, &response.transform, pos));
+        }
+        if response_ui.dragged() {
+            if let (Some((component, frame)), Some(pos)) =
+                (state.dragged_keyframe, response_ui.interact_pointer_pos())
+            {
+                let value = response.transform.value_from_position(pos).y as f32;
+                if let Some(track) = selected_track(&mut anim.groups, state) {
+                    changed |= set_track_component(&mut track.values, component, frame, value);
+                }
+            }
+        }
+        if response_ui.drag_stopped() {
+            state.dragged_keyframe = None;
+        }
+    });
 
-                        rotations.push([i as f64, v.rotation as f64]);
+    changed
+}
 
-                        translate_us.push([i as f64, v.translate_u as f64]);
-                        translate_vs.push([i as f64, v.translate_v as f64]);
-                    }
-                    shapes.push(Line::new(scale_us).name("scale_u"));
-                    shapes.push(Line::new(scale_vs).name("scale_v"));
+/// The name and per frame values for each component of the track.
+fn track_components(values: &TrackValues) -> Vec<(&'static str, Vec<f64>)> {
+    fn component<T>(values: &[T], f: impl Fn(&T) -> f32) -> Vec<f64> {
+        values.iter().map(|v| f(v) as f64).collect()
+    }
 
-                    shapes.push(Line::new(rotations).name("rotation"));
+    match values {
+        TrackValues::Transform(v) => vec![
+            ("translation.x", component(v, |t| t.translation.x)),
+            ("translation.y", component(v, |t| t.translation.y)),
+            ("translation.z", component(v, |t| t.translation.z)),
+            ("rotation.x", component(v, |t| t.rotation.x)),
+            ("rotation.y", component(v, |t| t.rotation.y)),
+            ("rotation.z", component(v, |t| t.rotation.z)),
+            ("rotation.w", component(v, |t| t.rotation.w)),
+            ("scale.x", component(v, |t| t.scale.x)),
+            ("scale.y", component(v, |t| t.scale.y)),
+            ("scale.z", component(v, |t| t.scale.z)),
+        ],
+        TrackValues::UvTransform(v) => vec![
+            ("scale_u", component(v, |t| t.scale_u)),
+            ("scale_v", component(v, |t| t.scale_v)),
+            ("rotation", component(v, |t| t.rotation)),
+            ("translate_u", component(v, |t| t.translate_u)),
+            ("translate_v", component(v, |t| t.translate_v)),
+        ],
+        TrackValues::Float(v) => vec![("value", component(v, |v| *v))],
+        TrackValues::PatternIndex(v) => vec![("value", component(v, |v| *v as f32))],
+        TrackValues::Boolean(v) => vec![("value", component(v, |v| if *v { 1.0 } else { 0.0 }))],
+        TrackValues::Vector4(v) => vec![
+            ("x", component(v, |v| v.x)),
+            ("y", component(v, |v| v.y)),
+            ("z", component(v, |v| v.z)),
+            ("w", component(v, |v| v.w)),
+        ],
+    }
+}
 
-                    shapes.push(Line::new(translate_us).name("translate_u"));
-                    shapes.push(Line::new(translate_vs).name("translate_v"));
-                }
-                TrackValues::Float(values) => {
-                    let mut points = Vec::new();
-                    for (i, v) in values.iter().enumerate() {
-                        points.push([i as f64, *v as f64]);
-                    }
-                    shapes.push(Line::new(points).name("value"));
-                }
-                TrackValues::PatternIndex(values) => {
-                    let mut points = Vec::new();
-                    for (i, v) in values.iter().enumerate() {
-                        points.push([i as f64, *v as f64]);
-                    }
-                    shapes.push(Line::new(points).name("value"));
-                }
-                TrackValues::Boolean(values) => {
-                    let mut points = Vec::new();
-                    for (i, v) in values.iter().enumerate() {
-                        points.push([i as f64, if *v { 1.0 } else { 0.0 }]);
-                        // Each value lasts until the next frame.
-                        if i < values.len() - 1 {
-                            points.push([(i + 1) as f64, if *v { 1.0 } else { 0.0 }]);
-                        }
-                    }
-                    shapes.push(Line::new(points).name("value"));
-                }
-                TrackValues::Vector4(values) => {
-                    let mut xs = Vec::new();
-                    let mut ys = Vec::new();
-                    let mut zs = Vec::new();
-                    let mut ws = Vec::new();
-
-                    for (i, v) in values.iter().enumerate() {
-                        xs.push([i as f64, v.x as f64]);
-                        ys.push([i as f64, v.y as f64]);
-                        zs.push([i as f64, v.z as f64]);
-                        ws.push([i as f64, v.w as f64]);
-                    }
-                    shapes.push(Line::new(xs).name("x"));
-                    shapes.push(Line::new(ys).name("y"));
-                    shapes.push(Line::new(zs).name("z"));
-                    shapes.push(Line::new(ws).name("w"));
-                }
-            }
+/// Sets the value for the component at `index` from [track_components].
+fn set_track_component(values: &mut TrackValues, index: usize, frame: usize, value: f32) -> bool {
+    let component = match values {
+        TrackValues::Transform(v) => v.get_mut(frame).and_then(|t| match index {
+            0 => Some(&mut t.translation.x),
+            1 => Some(&mut t.translation.y),
+            2 => Some(&mut t.translation.z),
+            3 => Some(&mut t.rotation.x),
+            4 => Some(&mut t.rotation.y),
+            5 => Some(&mut t.rotation.z),
+            6 => Some(&mut t.rotation.w),
+            7 => Some(&mut t.scale.x),
+            8 => Some(&mut t.scale.y),
+            9 => Some(&mut t.scale.z),
+            _ => None,
+        }),
+        TrackValues::UvTransform(v) => v.get_mut(frame).and_then(|t| match index {
+            0 => Some(&mut t.scale_u),
+            1 => Some(&mut t.scale_v),
+            2 => Some(&mut t.rotation),
+            3 => Some(&mut t.translate_u),
+            4 => Some(&mut t.translate_v),
+            _ => None,
+        }),
+        TrackValues::Float(v) => v.get_mut(frame),
+        TrackValues::Vector4(v) => v.get_mut(frame).and_then(|v| match index {
+            0 => Some(&mut v.x),
+            1 => Some(&mut v.y),
+            2 => Some(&mut v.z),
+            3 => Some(&mut v.w),
+            _ => None,
+        }),
+        TrackValues::PatternIndex(v) => {
+            return v
+                .get_mut(frame)
+                .map(|v| *v = value.round().max(0.0) as u32)
+                .is_some();
         }
+        TrackValues::Boolean(v) => {
+            return v.get_mut(frame).map(|v| *v = value >= 0.5).is_some();
+        }
+    };
 
-        plot.show(ui, |plot_ui| {
-            for shape in shapes {
-                plot_ui.line(shape);
-            }
-        });
-    });
+    component.map(|c| *c = value).is_some()
+}
 
-    // The graph view is readonly.
-    false
+/// Find the (component, frame) of the keyframe point within a few points of `pos`.
+fn nearest_keyframe(
+    components: &[(&'static str, Vec<f64>)],
+    transform: &PlotTransform,
+    pos: egui::Pos2,
+) -> Option<(usize, usize)> {
+    components
+        .iter()
+        .enumerate()
+        .flat_map(|(c, (_, values))| {
+            values.iter().enumerate().map(move |(f, v)| {
+                let point = transform.position_from_point(&PlotPoint::new(f as f64, *v));
+                ((c, f), point.distance(pos))
+            })
+        })
+        .filter(|(_, distance)| *distance <= 8.0)
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(index, _)| index)
 }
 
-fn select_track_panel(ui: &mut egui::Ui, anim: &mut AnimData, state: &mut AnimEditorState) {
+fn select_track_panel(ui: &mut egui::Ui, anim: &mut AnimData, state: &mut AnimEditorState) -> bool {
+    let mut paste = None;
+    let node_filter = state.node_filter.clone();
+    let hidden_types = state.hidden_group_types.clone();
+
     SidePanel::left("anim_left_panel")
         .default_width(300.0)
         .show_inside(ui, |ui| {
             ScrollArea::vertical()
                 .auto_shrink([false; 2])
                 .show(ui, |ui| {
-                    for (g, group) in anim.groups.iter().enumerate() {
+                    for (g, group) in anim.groups.iter().enumerate().filter(|(_, group)| {
+                        group_matches_filter(group, &node_filter, &hidden_types)
+                    }) {
                         CollapsingHeader::new(group.group_type.to_string())
-                            .default_open(false)
+                            .open((!node_filter.is_empty()).then_some(true))
                             .show(ui, |ui| {
-                                for (n, node) in &mut group.nodes.iter().enumerate() {
+                                for (n, node) in
+                                    group.nodes.iter().enumerate().filter(|(_, node)| {
+                                        node_matches_filter(&node.name, &node_filter)
+                                    })
+                                {
                                     match &node.tracks[..] {
                                         [t] => {
                                             // Single tracks just use the group type as the name.
@@ -291,7 +829,16 @@ fn select_track_panel(ui: &mut egui::Ui, anim: &mut AnimData, state: &mut AnimEd
                                                     node.name,
                                                     t.values.len()
                                                 ),
-                                            );
+                                            )
+                                            .context_menu(|ui| {
+                                                track_context_menu(
+                                                    ui,
+                                                    t,
+                                                    (g, n, 0),
+                                                    &mut state.track_clipboard,
+                                                    &mut paste,
+                                                )
+                                            });
                                             if selected {
                                                 state.selected_group_index = Some(g);
                                                 state.selected_node_index = Some(n);
@@ -317,7 +864,16 @@ fn select_track_panel(ui: &mut egui::Ui, anim: &mut AnimData, state: &mut AnimEd
                                                                 track.name,
                                                                 track.values.len()
                                                             ),
-                                                        );
+                                                        )
+                                                        .context_menu(|ui| {
+                                                            track_context_menu(
+                                                                ui,
+                                                                track,
+                                                                (g, n, t),
+                                                                &mut state.track_clipboard,
+                                                                &mut paste,
+                                                            )
+                                                        });
                                                         if selected {
                                                             state.selected_group_index = Some(g);
                                                             state.selected_node_index = Some(n);
@@ -332,6 +888,102 @@ fn select_track_panel(ui: &mut egui::Ui, anim: &mut AnimData, state: &mut AnimEd
                     }
                 });
         });
+
+    if let (Some(paste), Some(clipboard)) = (paste, state.track_clipboard.as_ref()) {
+        paste_track(anim, paste, clipboard)
+    } else {
+        false
+    }
+}
+
+/// The destination for pasting the copied track.
+#[derive(Clone, Copy)]
+enum TrackPaste {
+    Track(usize, usize, usize),
+    Group(usize),
+}
+
+fn track_context_menu(
+    ui: &mut egui::Ui,
+    track: &TrackData,
+    (g, n, t): (usize, usize, usize),
+    clipboard: &mut Option<TrackData>,
+    paste: &mut Option<TrackPaste>,
+) {
+    if ui.button("Copy Track").clicked() {
+        ui.close_menu();
+        *clipboard = Some(track.clone());
+    }
+
+    let can_paste = clipboard
+        .as_ref()
+        .map(|c| same_track_type(&c.values, &track.values))
+        .unwrap_or_default();
+    if ui
+        .add_enabled(can_paste, Button::new("Paste Track"))
+        .on_disabled_hover_text("Copy a track with the same type to enable pasting.")
+        .clicked()
+    {
+        ui.close_menu();
+        *paste = Some(TrackPaste::Track(g, n, t));
+    }
+    if ui
+        .add_enabled(
+            clipboard.is_some(),
+            Button::new("Paste to All Nodes in Group"),
+        )
+        .on_hover_text("Paste to the first track with the same type for each node in the group.")
+        .on_disabled_hover_text("Copy a track to enable pasting.")
+        .clicked()
+    {
+        ui.close_menu();
+        *paste = Some(TrackPaste::Group(g));
+    }
+}
+
+/// Replaces the values and flags of the destination tracks with `clipboard`.
+/// Track names are preserved since they identify the animated parameter.
+fn paste_track(anim: &mut AnimData, paste: TrackPaste, clipboard: &TrackData) -> bool {
+    let paste_values = |track: &mut TrackData| {
+        track.values = clipboard.values.clone();
+        track.compensate_scale = clipboard.compensate_scale;
+        track.transform_flags = clipboard.transform_flags;
+    };
+
+    let mut changed = false;
+    match paste {
+        TrackPaste::Track(g, n, t) => {
+            if let Some(track) = anim
+                .groups
+                .get_mut(g)
+                .and_then(|group| group.nodes.get_mut(n))
+                .and_then(|node| node.tracks.get_mut(t))
+                .filter(|track| same_track_type(&track.values, &clipboard.values))
+            {
+                paste_values(track);
+                changed = true;
+            }
+        }
+        TrackPaste::Group(g) => {
+            if let Some(group) = anim.groups.get_mut(g) {
+                for node in &mut group.nodes {
+                    if let Some(track) = node
+                        .tracks
+                        .iter_mut()
+                        .find(|track| same_track_type(&track.values, &clipboard.values))
+                    {
+                        paste_values(track);
+                        changed = true;
+                    }
+                }
+            }
+        }
+    }
+    changed
+}
+
+fn same_track_type(a: &TrackValues, b: &TrackValues) -> bool {
+    std::mem::discriminant(a) == std::mem::discriminant(b)
 }
 
 fn selected_track<'a>(
@@ -386,12 +1038,29 @@ fn edit_track(ui: &mut egui::Ui, track: &mut ssbh_data::anim_data::TrackData) ->
 }
 
 fn list_view(ui: &mut egui::Ui, anim: &mut AnimData, state: &mut AnimEditorState) -> bool {
-    select_track_panel(ui, anim, state);
-
-    let mut changed = false;
+    let mut changed = select_track_panel(ui, anim, state);
 
+    let final_frame_index = anim.final_frame_index.max(0.0) as usize;
     CentralPanel::default().show_inside(ui, |ui| {
         if let Some(track) = selected_track(&mut anim.groups, state) {
+            ui.horizontal(|ui| {
+                ui.label("Frame");
+                ui.add(DragValue::new(&mut state.keyframe_to_add).range(0..=final_frame_index));
+
+                // Tracks already store a value for every frame up to their length.
+                let frame = state.keyframe_to_add.min(final_frame_index);
+                if ui
+                    .add_enabled(frame >= track.values.len(), Button::new("Add Keyframe"))
+                    .on_hover_text("Extend the track to this frame using the final value.")
+                    .on_disabled_hover_text(
+                        "The track already has a keyframe for this frame. Edit the value below.",
+                    )
+                    .clicked()
+                {
+                    changed |= add_keyframe(track, frame);
+                }
+            });
+
             ScrollArea::vertical()
                 .auto_shrink([false; 2])
                 .show(ui, |ui| {
@@ -405,8 +1074,11 @@ fn list_view(ui: &mut egui::Ui, anim: &mut AnimData, state: &mut AnimEditorState
 
 fn track_value_grid(ui: &mut egui::Ui, track: &mut TrackData) -> bool {
     let mut changed = false;
+    let can_delete = track.values.len() > 1;
+    let mut frame_to_delete = None;
 
-    Grid::new("anim_grid")
+    // Use the parent id since the hierarchy view shows a grid for each track.
+    Grid::new(ui.id().with("anim_grid"))
         .striped(true)
         .show(ui, |ui| match &mut track.values {
             TrackValues::Transform(values) => {
@@ -414,21 +1086,25 @@ fn track_value_grid(ui: &mut egui::Ui, track: &mut TrackData) -> bool {
                 ui.heading("scale.x");
                 ui.heading("scale.y");
                 ui.heading("scale.z");
+                ui.label("");
                 ui.heading("rotation.x");
                 ui.heading("rotation.y");
                 ui.heading("rotation.z");
                 ui.heading("rotation.w");
+                ui.label("");
                 ui.heading("translation.x");
                 ui.heading("translation.y");
                 ui.heading("translation.z");
+                ui.label("");
                 ui.end_row();
 
                 for (i, v) in values.iter_mut().enumerate() {
-                    ui.label(i.to_string());
+                    keyframe_label(ui, i, can_delete, &mut frame_to_delete);
 
                     changed |= ui.add(DragValue::new(&mut v.scale.x).speed(0.1)).changed();
                     changed |= ui.add(DragValue::new(&mut v.scale.y).speed(0.1)).changed();
                     changed |= ui.add(DragValue::new(&mut v.scale.z).speed(0.1)).changed();
+                    changed |= vector3_clipboard_buttons(ui, &mut v.scale);
 
                     changed |= ui
                         .add(DragValue::new(&mut v.rotation.x).speed(0.1))
@@ -442,6 +1118,7 @@ fn track_value_grid(ui: &mut egui::Ui, track: &mut TrackData) -> bool {
                     changed |= ui
                         .add(DragValue::new(&mut v.rotation.w).speed(0.1))
                         .changed();
+                    changed |= vector4_clipboard_buttons(ui, &mut v.rotation);
 
                     changed |= ui
                         .add(DragValue::new(&mut v.translation.x).speed(0.1))
@@ -452,6 +1129,7 @@ fn track_value_grid(ui: &mut egui::Ui, track: &mut TrackData) -> bool {
                     changed |= ui
                         .add(DragValue::new(&mut v.translation.z).speed(0.1))
                         .changed();
+                    changed |= vector3_clipboard_buttons(ui, &mut v.translation);
 
                     ui.end_row();
                 }
@@ -466,7 +1144,7 @@ fn track_value_grid(ui: &mut egui::Ui, track: &mut TrackData) -> bool {
                 ui.end_row();
 
                 for (i, v) in values.iter_mut().enumerate() {
-                    ui.label(i.to_string());
+                    keyframe_label(ui, i, can_delete, &mut frame_to_delete);
 
                     changed |= ui.add(DragValue::new(&mut v.scale_u).speed(0.1)).changed();
                     changed |= ui.add(DragValue::new(&mut v.scale_v).speed(0.1)).changed();
@@ -489,7 +1167,7 @@ fn track_value_grid(ui: &mut egui::Ui, track: &mut TrackData) -> bool {
                 ui.end_row();
 
                 for (i, v) in values.iter_mut().enumerate() {
-                    ui.label(i.to_string());
+                    keyframe_label(ui, i, can_delete, &mut frame_to_delete);
 
                     changed |= ui.add(DragValue::new(v)).changed();
                     ui.end_row();
@@ -501,7 +1179,7 @@ fn track_value_grid(ui: &mut egui::Ui, track: &mut TrackData) -> bool {
                 ui.end_row();
 
                 for (i, v) in values.iter_mut().enumerate() {
-                    ui.label(i.to_string());
+                    keyframe_label(ui, i, can_delete, &mut frame_to_delete);
 
                     changed |= ui.add(DragValue::new(v)).changed();
                     ui.end_row();
@@ -513,7 +1191,7 @@ fn track_value_grid(ui: &mut egui::Ui, track: &mut TrackData) -> bool {
                 ui.end_row();
 
                 for (i, v) in values.iter_mut().enumerate() {
-                    ui.label(i.to_string());
+                    keyframe_label(ui, i, can_delete, &mut frame_to_delete);
 
                     changed |= ui.checkbox(v, "").changed();
                     ui.end_row();
@@ -525,20 +1203,826 @@ fn track_value_grid(ui: &mut egui::Ui, track: &mut TrackData) -> bool {
                 ui.heading("y");
                 ui.heading("z");
                 ui.heading("w");
-
+                ui.label("");
                 ui.end_row();
 
                 for (i, v) in values.iter_mut().enumerate() {
-                    ui.label(i.to_string());
+                    keyframe_label(ui, i, can_delete, &mut frame_to_delete);
 
                     changed |= ui.add(DragValue::new(&mut v.x).speed(0.1)).changed();
                     changed |= ui.add(DragValue::new(&mut v.y).speed(0.1)).changed();
                     changed |= ui.add(DragValue::new(&mut v.z).speed(0.1)).changed();
                     changed |= ui.add(DragValue::new(&mut v.w).speed(0.1)).changed();
+                    changed |= vector4_clipboard_buttons(ui, v);
                     ui.end_row();
                 }
             }
         });
 
+    if let Some(frame) = frame_to_delete {
+        delete_keyframe(track, frame);
+        changed = true;
+    }
+
+    changed
+}
+
+fn keyframe_label(
+    ui: &mut egui::Ui,
+    frame: usize,
+    can_delete: bool,
+    frame_to_delete: &mut Option<usize>,
+) {
+    ui.horizontal(|ui| {
+        ui.label(frame.to_string());
+        if ui
+            .add_enabled(can_delete, Button::new("🗑").small())
+            .on_hover_text("Delete Keyframe")
+            .on_disabled_hover_text("Tracks must have at least one keyframe.")
+            .clicked()
+        {
+            *frame_to_delete = Some(frame);
+        }
+    });
+}
+
+fn vector3_clipboard_buttons(ui: &mut egui::Ui, value: &mut Vector3) -> bool {
+    let mut values = value.to_array();
+    let changed = ui.horizontal(|ui| clipboard_buttons(ui, &mut values)).inner;
+    if changed {
+        *value = values.into();
+    }
+    changed
+}
+
+fn vector4_clipboard_buttons(ui: &mut egui::Ui, value: &mut Vector4) -> bool {
+    let mut values = value.to_array();
+    let changed = ui.horizontal(|ui| clipboard_buttons(ui, &mut values)).inner;
+    if changed {
+        *value = values.into();
+    }
+    changed
+}
+
+/// Extends the track to `frame` by repeating the final value.
+/// Tracks store a value for each frame, so frames already in the track are unchanged.
+/// Returns `true` if the track was modified.
+fn add_keyframe(track: &mut TrackData, frame: usize) -> bool {
+    fn extend<T: Clone>(values: &mut Vec<T>, frame: usize) -> bool {
+        match values.last() {
+            Some(last) if frame >= values.len() => {
+                let last = last.clone();
+                values.resize(frame + 1, last);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    match &mut track.values {
+        TrackValues::Transform(v) => extend(v, frame),
+        TrackValues::UvTransform(v) => extend(v, frame),
+        TrackValues::Float(v) => extend(v, frame),
+        TrackValues::PatternIndex(v) => extend(v, frame),
+        TrackValues::Boolean(v) => extend(v, frame),
+        TrackValues::Vector4(v) => extend(v, frame),
+    }
+}
+
+/// Replaces the value at `frame` with the interpolated value of its neighboring frames.
+/// Removing values would shift the timing of later frames,
+/// so only the final frame is removed unless it is the only value in the track.
+fn delete_keyframe(track: &mut TrackData, frame: usize) {
+    fn neighbors<T: Clone>(values: &[T], frame: usize) -> Vec<T> {
+        let previous = if frame == 0 { frame + 1 } else { frame - 1 };
+        vec![values[previous].clone(), values[frame + 1].clone()]
+    }
+
+    fn replace<T>(values: &mut [T], sampled: Vec<T>, frame: usize) {
+        if let Some(value) = sampled.into_iter().next() {
+            values[frame] = value;
+        }
+    }
+
+    let len = track.values.len();
+    if len <= 1 || frame >= len {
+        return;
+    }
+
+    if frame == len - 1 {
+        match &mut track.values {
+            TrackValues::Transform(v) => {
+                v.pop();
+            }
+            TrackValues::UvTransform(v) => {
+                v.pop();
+            }
+            TrackValues::Float(v) => {
+                v.pop();
+            }
+            TrackValues::PatternIndex(v) => {
+                v.pop();
+            }
+            TrackValues::Boolean(v) => {
+                v.pop();
+            }
+            TrackValues::Vector4(v) => {
+                v.pop();
+            }
+        }
+        return;
+    }
+
+    let neighbors = match &track.values {
+        TrackValues::Transform(v) => TrackValues::Transform(neighbors(v, frame)),
+        TrackValues::UvTransform(v) => TrackValues::UvTransform(neighbors(v, frame)),
+        TrackValues::Float(v) => TrackValues::Float(neighbors(v, frame)),
+        TrackValues::PatternIndex(v) => TrackValues::PatternIndex(neighbors(v, frame)),
+        TrackValues::Boolean(v) => TrackValues::Boolean(neighbors(v, frame)),
+        TrackValues::Vector4(v) => TrackValues::Vector4(neighbors(v, frame)),
+    };
+
+    match (&mut track.values, sample_track(&neighbors, 0.5)) {
+        (TrackValues::Transform(v), TrackValues::Transform(s)) => replace(v, s, frame),
+        (TrackValues::UvTransform(v), TrackValues::UvTransform(s)) => replace(v, s, frame),
+        (TrackValues::Float(v), TrackValues::Float(s)) => replace(v, s, frame),
+        (TrackValues::PatternIndex(v), TrackValues::PatternIndex(s)) => replace(v, s, frame),
+        (TrackValues::Boolean(v), TrackValues::Boolean(s)) => replace(v, s, frame),
+        (TrackValues::Vector4(v), TrackValues::Vector4(s)) => replace(v, s, frame),
+        _ => (),
+    }
+}
+
+/// Replaces the transform for `bone_name` at `frame` with the decomposed `transform` matrix.
+/// Constant tracks are expanded to a value for each frame to preserve the other frames.
+/// Returns `true` if a track was modified.
+pub fn set_bone_transform_keyframe(
+    anim: &mut AnimData,
+    bone_name: &str,
+    frame: usize,
+    transform: [[f32; 4]; 4],
+) -> bool {
+    let (s, r, t) = glam::Mat4::from_cols_array_2d(&transform).to_scale_rotation_translation();
+    let new_value = Transform {
+        scale: s.to_array().into(),
+        rotation: r.to_array().into(),
+        translation: t.to_array().into(),
+    };
+
+    let frame_count = anim.final_frame_index as usize + 1;
+
+    let mut changed = false;
+    for node in anim
+        .groups
+        .iter_mut()
+        .filter(|g| g.group_type == GroupType::Transform)
+        .flat_map(|g| g.nodes.iter_mut())
+        .filter(|n| n.name == bone_name)
+    {
+        for track in &mut node.tracks {
+            if let TrackValues::Transform(values) = &mut track.values {
+                if values.len() == 1 && frame_count > 1 {
+                    *values = vec![values[0]; frame_count];
+                }
+
+                if let Some(value) = values.get_mut(frame) {
+                    *value = new_value;
+                    changed = true;
+                }
+            }
+        }
+    }
+
     changed
 }
+
+/// The local transform for `bone_name` in `anim` at `frame` if `anim` has a track for the bone.
+/// This ignores scale compensation and inheritance.
+pub fn sample_bone_transform(anim: &AnimData, bone_name: &str, frame: f32) -> Option<glam::Mat4> {
+    anim.groups
+        .iter()
+        .filter(|g| g.group_type == GroupType::Transform)
+        .flat_map(|g| g.nodes.iter())
+        .filter(|n| n.name == bone_name)
+        .flat_map(|n| n.tracks.iter())
+        .filter(|t| matches!(t.values, TrackValues::Transform(_)))
+        .find_map(|t| match sample_track(&t.values, frame) {
+            TrackValues::Transform(values) => values.first().map(|v| {
+                glam::Mat4::from_scale_rotation_translation(
+                    glam::Vec3::from_array(v.scale.to_array()),
+                    glam::Quat::from_array(v.rotation.to_array()),
+                    glam::Vec3::from_array(v.translation.to_array()),
+                )
+            }),
+            _ => None,
+        })
+}
+
+/// The length of `anim` in seconds assuming the game's fixed rate of 60 frames per second.
+pub fn animation_duration_seconds(anim: &AnimData) -> f32 {
+    anim.final_frame_index / 60.0
+}
+
+/// The group, node, and track index for each material track in `anim`.
+/// Tracks are keyed by material label and parameter name.
+pub fn material_track_indices(anim: &AnimData) -> Vec<((String, String), [usize; 3])> {
+    anim.groups
+        .iter()
+        .enumerate()
+        .filter(|(_, g)| g.group_type == GroupType::Material)
+        .flat_map(|(g, group)| {
+            group.nodes.iter().enumerate().flat_map(move |(n, node)| {
+                node.tracks
+                    .iter()
+                    .enumerate()
+                    .map(move |(t, track)| ((node.name.clone(), track.name.clone()), [g, n, t]))
+            })
+        })
+        .collect()
+}
+
+/// The values for each frame of the float and vector material tracks in `anim`.
+/// Curves are keyed by material label and parameter name with one curve for each component.
+pub fn material_param_curves(anim: &AnimData) -> Vec<((String, String), Vec<Vec<f32>>)> {
+    anim.groups
+        .iter()
+        .filter(|g| g.group_type == GroupType::Material)
+        .flat_map(|g| &g.nodes)
+        .flat_map(|node| {
+            node.tracks.iter().filter_map(|track| {
+                let curves = match &track.values {
+                    TrackValues::Float(values) => vec![values.clone()],
+                    TrackValues::Vector4(values) => (0..4)
+                        .map(|i| values.iter().map(|v| v.to_array()[i]).collect())
+                        .collect(),
+                    _ => return None,
+                };
+                Some(((node.name.clone(), track.name.clone()), curves))
+            })
+        })
+        .collect()
+}
+
+/// Creates an animation with constant tracks using the values of `anim` at `frame`.
+/// Frames past the end of the animation use the values for the final frame.
+pub fn sample_anim(anim: &AnimData, frame: f32) -> AnimData {
+    // Only copy the sampled values since cloning every frame is expensive for long animations.
+    AnimData {
+        major_version: anim.major_version,
+        minor_version: anim.minor_version,
+        final_frame_index: 0.0,
+        groups: anim
+            .groups
+            .iter()
+            .map(|group| GroupData {
+                group_type: group.group_type,
+                nodes: group
+                    .nodes
+                    .iter()
+                    .map(|node| NodeData {
+                        name: node.name.clone(),
+                        tracks: node
+                            .tracks
+                            .iter()
+                            .map(|track| TrackData {
+                                name: track.name.clone(),
+                                compensate_scale: track.compensate_scale,
+                                transform_flags: track.transform_flags,
+                                values: sample_track(&track.values, frame),
+                            })
+                            .collect(),
+                    })
+                    .collect(),
+            })
+            .collect(),
+    }
+}
+
+fn sample_track(values: &TrackValues, frame: f32) -> TrackValues {
+    // Interpolate between the nearest frames.
+    let frame = frame.max(0.0);
+    let sample = |len: usize| {
+        let last = len.saturating_sub(1);
+        let current = (frame.floor() as usize).min(last);
+        let next = (current + 1).min(last);
+        (current, next, frame.fract())
+    };
+
+    match values {
+        TrackValues::Transform(v) => {
+            let (current, next, t) = sample(v.len());
+            TrackValues::Transform(
+                v.get(current)
+                    .zip(v.get(next))
+                    .map(|(a, b)| vec![lerp_transform(a, b, t)])
+                    .unwrap_or_default(),
+            )
+        }
+        TrackValues::UvTransform(v) => {
+            let (current, next, t) = sample(v.len());
+            TrackValues::UvTransform(
+                v.get(current)
+                    .zip(v.get(next))
+                    .map(|(a, b)| {
+                        vec![UvTransform {
+                            scale_u: lerp(a.scale_u, b.scale_u, t),
+                            scale_v: lerp(a.scale_v, b.scale_v, t),
+                            rotation: lerp(a.rotation, b.rotation, t),
+                            translate_u: lerp(a.translate_u, b.translate_u, t),
+                            translate_v: lerp(a.translate_v, b.translate_v, t),
+                        }]
+                    })
+                    .unwrap_or_default(),
+            )
+        }
+        TrackValues::Float(v) => {
+            let (current, next, t) = sample(v.len());
+            TrackValues::Float(
+                v.get(current)
+                    .zip(v.get(next))
+                    .map(|(a, b)| vec![lerp(*a, *b, t)])
+                    .unwrap_or_default(),
+            )
+        }
+        TrackValues::Vector4(v) => {
+            let (current, next, t) = sample(v.len());
+            TrackValues::Vector4(
+                v.get(current)
+                    .zip(v.get(next))
+                    .map(|(a, b)| {
+                        let a = glam::Vec4::from_array(a.to_array());
+                        let b = glam::Vec4::from_array(b.to_array());
+                        vec![a.lerp(b, t).to_array().into()]
+                    })
+                    .unwrap_or_default(),
+            )
+        }
+        // These values can't be interpolated.
+        TrackValues::PatternIndex(v) => {
+            let (current, _, _) = sample(v.len());
+            TrackValues::PatternIndex(v.get(current).map(|v| vec![*v]).unwrap_or_default())
+        }
+        TrackValues::Boolean(v) => {
+            let (current, _, _) = sample(v.len());
+            TrackValues::Boolean(v.get(current).map(|v| vec![*v]).unwrap_or_default())
+        }
+    }
+}
+
+fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + (b - a) * t
+}
+
+fn lerp_transform(a: &Transform, b: &Transform, t: f32) -> Transform {
+    let rotation_a = glam::Quat::from_array(a.rotation.to_array());
+    let rotation_b = glam::Quat::from_array(b.rotation.to_array());
+    Transform {
+        scale: glam::Vec3::from_array(a.scale.to_array())
+            .lerp(glam::Vec3::from_array(b.scale.to_array()), t)
+            .to_array()
+            .into(),
+        rotation: rotation_a.slerp(rotation_b, t).to_array().into(),
+        translation: glam::Vec3::from_array(a.translation.to_array())
+            .lerp(glam::Vec3::from_array(b.translation.to_array()), t)
+            .to_array()
+            .into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn float_track(values: Vec<f32>) -> TrackData {
+        TrackData {
+            name: "CustomFloat0".to_owned(),
+            compensate_scale: false,
+            transform_flags: Default::default(),
+            values: TrackValues::Float(values),
+        }
+    }
+
+    fn anim(group_type: GroupType, nodes: Vec<(&str, Vec<TrackData>)>) -> AnimData {
+        AnimData {
+            major_version: 2,
+            minor_version: 0,
+            final_frame_index: 0.0,
+            groups: vec![GroupData {
+                group_type,
+                nodes: nodes
+                    .into_iter()
+                    .map(|(name, tracks)| NodeData {
+                        name: name.to_owned(),
+                        tracks,
+                    })
+                    .collect(),
+            }],
+        }
+    }
+
+    #[test]
+    fn group_matches_filter_node_name() {
+        let anim = anim(
+            GroupType::Transform,
+            vec![("ArmL", Vec::new()), ("ArmR", Vec::new())],
+        );
+        assert!(group_matches_filter(&anim.groups[0], "", &[]));
+        assert!(group_matches_filter(&anim.groups[0], "arml", &[]));
+        assert!(!group_matches_filter(&anim.groups[0], "Leg", &[]));
+    }
+
+    #[test]
+    fn group_matches_filter_hidden_type() {
+        // Name and type filters both need to match.
+        let anim = anim(GroupType::Transform, vec![("ArmL", Vec::new())]);
+        assert!(!group_matches_filter(
+            &anim.groups[0],
+            "ArmL",
+            &[GroupType::Transform]
+        ));
+        assert!(group_matches_filter(
+            &anim.groups[0],
+            "ArmL",
+            &[GroupType::Visibility]
+        ));
+    }
+
+    fn transform_track(values: Vec<Transform>) -> TrackData {
+        TrackData {
+            name: "Transform".to_owned(),
+            compensate_scale: false,
+            transform_flags: Default::default(),
+            values: TrackValues::Transform(values),
+        }
+    }
+
+    fn translation(x: f32) -> Transform {
+        Transform {
+            scale: [1.0; 3].into(),
+            rotation: [0.0, 0.0, 0.0, 1.0].into(),
+            translation: [x, 0.0, 0.0].into(),
+        }
+    }
+
+    fn translation_matrix(x: f32) -> [[f32; 4]; 4] {
+        glam::Mat4::from_translation(glam::vec3(x, 0.0, 0.0)).to_cols_array_2d()
+    }
+
+    #[test]
+    fn set_bone_transform_keyframe_single_frame() {
+        let mut anim = anim(
+            GroupType::Transform,
+            vec![(
+                "ArmL",
+                vec![transform_track(vec![translation(1.0), translation(2.0)])],
+            )],
+        );
+        anim.final_frame_index = 1.0;
+
+        assert!(set_bone_transform_keyframe(
+            &mut anim,
+            "ArmL",
+            1,
+            translation_matrix(5.0)
+        ));
+        assert_eq!(
+            TrackValues::Transform(vec![translation(1.0), translation(5.0)]),
+            anim.groups[0].nodes[0].tracks[0].values
+        );
+    }
+
+    #[test]
+    fn set_bone_transform_keyframe_expand_constant_track() {
+        let mut anim = anim(
+            GroupType::Transform,
+            vec![("ArmL", vec![transform_track(vec![translation(1.0)])])],
+        );
+        anim.final_frame_index = 2.0;
+
+        assert!(set_bone_transform_keyframe(
+            &mut anim,
+            "ArmL",
+            1,
+            translation_matrix(5.0)
+        ));
+        assert_eq!(
+            TrackValues::Transform(vec![translation(1.0), translation(5.0), translation(1.0)]),
+            anim.groups[0].nodes[0].tracks[0].values
+        );
+    }
+
+    #[test]
+    fn set_bone_transform_keyframe_missing_bone_or_frame() {
+        let mut anim = anim(
+            GroupType::Transform,
+            vec![("ArmL", vec![transform_track(vec![translation(1.0)])])],
+        );
+
+        assert!(!set_bone_transform_keyframe(
+            &mut anim,
+            "ArmR",
+            0,
+            translation_matrix(5.0)
+        ));
+        assert!(!set_bone_transform_keyframe(
+            &mut anim,
+            "ArmL",
+            3,
+            translation_matrix(5.0)
+        ));
+        assert_eq!(
+            TrackValues::Transform(vec![translation(1.0)]),
+            anim.groups[0].nodes[0].tracks[0].values
+        );
+    }
+
+    #[test]
+    fn add_keyframe_extend_track() {
+        let mut track = float_track(vec![1.0, 2.0]);
+        assert!(add_keyframe(&mut track, 4));
+        assert_eq!(
+            TrackValues::Float(vec![1.0, 2.0, 2.0, 2.0, 2.0]),
+            track.values
+        );
+    }
+
+    #[test]
+    fn add_keyframe_existing_frame() {
+        let mut track = float_track(vec![1.0, 2.0, 3.0]);
+        assert!(!add_keyframe(&mut track, 1));
+        assert_eq!(TrackValues::Float(vec![1.0, 2.0, 3.0]), track.values);
+    }
+
+    #[test]
+    fn delete_keyframe_middle() {
+        // Deleting shouldn't change the timing of later frames.
+        let mut track = float_track(vec![1.0, 5.0, 3.0, 4.0]);
+        delete_keyframe(&mut track, 1);
+        assert_eq!(TrackValues::Float(vec![1.0, 2.0, 3.0, 4.0]), track.values);
+    }
+
+    #[test]
+    fn delete_keyframe_first() {
+        let mut track = float_track(vec![1.0, 2.0, 3.0]);
+        delete_keyframe(&mut track, 0);
+        assert_eq!(TrackValues::Float(vec![2.0, 2.0, 3.0]), track.values);
+    }
+
+    #[test]
+    fn delete_keyframe_last() {
+        let mut track = float_track(vec![1.0, 2.0, 3.0]);
+        delete_keyframe(&mut track, 2);
+        assert_eq!(TrackValues::Float(vec![1.0, 2.0]), track.values);
+    }
+
+    #[test]
+    fn delete_keyframe_single_frame() {
+        let mut track = float_track(vec![1.0]);
+        delete_keyframe(&mut track, 0);
+        assert_eq!(TrackValues::Float(vec![1.0]), track.values);
+    }
+
+    #[test]
+    fn delete_keyframe_pattern_index() {
+        // Values that can't be interpolated use the previous frame.
+        let mut track = float_track(Vec::new());
+        track.values = TrackValues::PatternIndex(vec![1, 5, 3]);
+        delete_keyframe(&mut track, 1);
+        assert_eq!(TrackValues::PatternIndex(vec![1, 1, 3]), track.values);
+    }
+
+    #[test]
+    fn copy_frame_range_filtered() {
+        let anim = anim(
+            GroupType::Material,
+            vec![
+                ("A", vec![float_track(vec![0.0, 1.0, 2.0, 3.0])]),
+                ("B", vec![float_track(vec![4.0, 5.0])]),
+            ],
+        );
+        assert_eq!(
+            vec![(
+                GroupType::Material,
+                "A".to_owned(),
+                "CustomFloat0".to_owned(),
+                TrackValues::Float(vec![1.0, 2.0])
+            )],
+            copy_frame_range(&anim, 1..=2, "A", &[])
+        );
+    }
+
+    #[test]
+    fn copy_frame_range_past_end() {
+        let anim = anim(
+            GroupType::Material,
+            vec![("A", vec![float_track(vec![0.0])])],
+        );
+        assert_eq!(
+            vec![(
+                GroupType::Material,
+                "A".to_owned(),
+                "CustomFloat0".to_owned(),
+                TrackValues::Float(Vec::new())
+            )],
+            copy_frame_range(&anim, 1..=2, "", &[GroupType::Visibility])
+        );
+    }
+
+    #[test]
+    fn paste_frame_range_overwrite() {
+        let mut anim = anim(
+            GroupType::Material,
+            vec![("A", vec![float_track(vec![0.0, 1.0, 2.0])])],
+        );
+        anim.final_frame_index = 2.0;
+        let clipboard = vec![(
+            GroupType::Material,
+            "A".to_owned(),
+            "CustomFloat0".to_owned(),
+            TrackValues::Float(vec![5.0, 6.0]),
+        )];
+        assert!(paste_frame_range(&mut anim, &clipboard, 2, false));
+        assert_eq!(
+            TrackValues::Float(vec![0.0, 1.0, 5.0, 6.0]),
+            anim.groups[0].nodes[0].tracks[0].values
+        );
+        assert_eq!(3.0, anim.final_frame_index);
+    }
+
+    #[test]
+    fn paste_frame_range_after_end() {
+        // Frames between the end of the track and the target hold the last value.
+        let mut anim = anim(
+            GroupType::Material,
+            vec![("A", vec![float_track(vec![1.0])])],
+        );
+        let clipboard = vec![(
+            GroupType::Material,
+            "A".to_owned(),
+            "CustomFloat0".to_owned(),
+            TrackValues::Float(vec![5.0]),
+        )];
+        assert!(paste_frame_range(&mut anim, &clipboard, 3, false));
+        assert_eq!(
+            TrackValues::Float(vec![1.0, 1.0, 1.0, 5.0]),
+            anim.groups[0].nodes[0].tracks[0].values
+        );
+    }
+
+    #[test]
+    fn paste_frame_range_shift_all_tracks() {
+        let mut anim = anim(
+            GroupType::Material,
+            vec![
+                ("A", vec![float_track(vec![0.0, 1.0, 2.0])]),
+                ("B", vec![float_track(vec![3.0, 4.0, 5.0])]),
+                ("C", vec![float_track(vec![6.0])]),
+            ],
+        );
+        anim.final_frame_index = 2.0;
+        let clipboard = vec![(
+            GroupType::Material,
+            "A".to_owned(),
+            "CustomFloat0".to_owned(),
+            TrackValues::Float(vec![7.0, 8.0]),
+        )];
+        assert!(paste_frame_range(&mut anim, &clipboard, 1, true));
+
+        let values: Vec<_> = anim.groups[0]
+            .nodes
+            .iter()
+            .map(|n| n.tracks[0].values.clone())
+            .collect();
+        assert_eq!(
+            vec![
+                TrackValues::Float(vec![0.0, 7.0, 8.0, 1.0, 2.0]),
+                TrackValues::Float(vec![3.0, 3.0, 3.0, 4.0, 5.0]),
+                // Single frame tracks have no later frames to shift.
+                TrackValues::Float(vec![6.0]),
+            ],
+            values
+        );
+        assert_eq!(4.0, anim.final_frame_index);
+    }
+
+    #[test]
+    fn truncate_keyframes_after_final_frame() {
+        let mut anim = anim(
+            GroupType::Material,
+            vec![
+                ("A", vec![float_track(vec![0.0, 1.0, 2.0, 3.0])]),
+                ("B", vec![float_track(vec![4.0])]),
+            ],
+        );
+        anim.final_frame_index = 1.0;
+        assert_eq!(2, truncated_keyframe_count(&anim));
+
+        truncate_keyframes(&mut anim);
+        assert_eq!(
+            TrackValues::Float(vec![0.0, 1.0]),
+            anim.groups[0].nodes[0].tracks[0].values
+        );
+        assert_eq!(
+            TrackValues::Float(vec![4.0]),
+            anim.groups[0].nodes[1].tracks[0].values
+        );
+        assert_eq!(0, truncated_keyframe_count(&anim));
+    }
+
+    #[test]
+    fn retarget_bones_transform_nodes() {
+        let mut anim = anim(
+            GroupType::Transform,
+            vec![
+                ("ArmL", Vec::new()),
+                ("ArmR", Vec::new()),
+                ("Hip", Vec::new()),
+            ],
+        );
+        let mapping = vec![
+            ("ArmL".to_owned(), Some("L_Arm".to_owned())),
+            ("ArmR".to_owned(), None),
+            ("Hip".to_owned(), Some("Hip".to_owned())),
+        ];
+        assert!(retarget_bones(&mut anim, &mapping));
+
+        let names: Vec<_> = anim.groups[0].nodes.iter().map(|n| &n.name).collect();
+        assert_eq!(vec!["L_Arm", "ArmR", "Hip"], names);
+    }
+
+    #[test]
+    fn retarget_bones_ignore_other_groups() {
+        // Material and visibility nodes aren't bones.
+        let mut anim = anim(GroupType::Material, vec![("ArmL", Vec::new())]);
+        let mapping = vec![("ArmL".to_owned(), Some("L_Arm".to_owned()))];
+        assert!(!retarget_bones(&mut anim, &mapping));
+        assert_eq!("ArmL", anim.groups[0].nodes[0].name);
+    }
+
+    #[test]
+    fn paste_track_single_track() {
+        let mut anim = anim(
+            GroupType::Material,
+            vec![("A", vec![float_track(vec![0.0]), float_track(vec![1.0])])],
+        );
+        let mut clipboard = float_track(vec![2.0, 3.0]);
+        clipboard.name = "CustomFloat1".to_owned();
+        clipboard.compensate_scale = true;
+
+        assert!(paste_track(
+            &mut anim,
+            TrackPaste::Track(0, 0, 1),
+            &clipboard
+        ));
+        let tracks = &anim.groups[0].nodes[0].tracks;
+        assert_eq!(TrackValues::Float(vec![0.0]), tracks[0].values);
+        assert_eq!(TrackValues::Float(vec![2.0, 3.0]), tracks[1].values);
+        assert!(tracks[1].compensate_scale);
+        // The name identifies the animated parameter, so it isn't pasted.
+        assert_eq!("CustomFloat0", tracks[1].name);
+    }
+
+    #[test]
+    fn paste_track_different_type() {
+        let mut anim = anim(
+            GroupType::Material,
+            vec![("A", vec![float_track(vec![0.0])])],
+        );
+        let mut clipboard = float_track(Vec::new());
+        clipboard.values = TrackValues::Boolean(vec![true]);
+
+        assert!(!paste_track(
+            &mut anim,
+            TrackPaste::Track(0, 0, 0),
+            &clipboard
+        ));
+        assert_eq!(
+            TrackValues::Float(vec![0.0]),
+            anim.groups[0].nodes[0].tracks[0].values
+        );
+    }
+
+    #[test]
+    fn paste_track_group() {
+        let mut visibility = float_track(Vec::new());
+        visibility.values = TrackValues::Boolean(vec![true]);
+        let mut anim = anim(
+            GroupType::Material,
+            vec![
+                ("A", vec![visibility, float_track(vec![0.0])]),
+                ("B", vec![float_track(vec![1.0])]),
+            ],
+        );
+
+        // Paste to the first track with the same type for each node.
+        assert!(paste_track(
+            &mut anim,
+            TrackPaste::Group(0),
+            &float_track(vec![2.0])
+        ));
+        let nodes = &anim.groups[0].nodes;
+        assert_eq!(TrackValues::Boolean(vec![true]), nodes[0].tracks[0].values);
+        assert_eq!(TrackValues::Float(vec![2.0]), nodes[0].tracks[1].values);
+        assert_eq!(TrackValues::Float(vec![2.0]), nodes[1].tracks[0].values);
+    }
+}
@@ -1,12 +1,19 @@
 use std::path::Path;
 
 use crate::{
+    app::HlpbEditorState,
+    horizontal_separator_empty,
     path::folder_editor_title,
     save_file, save_file_as,
-    widgets::{bone_combo_box, DragSlider},
+    validation::validate_hlpb_against_skel,
+    widgets::{bone_combo_box, clipboard_buttons, dialog_window, tooltip_text, DragSlider},
     EditorResponse,
 };
-use egui::{special_emojis::GITHUB, CollapsingHeader, DragValue, Grid, ScrollArea, TextEdit, Ui};
+use egui::{
+    special_emojis::GITHUB, CollapsingHeader, Context, DragValue, Grid, ScrollArea, TextEdit, Ui,
+};
+use log::error;
+use rfd::FileDialog;
 
 use ssbh_data::{
     hlpb_data::{AimConstraintData, OrientConstraintData},
@@ -20,6 +27,7 @@ pub fn hlpb_editor(
     file_name: &str,
     hlpb: &mut HlpbData,
     skel: Option<&SkelData>,
+    state: &mut HlpbEditorState,
 ) -> EditorResponse {
     let mut open = true;
     let mut changed = false;
@@ -34,12 +42,48 @@ pub fn hlpb_editor(
                 ui.menu_button("File", |ui| {
                     if ui.button("Save").clicked() {
                         ui.close_menu();
-                        saved |= save_file(hlpb, folder_name, file_name);
+                        if is_valid_for_saving(hlpb, skel, state) {
+                            saved |= save_file(hlpb, folder_name, file_name);
+                        }
                     }
 
                     if ui.button("Save As...").clicked() {
                         ui.close_menu();
-                        saved |= save_file_as(hlpb, folder_name, file_name, "Hlpb", "nuhlpb");
+                        if is_valid_for_saving(hlpb, skel, state) {
+                            saved |= save_file_as(hlpb, folder_name, file_name, "Hlpb", "nuhlpb");
+                        }
+                    }
+                    ui.separator();
+
+                    if ui.button("Export to JSON...").clicked() {
+                        ui.close_menu();
+
+                        if let Some(file) = FileDialog::new()
+                            .add_filter("Hlpb JSON", &["json"])
+                            .save_file()
+                        {
+                            export_hlpb_json(hlpb, &file);
+                        }
+                    }
+
+                    if ui.button("Import Constraints from JSON...").clicked() {
+                        ui.close_menu();
+
+                        if let Some(file) = FileDialog::new()
+                            .add_filter("Hlpb JSON", &["json"])
+                            .pick_file()
+                        {
+                            // Replacing all constraints can't be undone, so confirm first.
+                            match std::fs::read(&file)
+                                .map_err(anyhow::Error::from)
+                                .and_then(|data| Ok(serde_json::from_slice(&data)?))
+                            {
+                                Ok(imported) => state.hlpb_to_import = Some(imported),
+                                Err(e) => {
+                                    error!("Failed to import constraints from {:?}: {}", file, e)
+                                }
+                            }
+                        }
                     }
                 });
 
@@ -49,27 +93,14 @@ pub fn hlpb_editor(
 
                         // Create a unique name for the new constraint.
                         // TODO: Increment the ID at the end instead (requires tests).
-                        hlpb.aim_constraints.push(AimConstraintData {
-                            name: hlpb
-                                .aim_constraints
-                                .iter()
-                                .map(|a| &a.name)
-                                .max()
-                                .map(|n| n.to_owned() + "1")
-                                .unwrap_or_else(|| "nuHelperBoneRotateAim1".to_owned()),
-                            aim_bone_name1: String::new(),
-                            aim_bone_name2: String::new(),
-                            aim_type1: "DEFAULT".to_owned(),
-                            aim_type2: "DEFAULT".to_owned(),
-                            target_bone_name1: String::new(),
-                            target_bone_name2: String::new(),
-                            unk1: 0,
-                            unk2: 1,
-                            aim: Vector3::new(1.0, 0.0, 0.0),
-                            up: Vector3::new(0.0, 1.0, 0.0),
-                            quat1: Vector4::new(0.0, 0.0, 0.0, 1.0),
-                            quat2: Vector4::new(0.0, 0.0, 0.0, 1.0),
-                        });
+                        let name = hlpb
+                            .aim_constraints
+                            .iter()
+                            .map(|a| &a.name)
+                            .max()
+                            .map(|n| n.to_owned() + "1")
+                            .unwrap_or_else(|| "nuHelperBoneRotateAim1".to_owned());
+                        hlpb.aim_constraints.push(default_aim_constraint(name));
                         changed = true;
                     }
 
@@ -78,25 +109,15 @@ pub fn hlpb_editor(
 
                         // Create a unique name for the new constraint.
                         // TODO: Increment the ID at the end instead (requires tests).
-                        hlpb.orient_constraints.push(OrientConstraintData {
-                            name: hlpb
-                                .orient_constraints
-                                .iter()
-                                .map(|o| &o.name)
-                                .max()
-                                .map(|n| n.to_owned() + "1")
-                                .unwrap_or_else(|| "nuHelperBoneRotateInterp1".to_owned()),
-                            parent_bone_name1: String::new(),
-                            parent_bone_name2: String::new(),
-                            source_bone_name: String::new(),
-                            target_bone_name: String::new(),
-                            unk_type: 1,
-                            constraint_axes: Vector3::new(1.0, 1.0, 1.0),
-                            quat1: Vector4::new(0.0, 0.0, 0.0, 1.0),
-                            quat2: Vector4::new(0.0, 0.0, 0.0, 1.0),
-                            range_min: Vector3::new(-180.0, -180.0, -180.0),
-                            range_max: Vector3::new(180.0, 180.0, 180.0),
-                        });
+                        let name = hlpb
+                            .orient_constraints
+                            .iter()
+                            .map(|o| &o.name)
+                            .max()
+                            .map(|n| n.to_owned() + "1")
+                            .unwrap_or_else(|| "nuHelperBoneRotateInterp1".to_owned());
+                        hlpb.orient_constraints
+                            .push(default_orient_constraint(name));
                         changed = true;
                     }
                 });
@@ -114,15 +135,30 @@ pub fn hlpb_editor(
             });
             ui.separator();
 
+            changed |= import_constraints_window(ctx, &mut state.hlpb_to_import, hlpb);
+            missing_bones_window(ctx, &mut state.missing_bones);
+
+            ui.horizontal(|ui| {
+                ui.label("Bone Filter");
+                ui.add(
+                    TextEdit::singleline(&mut state.hlpb_filter).hint_text("Filter by bone name"),
+                );
+                if ui.button("Clear").clicked() {
+                    state.hlpb_filter.clear();
+                }
+            });
+
+            let filter = state.hlpb_filter.to_lowercase();
+
             ScrollArea::vertical()
                 .auto_shrink([false; 2])
                 .show(ui, |ui| {
                     if !hlpb.aim_constraints.is_empty() {
-                        changed |= aim_constraints(ui, hlpb, skel);
+                        changed |= aim_constraints(ui, hlpb, skel, &filter);
                     }
 
                     if !hlpb.orient_constraints.is_empty() {
-                        changed |= orient_constraints(ui, hlpb, skel);
+                        changed |= orient_constraints(ui, hlpb, skel, &filter);
                     }
                 });
         });
@@ -135,80 +171,100 @@ pub fn hlpb_editor(
     }
 }
 
-fn orient_constraints(ui: &mut Ui, hlpb: &mut HlpbData, skel: Option<&SkelData>) -> bool {
+fn orient_constraints(
+    ui: &mut Ui,
+    hlpb: &mut HlpbData,
+    skel: Option<&SkelData>,
+    filter: &str,
+) -> bool {
     let mut changed = false;
     CollapsingHeader::new("Orient Constraints")
         .default_open(true)
         .show(ui, |ui| {
             let mut entry_to_remove = None;
+            let mut entry_to_duplicate = None;
+            let mut entry_to_convert = None;
             for (i, o) in hlpb.orient_constraints.iter_mut().enumerate() {
+                if !filter.is_empty() && !orient_matches_filter(o, filter) {
+                    continue;
+                }
+
                 let id = egui::Id::new("orient").with(i);
 
                 // Append the helper bone name to make it easier to find constraints.
                 CollapsingHeader::new(format!("{} ({})", o.name, o.target_bone_name))
                     .id_salt(id.with(&o.name))
-                    .default_open(false)
+                    // default_open only applies to headers without stored state, so force matches open.
+                    .open((!filter.is_empty()).then_some(true))
                     .show(ui, |ui| {
                         Grid::new(id).show(ui, |ui| {
-                            ui.label("Name");
+                            tooltip_text(ui, "Name", "The name of the helper bone constraint.");
                             changed |= ui
                                 .add_sized([200.0, 20.0], TextEdit::singleline(&mut o.name))
                                 .changed();
                             ui.end_row();
 
-                            ui.label("Parent 1");
+                            tooltip_text(ui, "Parent 1", "Selects the bone that defines the coordinate space of the source bone.");
                             changed |=
                                 bone_combo_box(ui, &mut o.parent_bone_name1, id.with(0), skel, &[]);
                             ui.end_row();
 
-                            ui.label("Parent 2");
+                            tooltip_text(ui, "Parent 2", "Selects the bone that defines the coordinate space of the target bone.");
                             changed |=
                                 bone_combo_box(ui, &mut o.parent_bone_name2, id.with(1), skel, &[]);
                             ui.end_row();
 
-                            ui.label("Source");
+                            tooltip_text(ui, "Source", "Selects the bone that the rotation is copied from.");
                             changed |=
                                 bone_combo_box(ui, &mut o.source_bone_name, id.with(2), skel, &[]);
                             ui.end_row();
 
-                            ui.label("Target");
+                            tooltip_text(ui, "Target", "Selects the bone that the orient constraint is applied to.");
                             changed |=
                                 bone_combo_box(ui, &mut o.target_bone_name, id.with(3), skel, &[]);
                             ui.end_row();
 
                             // TODO: Make this an enum in ssbh_data eventually.
-                            ui.label("Unk Type");
+                            // The descriptions are based on community testing and may not be exact.
+                            tooltip_text(ui, "Unk Type", "The constraint type. 1 copies only the orientation. 2 copies the position and orientation.");
+                            let selected_text = match o.unk_type {
+                                1 => UNK_TYPE1.to_owned(),
+                                2 => UNK_TYPE2.to_owned(),
+                                t => t.to_string(),
+                            };
                             egui::ComboBox::from_id_salt(id.with(4))
-                                .selected_text(o.unk_type.to_string())
+                                .selected_text(selected_text)
                                 .show_ui(ui, |ui| {
                                     changed |=
                                         ui.selectable_value(&mut o.unk_type, 0, "0").changed();
-                                    changed |=
-                                        ui.selectable_value(&mut o.unk_type, 1, "1").changed();
-                                    changed |=
-                                        ui.selectable_value(&mut o.unk_type, 2, "2").changed();
+                                    changed |= ui
+                                        .selectable_value(&mut o.unk_type, 1, UNK_TYPE1)
+                                        .changed();
+                                    changed |= ui
+                                        .selectable_value(&mut o.unk_type, 2, UNK_TYPE2)
+                                        .changed();
                                 });
                             ui.end_row();
 
-                            ui.label("Constraint Axes");
+                            tooltip_text(ui, "Constraint Axes", "The fraction of the source rotation applied to the target for each axis.");
                             changed |=
                                 edit_vector3(ui, id.with(5), &mut o.constraint_axes, 0.0, 1.0);
                             ui.end_row();
 
-                            ui.label("Quat 1");
+                            tooltip_text(ui, "Quat 1", "An additional rotation offset as a quaternion.");
                             changed |= edit_vector4(ui, id.with(6), &mut o.quat1);
                             ui.end_row();
 
-                            ui.label("Quat 2");
+                            tooltip_text(ui, "Quat 2", "An additional rotation offset as a quaternion.");
                             changed |= edit_vector4(ui, id.with(7), &mut o.quat2);
                             ui.end_row();
 
-                            ui.label("Range Min");
+                            tooltip_text(ui, "Range Min", "The minimum rotation in degrees for each axis.");
                             changed |=
                                 edit_vector3(ui, id.with(8), &mut o.range_min, -180.0, 180.0);
                             ui.end_row();
 
-                            ui.label("Range Max");
+                            tooltip_text(ui, "Range Max", "The maximum rotation in degrees for each axis.");
                             changed |=
                                 edit_vector3(ui, id.with(9), &mut o.range_max, -180.0, 180.0);
                             ui.end_row();
@@ -216,6 +272,31 @@ fn orient_constraints(ui: &mut Ui, hlpb: &mut HlpbData, skel: Option<&SkelData>)
                     })
                     .header_response
                     .context_menu(|ui| {
+                        if ui.button("Duplicate").clicked() {
+                            ui.close_menu();
+
+                            entry_to_duplicate = Some(i);
+                            changed = true;
+                        }
+
+                        if ui
+                            .button("Convert to Aim Constraint")
+                            .on_hover_text(format!(
+                                "Copies the bone names and quaternions. \
+                                These fields have no aim equivalent and are not copied: {}. \
+                                The new aim constraint uses default values instead of zeros \
+                                to keep the constraint usable: {}.",
+                                ORIENT_ONLY_FIELDS.join(", "),
+                                aim_only_defaults()
+                            ))
+                            .clicked()
+                        {
+                            ui.close_menu();
+
+                            entry_to_convert = Some(i);
+                            changed = true;
+                        }
+
                         if ui.button("Delete").clicked() {
                             ui.close_menu();
 
@@ -225,6 +306,18 @@ fn orient_constraints(ui: &mut Ui, hlpb: &mut HlpbData, skel: Option<&SkelData>)
                     });
             }
 
+            if let Some(i) = entry_to_duplicate {
+                // Insert after the original to make it easy to edit the copy.
+                let mut copy = hlpb.orient_constraints[i].clone();
+                copy.name.push_str("_copy");
+                hlpb.orient_constraints.insert(i + 1, copy);
+            }
+
+            if let Some(i) = entry_to_convert {
+                let aim = orient_to_aim(&hlpb.orient_constraints[i]);
+                hlpb.aim_constraints.push(aim);
+            }
+
             if let Some(i) = entry_to_remove {
                 hlpb.orient_constraints.remove(i);
             }
@@ -232,13 +325,24 @@ fn orient_constraints(ui: &mut Ui, hlpb: &mut HlpbData, skel: Option<&SkelData>)
     changed
 }
 
-fn aim_constraints(ui: &mut Ui, hlpb: &mut HlpbData, skel: Option<&SkelData>) -> bool {
+fn aim_constraints(
+    ui: &mut Ui,
+    hlpb: &mut HlpbData,
+    skel: Option<&SkelData>,
+    filter: &str,
+) -> bool {
     let mut changed = false;
     CollapsingHeader::new("Aim Constraints")
         .default_open(true)
         .show(ui, |ui| {
             let mut entry_to_remove = None;
+            let mut entry_to_duplicate = None;
+            let mut entry_to_convert = None;
             for (i, aim) in hlpb.aim_constraints.iter_mut().enumerate() {
+                if !filter.is_empty() && !aim_matches_filter(aim, filter) {
+                    continue;
+                }
+
                 let id = egui::Id::new("aim").with(i);
 
                 // Append the helper bone names to make it easier to find constraints.
@@ -247,41 +351,42 @@ fn aim_constraints(ui: &mut Ui, hlpb: &mut HlpbData, skel: Option<&SkelData>) ->
                     aim.name, aim.target_bone_name1, aim.target_bone_name2
                 ))
                 .id_salt(id.with(&aim.name))
-                .default_open(false)
+                // default_open only applies to headers without stored state, so force matches open.
+                .open((!filter.is_empty()).then_some(true))
                 .show(ui, |ui| {
                     egui::Grid::new(id).show(ui, |ui| {
-                        ui.label("Name");
+                        tooltip_text(ui, "Name", "The name of the helper bone constraint.");
                         changed |= ui
                             .add_sized([200.0, 20.0], TextEdit::singleline(&mut aim.name))
                             .changed();
                         ui.end_row();
 
-                        ui.label("Aim 1");
+                        tooltip_text(ui, "Aim 1", "Selects the bone that the aim direction starts from.");
                         changed |=
                             bone_combo_box(ui, &mut aim.aim_bone_name1, id.with(0), skel, &[]);
                         ui.end_row();
 
-                        ui.label("Aim 2");
+                        tooltip_text(ui, "Aim 2", "Selects the bone that the aim direction points towards.");
                         changed |=
                             bone_combo_box(ui, &mut aim.aim_bone_name2, id.with(1), skel, &[]);
                         ui.end_row();
 
-                        ui.label("Aim Type 1");
+                        tooltip_text(ui, "Aim Type 1", "Selects the bone that defines the coordinate space for Aim 1. DEFAULT uses Aim 1.");
                         changed |=
                             bone_combo_box(ui, &mut aim.aim_type1, id.with(2), skel, &["DEFAULT"]);
                         ui.end_row();
 
-                        ui.label("Aim Type 2");
+                        tooltip_text(ui, "Aim Type 2", "Selects the bone that defines the coordinate space for Aim 2. DEFAULT uses Aim 2.");
                         changed |=
                             bone_combo_box(ui, &mut aim.aim_type2, id.with(3), skel, &["DEFAULT"]);
                         ui.end_row();
 
-                        ui.label("Target 1");
+                        tooltip_text(ui, "Target 1", "Selects the bone that the aim constraint is applied to.");
                         changed |=
                             bone_combo_box(ui, &mut aim.target_bone_name1, id.with(4), skel, &[]);
                         ui.end_row();
 
-                        ui.label("Target 2");
+                        tooltip_text(ui, "Target 2", "Selects the bone that defines the rest orientation of Target 1.");
                         changed |=
                             bone_combo_box(ui, &mut aim.target_bone_name2, id.with(5), skel, &[]);
                         ui.end_row();
@@ -294,25 +399,48 @@ fn aim_constraints(ui: &mut Ui, hlpb: &mut HlpbData, skel: Option<&SkelData>) ->
                         changed |= ui.add(DragValue::new(&mut aim.unk2)).changed();
                         ui.end_row();
 
-                        ui.label("Aim");
+                        tooltip_text(ui, "Aim", "The local axis of the target bone that points along the aim direction.");
                         changed |= edit_vector3(ui, id.with(6), &mut aim.aim, 0.0, 1.0);
                         ui.end_row();
 
-                        ui.label("Up");
+                        tooltip_text(ui, "Up", "The local axis of the target bone that points up.");
                         changed |= edit_vector3(ui, id.with(7), &mut aim.up, 0.0, 1.0);
                         ui.end_row();
 
-                        ui.label("Quat 1");
+                        tooltip_text(ui, "Quat 1", "An additional rotation offset as a quaternion.");
                         changed |= edit_vector4(ui, id.with(8), &mut aim.quat1);
                         ui.end_row();
 
-                        ui.label("Quat 2");
+                        tooltip_text(ui, "Quat 2", "An additional rotation offset as a quaternion.");
                         changed |= edit_vector4(ui, id.with(9), &mut aim.quat2);
                         ui.end_row();
                     });
                 })
                 .header_response
                 .context_menu(|ui| {
+                    if ui.button("Duplicate").clicked() {
+                        ui.close_menu();
+                        entry_to_duplicate = Some(i);
+                        changed = true;
+                    }
+
+                    if ui
+                        .button("Convert to Orient Constraint")
+                        .on_hover_text(format!(
+                            "Copies the bone names and quaternions. \
+                            These fields have no orient equivalent and are not copied: {}. \
+                            The new orient constraint uses default values instead of zeros \
+                            to keep the constraint usable: {}.",
+                            AIM_ONLY_FIELDS.join(", "),
+                            orient_only_defaults()
+                        ))
+                        .clicked()
+                    {
+                        ui.close_menu();
+                        entry_to_convert = Some(i);
+                        changed = true;
+                    }
+
                     if ui.button("Delete").clicked() {
                         ui.close_menu();
                         entry_to_remove = Some(i);
@@ -321,6 +449,18 @@ fn aim_constraints(ui: &mut Ui, hlpb: &mut HlpbData, skel: Option<&SkelData>) ->
                 });
             }
 
+            if let Some(i) = entry_to_duplicate {
+                // Insert after the original to make it easy to edit the copy.
+                let mut copy = hlpb.aim_constraints[i].clone();
+                copy.name.push_str("_copy");
+                hlpb.aim_constraints.insert(i + 1, copy);
+            }
+
+            if let Some(i) = entry_to_convert {
+                let orient = aim_to_orient(&hlpb.aim_constraints[i]);
+                hlpb.orient_constraints.push(orient);
+            }
+
             if let Some(i) = entry_to_remove {
                 hlpb.aim_constraints.remove(i);
             }
@@ -328,6 +468,203 @@ fn aim_constraints(ui: &mut Ui, hlpb: &mut HlpbData, skel: Option<&SkelData>) ->
     changed
 }
 
+fn is_valid_for_saving(
+    hlpb: &HlpbData,
+    skel: Option<&SkelData>,
+    state: &mut HlpbEditorState,
+) -> bool {
+    // Constraints can't be checked without a skel.
+    let missing = skel
+        .map(|skel| validate_hlpb_against_skel(hlpb, skel))
+        .unwrap_or_default();
+    if missing.is_empty() {
+        true
+    } else {
+        state.missing_bones = Some(missing);
+        false
+    }
+}
+
+fn missing_bones_window(ctx: &Context, missing_bones: &mut Option<Vec<String>>) {
+    dialog_window(ctx, "Missing Bones", missing_bones, |ui, bones| {
+        ui.label("The file was not saved. Aim constraints reference bones missing from the skel:");
+        for bone in bones.iter() {
+            ui.colored_label(ui.visuals().error_fg_color, bone);
+        }
+        horizontal_separator_empty(ui);
+
+        ui.button("OK").clicked()
+    });
+}
+
+fn export_hlpb_json(hlpb: &HlpbData, file: &Path) {
+    match serde_json::to_string_pretty(hlpb) {
+        Ok(json) => {
+            if let Err(e) = std::fs::write(file, json) {
+                error!("Failed to save constraints to {:?}: {}", file, e);
+            }
+        }
+        Err(e) => error!("Failed to convert constraints to JSON: {}", e),
+    }
+}
+
+fn import_constraints_window(
+    ctx: &Context,
+    hlpb_to_import: &mut Option<HlpbData>,
+    hlpb: &mut HlpbData,
+) -> bool {
+    let mut changed = false;
+    dialog_window(ctx, "Import Constraints", hlpb_to_import, |ui, imported| {
+        ui.label(format!(
+            "Replace the {} aim and {} orient constraints with {} aim and {} orient constraints?",
+            hlpb.aim_constraints.len(),
+            hlpb.orient_constraints.len(),
+            imported.aim_constraints.len(),
+            imported.orient_constraints.len(),
+        ));
+        horizontal_separator_empty(ui);
+
+        ui.horizontal(|ui| {
+            if ui.button("Replace").clicked() {
+                *hlpb = imported.clone();
+                changed = true;
+                true
+            } else {
+                ui.button("Cancel").clicked()
+            }
+        })
+        .inner
+    });
+    changed
+}
+
+// The filter is assumed to already be lowercase.
+fn orient_matches_filter(o: &OrientConstraintData, filter: &str) -> bool {
+    [
+        &o.parent_bone_name1,
+        &o.parent_bone_name2,
+        &o.source_bone_name,
+        &o.target_bone_name,
+    ]
+    .iter()
+    .any(|n| n.to_lowercase().contains(filter))
+}
+
+fn aim_matches_filter(aim: &AimConstraintData, filter: &str) -> bool {
+    [
+        &aim.aim_bone_name1,
+        &aim.aim_bone_name2,
+        &aim.target_bone_name1,
+        &aim.target_bone_name2,
+    ]
+    .iter()
+    .any(|n| n.to_lowercase().contains(filter))
+}
+
+fn default_aim_constraint(name: String) -> AimConstraintData {
+    AimConstraintData {
+        name,
+        aim_bone_name1: String::new(),
+        aim_bone_name2: String::new(),
+        aim_type1: "DEFAULT".to_owned(),
+        aim_type2: "DEFAULT".to_owned(),
+        target_bone_name1: String::new(),
+        target_bone_name2: String::new(),
+        unk1: 0,
+        unk2: 1,
+        aim: Vector3::new(1.0, 0.0, 0.0),
+        up: Vector3::new(0.0, 1.0, 0.0),
+        quat1: Vector4::new(0.0, 0.0, 0.0, 1.0),
+        quat2: Vector4::new(0.0, 0.0, 0.0, 1.0),
+    }
+}
+
+fn default_orient_constraint(name: String) -> OrientConstraintData {
+    OrientConstraintData {
+        name,
+        parent_bone_name1: String::new(),
+        parent_bone_name2: String::new(),
+        source_bone_name: String::new(),
+        target_bone_name: String::new(),
+        unk_type: 1,
+        constraint_axes: Vector3::new(1.0, 1.0, 1.0),
+        quat1: Vector4::new(0.0, 0.0, 0.0, 1.0),
+        quat2: Vector4::new(0.0, 0.0, 0.0, 1.0),
+        range_min: Vector3::new(-180.0, -180.0, -180.0),
+        range_max: Vector3::new(180.0, 180.0, 180.0),
+    }
+}
+
+const UNK_TYPE1: &str = "1 – Orientation only";
+const UNK_TYPE2: &str = "2 – Position + Orientation";
+
+// The fields without an equivalent in the other constraint type.
+const AIM_ONLY_FIELDS: &[&str] = &["Aim Type 1", "Aim Type 2", "Unk1", "Unk2", "Aim", "Up"];
+const ORIENT_ONLY_FIELDS: &[&str] = &["Unk Type", "Constraint Axes", "Range Min", "Range Max"];
+
+/// The default values for [AIM_ONLY_FIELDS] used when converting orient constraints.
+fn aim_only_defaults() -> String {
+    let aim = default_aim_constraint(String::new());
+    format!(
+        "Aim Type 1 = {}, Aim Type 2 = {}, Unk1 = {}, Unk2 = {}, Aim = {}, Up = {}",
+        aim.aim_type1,
+        aim.aim_type2,
+        aim.unk1,
+        aim.unk2,
+        vector3_text(&aim.aim),
+        vector3_text(&aim.up)
+    )
+}
+
+/// The default values for [ORIENT_ONLY_FIELDS] used when converting aim constraints.
+fn orient_only_defaults() -> String {
+    let orient = default_orient_constraint(String::new());
+    format!(
+        "Unk Type = {}, Constraint Axes = {}, Range Min = {}, Range Max = {}",
+        orient.unk_type,
+        vector3_text(&orient.constraint_axes),
+        vector3_text(&orient.range_min),
+        vector3_text(&orient.range_max)
+    )
+}
+
+fn vector3_text(v: &Vector3) -> String {
+    format!("({}, {}, {})", v.x, v.y, v.z)
+}
+
+// Bone fields are paired by the role described in each field's tooltip:
+// Target <-> Target 1 is the constrained bone.
+// Source <-> Aim 2 is the bone that drives the constraint.
+// Parent 1 <-> Aim 1 is the bone the driving rotation or direction is relative to.
+// Parent 2 <-> Target 2 is the reference frame for the constrained bone.
+// Parent 2 defines the coordinate space of the target bone,
+// and Target 2 defines the rest orientation of Target 1 in the same way.
+// Neither constraint type has another field with this role.
+// This is a starting point for editing and won't produce the same result in game.
+fn aim_to_orient(aim: &AimConstraintData) -> OrientConstraintData {
+    OrientConstraintData {
+        parent_bone_name1: aim.aim_bone_name1.clone(),
+        parent_bone_name2: aim.target_bone_name2.clone(),
+        source_bone_name: aim.aim_bone_name2.clone(),
+        target_bone_name: aim.target_bone_name1.clone(),
+        quat1: aim.quat1,
+        quat2: aim.quat2,
+        ..default_orient_constraint(aim.name.clone())
+    }
+}
+
+fn orient_to_aim(orient: &OrientConstraintData) -> AimConstraintData {
+    AimConstraintData {
+        aim_bone_name1: orient.parent_bone_name1.clone(),
+        aim_bone_name2: orient.source_bone_name.clone(),
+        target_bone_name1: orient.target_bone_name.clone(),
+        target_bone_name2: orient.parent_bone_name2.clone(),
+        quat1: orient.quat1,
+        quat2: orient.quat2,
+        ..default_aim_constraint(orient.name.clone())
+    }
+}
+
 fn edit_vector3(ui: &mut Ui, id: egui::Id, value: &mut Vector3, min: f32, max: f32) -> bool {
     let mut changed = false;
     ui.horizontal(|ui| {
@@ -352,6 +689,12 @@ fn edit_vector3(ui: &mut Ui, id: egui::Id, value: &mut Vector3, min: f32, max: f
                     .range(min, max),
             )
             .changed();
+
+        let mut values = value.to_array();
+        if clipboard_buttons(ui, &mut values) {
+            *value = values.into();
+            changed = true;
+        }
     });
     changed
 }
@@ -371,6 +714,128 @@ fn edit_vector4(ui: &mut Ui, id: egui::Id, value: &mut Vector4) -> bool {
         changed |= ui
             .add(DragSlider::new(id.with("w"), &mut value.w).width(40.0))
             .changed();
+
+        let mut values = value.to_array();
+        if clipboard_buttons(ui, &mut values) {
+            *value = values.into();
+            changed = true;
+        }
     });
     changed
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn aim_to_orient_bone_names() {
+        let aim = AimConstraintData {
+            aim_bone_name1: "Aim1".to_owned(),
+            aim_bone_name2: "Aim2".to_owned(),
+            target_bone_name1: "Target1".to_owned(),
+            target_bone_name2: "Target2".to_owned(),
+            ..default_aim_constraint("nuHelperBoneAim1".to_owned())
+        };
+
+        let orient = aim_to_orient(&aim);
+        assert_eq!("nuHelperBoneAim1", orient.name);
+        assert_eq!("Aim1", orient.parent_bone_name1);
+        assert_eq!("Target2", orient.parent_bone_name2);
+        assert_eq!("Aim2", orient.source_bone_name);
+        assert_eq!("Target1", orient.target_bone_name);
+    }
+
+    #[test]
+    fn orient_to_aim_bone_names() {
+        let orient = OrientConstraintData {
+            parent_bone_name1: "Parent1".to_owned(),
+            parent_bone_name2: "Parent2".to_owned(),
+            source_bone_name: "Source".to_owned(),
+            target_bone_name: "Target".to_owned(),
+            ..default_orient_constraint("nuHelperBoneRotateInterp1".to_owned())
+        };
+
+        let aim = orient_to_aim(&orient);
+        assert_eq!("nuHelperBoneRotateInterp1", aim.name);
+        assert_eq!("Parent1", aim.aim_bone_name1);
+        assert_eq!("Source", aim.aim_bone_name2);
+        assert_eq!("Target", aim.target_bone_name1);
+        assert_eq!("Parent2", aim.target_bone_name2);
+    }
+
+    #[test]
+    fn orient_aim_orient_round_trip() {
+        // Orient only fields are lost, so only the defaults round trip.
+        let orient = OrientConstraintData {
+            parent_bone_name1: "Parent1".to_owned(),
+            parent_bone_name2: "Parent2".to_owned(),
+            source_bone_name: "Source".to_owned(),
+            target_bone_name: "Target".to_owned(),
+            quat1: Vector4::new(0.0, 0.0, 0.7071068, 0.7071068),
+            quat2: Vector4::new(0.5, 0.5, 0.5, 0.5),
+            ..default_orient_constraint("a".to_owned())
+        };
+        assert_eq!(orient, aim_to_orient(&orient_to_aim(&orient)));
+    }
+
+    #[test]
+    fn aim_orient_aim_round_trip() {
+        let aim = AimConstraintData {
+            aim_bone_name1: "Aim1".to_owned(),
+            aim_bone_name2: "Aim2".to_owned(),
+            target_bone_name1: "Target1".to_owned(),
+            target_bone_name2: "Target2".to_owned(),
+            quat1: Vector4::new(0.0, 0.0, 0.7071068, 0.7071068),
+            quat2: Vector4::new(0.5, 0.5, 0.5, 0.5),
+            ..default_aim_constraint("a".to_owned())
+        };
+        assert_eq!(aim, orient_to_aim(&aim_to_orient(&aim)));
+    }
+
+    #[test]
+    fn aim_only_defaults_fields() {
+        assert_eq!(
+            "Aim Type 1 = DEFAULT, Aim Type 2 = DEFAULT, Unk1 = 0, Unk2 = 1, \
+             Aim = (1, 0, 0), Up = (0, 1, 0)",
+            aim_only_defaults()
+        );
+        assert!(AIM_ONLY_FIELDS
+            .iter()
+            .all(|f| aim_only_defaults().contains(&format!("{f} = "))));
+    }
+
+    #[test]
+    fn orient_only_defaults_fields() {
+        assert_eq!(
+            "Unk Type = 1, Constraint Axes = (1, 1, 1), \
+             Range Min = (-180, -180, -180), Range Max = (180, 180, 180)",
+            orient_only_defaults()
+        );
+        assert!(ORIENT_ONLY_FIELDS
+            .iter()
+            .all(|f| orient_only_defaults().contains(&format!("{f} = "))));
+    }
+
+    #[test]
+    fn orient_to_aim_default_fields() {
+        let orient = OrientConstraintData {
+            unk_type: 2,
+            range_min: Vector3::new(-90.0, 0.0, 0.0),
+            ..default_orient_constraint("a".to_owned())
+        };
+        let aim = orient_to_aim(&orient);
+        assert_eq!(default_aim_constraint("a".to_owned()), aim);
+    }
+
+    #[test]
+    fn aim_to_orient_default_fields() {
+        let aim = AimConstraintData {
+            unk1: 5,
+            aim: Vector3::new(0.0, 0.0, 1.0),
+            ..default_aim_constraint("a".to_owned())
+        };
+        let orient = aim_to_orient(&aim);
+        assert_eq!(default_orient_constraint("a".to_owned()), orient);
+    }
+}
@@ -1,7 +1,7 @@
 use crate::{
     app::{
-        display_validation_errors, draggable_icon, warning_icon, warning_icon_text,
-        MatlEditorState, PresetMode, UiState,
+        display_validation_errors, draggable_icon, warning_icon, warning_icon_text, AnimTrackIndex,
+        MatlEditorState, PresetMode, TextureReassignState, UiState,
     },
     horizontal_separator_empty,
     material::*,
@@ -10,11 +10,11 @@ use crate::{
     save_file, save_file_as,
     validation::{MatlValidationError, MatlValidationErrorKind},
     widgets::*,
-    EditorResponse, TextureDimension, Thumbnail,
+    EditorResponse, FileResult, TextureDimension, Thumbnail,
 };
 use egui::{
     load::SizedTexture, special_emojis::GITHUB, Button, CentralPanel, CollapsingHeader, ComboBox,
-    Context, DragValue, Grid, Label, RichText, ScrollArea, Sense, SidePanel, TextEdit,
+    Context, DragValue, Grid, Label, Response, RichText, ScrollArea, Sense, SidePanel, TextEdit,
     TextWrapMode, TopBottomPanel, Ui, Window,
 };
 use egui_dnd::dnd;
@@ -22,7 +22,7 @@ use log::error;
 use rfd::FileDialog;
 use ssbh_data::{matl_data::*, modl_data::ModlEntryData, prelude::*, Color4f, Vector4};
 use ssbh_wgpu::{ShaderDatabase, ShaderProgram};
-use std::path::Path;
+use std::{collections::HashMap, path::Path};
 use strum::IntoEnumIterator;
 
 const UNUSED_PARAM: &str =
@@ -33,6 +33,7 @@ pub fn matl_editor(
     ctx: &egui::Context,
     folder_name: &Path,
     file_name: &str,
+    file_key: (usize, usize),
     state: &mut MatlEditorState,
     matl: &mut MatlData,
     modl: Option<&mut ModlData>,
@@ -50,6 +51,13 @@ pub fn matl_editor(
     let mut changed = false;
     let mut saved = false;
 
+    // Store the original values to support resetting parameters.
+    // Saving or renaming the file shouldn't replace the values from when it was loaded.
+    if state.original_matl.as_ref().map(|(k, _)| *k) != Some(file_key) {
+        state.original_matl = Some((file_key, matl.clone()));
+        state.applied_presets.clear();
+    }
+
     let title = folder_editor_title(folder_name, file_name);
     Window::new(format!("Matl Editor ({title})"))
         .open(&mut open)
@@ -103,6 +111,15 @@ pub fn matl_editor(
                 }
                 changed |= preset_changed;
 
+                changed |= reassign_texture_window(ctx, &mut state.texture_reassign, matl);
+                changed |= copy_param_window(ctx, &mut state.param_to_copy, matl);
+                changed |= import_entries_window(
+                    ctx,
+                    &mut state.entries_to_import,
+                    matl,
+                    &mut state.selected_material_index,
+                );
+
                 ScrollArea::vertical()
                     .auto_shrink([false; 2])
                     .show(ui, |ui| {
@@ -143,67 +160,73 @@ fn select_material_dnd(
 ) -> bool {
     let mut changed = false;
 
-    // TODO: Avoid allocating here.
-    let mut item_indices: Vec<_> = (0..entries.len()).collect();
+    ui.add(
+        TextEdit::singleline(&mut state.material_filter)
+            .hint_text("Filter material labels")
+            .desired_width(f32::INFINITY),
+    );
+    ui.add(
+        TextEdit::singleline(&mut state.shader_filter)
+            .hint_text("Filter shader labels")
+            .desired_width(f32::INFINITY),
+    );
+    horizontal_separator_empty(ui);
 
     let mut index_to_delete = None;
 
-    let response = dnd(ui, "matl_dnd").show_vec(&mut item_indices, |ui, item_index, handle, _| {
-        ui.horizontal(|ui| {
-            let entry = &entries[*item_index];
-
-            handle.ui(ui, |ui| {
-                draggable_icon(ctx, ui, dark_mode);
-            });
-
-            // TODO: Avoid collect.
-            let errors: Vec<_> = validation_errors
-                .iter()
-                .filter(|e| e.entry_index == *item_index)
-                .collect();
-            let text = if !errors.is_empty() {
-                warning_icon_text(&entry.material_label)
-            } else {
-                RichText::new(&entry.material_label)
-            };
-
-            // Wrap long material labels.
-            ui.style_mut().wrap_mode = Some(TextWrapMode::Wrap);
-
-            let mut response =
-                ui.selectable_label(state.selected_material_index == *item_index, text);
-            if response.clicked() {
-                state.selected_material_index = *item_index;
-            }
-
-            if !errors.is_empty() {
-                response = response.on_hover_ui(|ui| display_validation_errors(ui, &errors));
-            }
-
-            // TODO: This needs to be cleared every frame.
-            // TODO: Use a messages instead?
-            if response.hovered() {
-                // Used for material mask rendering.
-                state.hovered_material_index = Some(*item_index);
+    if !state.material_filter.is_empty() || !state.shader_filter.is_empty() {
+        // Reordering only a subset of the entries would be confusing.
+        let material_filter = state.material_filter.to_lowercase();
+        let shader_filter = state.shader_filter.to_lowercase();
+        for (i, entry) in entries.iter().enumerate() {
+            if entry
+                .material_label
+                .to_lowercase()
+                .contains(&material_filter)
+                && entry.shader_label.to_lowercase().contains(&shader_filter)
+            {
+                ui.horizontal(|ui| {
+                    material_list_item(
+                        ui,
+                        entry,
+                        i,
+                        validation_errors,
+                        state,
+                        &mut index_to_delete,
+                    );
+                });
             }
+        }
+    } else {
+        // TODO: Avoid allocating here.
+        let mut item_indices: Vec<_> = (0..entries.len()).collect();
+
+        let response =
+            dnd(ui, "matl_dnd").show_vec(&mut item_indices, |ui, item_index, handle, _| {
+                ui.horizontal(|ui| {
+                    handle.ui(ui, |ui| {
+                        draggable_icon(ctx, ui, dark_mode);
+                    });
 
-            response.context_menu(|ui| {
-                // TODO: Also add a menu option?
-                if ui.button("Delete").clicked() {
-                    ui.close_menu();
-                    index_to_delete = Some(*item_index);
-                }
+                    material_list_item(
+                        ui,
+                        &entries[*item_index],
+                        *item_index,
+                        validation_errors,
+                        state,
+                        &mut index_to_delete,
+                    );
+                });
             });
-        });
-    });
 
-    if let Some(response) = response.final_update() {
-        egui_dnd::utils::shift_vec(response.from, response.to, entries);
-        state.selected_material_index = item_indices
-            .iter()
-            .position(|i| *i == state.selected_material_index)
-            .unwrap_or_default();
-        changed = true;
+        if let Some(response) = response.final_update() {
+            egui_dnd::utils::shift_vec(response.from, response.to, entries);
+            state.selected_material_index = item_indices
+                .iter()
+                .position(|i| *i == state.selected_material_index)
+                .unwrap_or_default();
+            changed = true;
+        }
     }
 
     if let Some(i) = index_to_delete {
@@ -214,6 +237,59 @@ fn select_material_dnd(
     changed
 }
 
+fn material_list_item(
+    ui: &mut Ui,
+    entry: &MatlEntryData,
+    entry_index: usize,
+    validation_errors: &[MatlValidationError],
+    state: &mut MatlEditorState,
+    index_to_delete: &mut Option<usize>,
+) {
+    // TODO: Avoid collect.
+    let errors: Vec<_> = validation_errors
+        .iter()
+        .filter(|e| e.entry_index == entry_index)
+        .collect();
+    let text = if !errors.is_empty() {
+        warning_icon_text(&entry.material_label)
+    } else {
+        RichText::new(&entry.material_label)
+    };
+
+    // Wrap long material labels.
+    ui.style_mut().wrap_mode = Some(TextWrapMode::Wrap);
+
+    ui.vertical(|ui| {
+        let mut response = ui.selectable_label(state.selected_material_index == entry_index, text);
+
+        // Presets are shader specific, so also show the shader label.
+        ui.label(RichText::new(&entry.shader_label).small().weak());
+
+        if response.clicked() {
+            state.selected_material_index = entry_index;
+        }
+
+        if !errors.is_empty() {
+            response = response.on_hover_ui(|ui| display_validation_errors(ui, &errors));
+        }
+
+        // TODO: This needs to be cleared every frame.
+        // TODO: Use a messages instead?
+        if response.hovered() {
+            // Used for material mask rendering.
+            state.hovered_material_index = Some(entry_index);
+        }
+
+        response.context_menu(|ui| {
+            // TODO: Also add a menu option?
+            if ui.button("Delete").clicked() {
+                ui.close_menu();
+                *index_to_delete = Some(entry_index);
+            }
+        });
+    });
+}
+
 // TODO: Validate presets?
 pub fn preset_editor(
     ctx: &egui::Context,
@@ -338,6 +414,93 @@ fn presets_menu(ui: &mut Ui, user_presets: &mut Vec<MatlEntryData>) {
     });
 }
 
+/// Tile a preview of each material into a grid image with `cell_size` pixels per material.
+/// Each cell is labeled with its material label.
+pub fn export_matl_swatch_sheet<F>(
+    matl: &MatlData,
+    render_swatch: F,
+    output_path: &Path,
+    cell_size: u32,
+) -> FileResult<()>
+where
+    F: Fn(&MatlEntryData, u32) -> Option<image::RgbaImage>,
+{
+    let columns = (matl.entries.len() as f32).sqrt().ceil().max(1.0) as u32;
+    let rows = (matl.entries.len() as u32).div_ceil(columns).max(1);
+
+    let font = ab_glyph::FontRef::try_from_slice(crate::FONT_BYTES)?;
+
+    let mut sheet = image::RgbaImage::new(columns * cell_size, rows * cell_size);
+    for (i, entry) in matl.entries.iter().enumerate() {
+        let x = (i as u32 % columns) * cell_size;
+        let y = (i as u32 / columns) * cell_size;
+        if let Some(swatch) = render_swatch(entry, cell_size) {
+            image::imageops::overlay(&mut sheet, &swatch, x as i64, y as i64);
+        }
+        draw_swatch_label(&mut sheet, &font, &entry.material_label, x, y, cell_size);
+    }
+
+    sheet.save(output_path)?;
+    Ok(())
+}
+
+/// Draw `label` on a dark band along the bottom of the cell at `x`, `y`.
+/// Text that doesn't fit in the cell is clipped.
+fn draw_swatch_label(
+    image: &mut image::RgbaImage,
+    font: &ab_glyph::FontRef,
+    label: &str,
+    x: u32,
+    y: u32,
+    cell_size: u32,
+) {
+    use ab_glyph::{Font, ScaleFont};
+
+    let band_height = (cell_size / 8).max(8);
+    let min_x = x;
+    let max_x = (x + cell_size).min(image.width());
+    let min_y = (y + cell_size).saturating_sub(band_height);
+    let max_y = (y + cell_size).min(image.height());
+
+    for py in min_y..max_y {
+        for px in min_x..max_x {
+            image.put_pixel(px, py, image::Rgba([0, 0, 0, 255]));
+        }
+    }
+
+    let scale = ab_glyph::PxScale::from(band_height as f32 * 0.9);
+    let scaled_font = font.as_scaled(scale);
+    let margin = band_height as f32 * 0.25;
+    let baseline = min_y as f32 + (band_height as f32 + scaled_font.ascent()) / 2.0 - margin / 2.0;
+
+    let mut caret = min_x as f32 + margin;
+    for c in label.chars() {
+        let glyph_id = scaled_font.glyph_id(c);
+        let glyph = glyph_id.with_scale_and_position(scale, ab_glyph::point(caret, baseline));
+        caret += scaled_font.h_advance(glyph_id);
+
+        if let Some(outline) = font.outline_glyph(glyph) {
+            let bounds = outline.px_bounds();
+            outline.draw(|gx, gy, coverage| {
+                let px = bounds.min.x as i64 + gx as i64;
+                let py = bounds.min.y as i64 + gy as i64;
+                if (min_x as i64..max_x as i64).contains(&px)
+                    && (min_y as i64..max_y as i64).contains(&py)
+                {
+                    let pixel = image.get_pixel_mut(px as u32, py as u32);
+                    let value = (coverage.clamp(0.0, 1.0) * 255.0) as u8;
+                    pixel.0 = [
+                        value.max(pixel[0]),
+                        value.max(pixel[1]),
+                        value.max(pixel[2]),
+                        255,
+                    ];
+                }
+            });
+        }
+    }
+}
+
 fn remove_duplicates(entries: &mut Vec<MatlEntryData>) {
     // Remove duplicates using PartialEq while preserving ordering.
     // TODO: Avoid clone?
@@ -433,6 +596,52 @@ fn load_presets_from_file<F: Fn(&[u8]) -> anyhow::Result<Vec<MatlEntryData>>>(
     }
 }
 
+fn export_entries_json(entries: &[MatlEntryData], file: &Path) {
+    match matl_entries_to_json(entries) {
+        Ok(json) => {
+            if let Err(e) = std::fs::write(file, json) {
+                error!("Failed to save material entries to {:?}: {}", file, e);
+            }
+        }
+        Err(e) => error!("Failed to convert material entries to JSON: {}", e),
+    }
+}
+
+fn import_entries_window(
+    ctx: &Context,
+    entries_to_import: &mut Option<Vec<MatlEntryData>>,
+    matl: &mut MatlData,
+    selected_material_index: &mut usize,
+) -> bool {
+    let mut changed = false;
+    dialog_window(
+        ctx,
+        "Import Material Entries",
+        entries_to_import,
+        |ui, entries| {
+            ui.label(format!(
+                "Replace the {} current entries with {} imported entries?",
+                matl.entries.len(),
+                entries.len()
+            ));
+            horizontal_separator_empty(ui);
+
+            ui.horizontal(|ui| {
+                if ui.button("Replace").clicked() {
+                    matl.entries = std::mem::take(entries);
+                    *selected_material_index = 0;
+                    changed = true;
+                    true
+                } else {
+                    ui.button("Cancel").clicked()
+                }
+            })
+            .inner
+        },
+    );
+    changed
+}
+
 fn save_material_presets(presets: &[MatlEntryData], file: std::path::PathBuf) {
     // TODO: Give a visual indication that the file saved?
     match serde_json::to_string_pretty(&MatlData {
@@ -551,6 +760,9 @@ fn preset_window(
                 {
                     if let Some(entry) = entry {
                         *entry = apply_preset(entry, preset);
+                        state
+                            .applied_presets
+                            .insert(entry.material_label.clone(), preset.clone());
                         changed = true;
                     }
                 }
@@ -624,6 +836,51 @@ fn menu_bar(
                 ui.close_menu();
                 saved |= save_file_as(matl, folder_name, file_name, "Matl", "numatb");
             }
+            ui.separator();
+
+            if ui.button("Export as JSON...").clicked() {
+                ui.close_menu();
+
+                if let Some(file) = FileDialog::new()
+                    .add_filter("Matl JSON", &["json"])
+                    .save_file()
+                {
+                    export_entries_json(&matl.entries, &file);
+                }
+            }
+
+            if ui.button("Import from JSON...").clicked() {
+                ui.close_menu();
+
+                if let Some(file) = FileDialog::new()
+                    .add_filter("Matl JSON", &["json"])
+                    .pick_file()
+                {
+                    // Replacing all entries can't be undone, so confirm first.
+                    match std::fs::read(&file)
+                        .map_err(anyhow::Error::from)
+                        .and_then(|data| Ok(matl_entries_from_json(&data)?))
+                    {
+                        Ok(entries) => state.entries_to_import = Some(entries),
+                        Err(e) => {
+                            error!("Failed to import material entries from {:?}: {}", file, e)
+                        }
+                    }
+                }
+            }
+            ui.separator();
+
+            if ui
+                .add(Button::new("Export Swatch Sheet...").wrap_mode(TextWrapMode::Extend))
+                .clicked()
+            {
+                ui.close_menu();
+
+                if let Some(file) = FileDialog::new().add_filter("Image", &["png"]).save_file() {
+                    // Rendering requires the GPU, so defer rendering to the app.
+                    state.swatch_sheet_to_render = Some(file);
+                }
+            }
         });
 
         ui.menu_button("Material", |ui| {
@@ -805,7 +1062,7 @@ fn edit_shader_label(
             ui.image(SizedTexture { id: red_checkerboard, size: egui::Vec2::new(16.0, 16.0) });
             changed |= ui.add_sized(
                 egui::Vec2::new(ui.available_width(), 20.0),
-                egui::TextEdit::singleline(shader_label).text_color(egui::Color32::RED),
+                egui::TextEdit::singleline(shader_label).text_color(ui.visuals().error_fg_color),
             ).changed();
         })
         .response
@@ -830,6 +1087,12 @@ fn edit_matl_entry_inner(
     let mut changed = false;
 
     let program = shader_database.get(&entry.shader_label);
+    let defaults = default_entry(
+        &state.applied_presets,
+        state.original_matl.as_ref(),
+        &entry.material_label,
+    );
+    let mut param_to_copy = None;
 
     ui.heading("Shader");
     changed |= edit_shader(ui, entry, program, red_checkerboard);
@@ -915,32 +1178,78 @@ fn edit_matl_entry_inner(
 
     for param in entry.booleans.iter_mut() {
         ui.add_enabled_ui(!unused_parameters.contains(&param.param_id), |ui| {
-            changed |= ui
-                .checkbox(&mut param.data, param_label(param.param_id))
-                .on_disabled_hover_text(UNUSED_PARAM)
-                .changed();
+            ui.horizontal(|ui| {
+                let response = ui
+                    .checkbox(&mut param.data, param_label(param.param_id))
+                    .on_disabled_hover_text(UNUSED_PARAM);
+                changed |= response.changed();
+                if unused_parameters.contains(&param.param_id) {
+                    unused_label(ui);
+                }
+                copy_to_entries_menu(&response, param.param_id, &mut param_to_copy);
+                animated_param_icon(ui, animated_track(param.param_id), &mut track_to_open);
+                changed |= reset_param_button(ui, param, defaults.map(|e| &e.booleans[..]));
+            });
         });
     }
     horizontal_separator_empty(ui);
 
+    let curve = |param_id: ParamId| {
+        state
+            .param_curves
+            .get(&(entry.material_label.clone(), param_id.to_string()))
+    };
+    let animated_track = |param_id: ParamId| {
+        state
+            .animated_params
+            .get(&(entry.material_label.clone(), param_id.to_string()))
+    };
+    let mut track_to_open = None;
+
     for param in entry.floats.iter_mut() {
         let id = egui::Id::new(param.param_id.to_string());
         ui.add_enabled_ui(!unused_parameters.contains(&param.param_id), |ui| {
             ui.horizontal(|ui| {
-                ui.label(param_label(param.param_id))
-                    .on_disabled_hover_text(UNUSED_PARAM);
+                let usage = param_usage(param.param_id);
+                let response = if usage.is_empty() {
+                    ui.label(param_label(param.param_id))
+                } else {
+                    tooltip_text(ui, param_label(param.param_id), usage)
+                };
+                let response = response
+                    .on_disabled_hover_text(UNUSED_PARAM)
+                    .interact(Sense::click());
+                if unused_parameters.contains(&param.param_id) {
+                    unused_label(ui);
+                }
+                copy_to_entries_menu(&response, param.param_id, &mut param_to_copy);
+                animated_param_icon(ui, animated_track(param.param_id), &mut track_to_open);
                 changed |= ui.add(DragSlider::new(id, &mut param.data)).changed();
+                if let Some(curve) = curve(param.param_id) {
+                    sparkline(ui, curve, state.axis_colors);
+                }
+                changed |= clipboard_buttons(ui, std::slice::from_mut(&mut param.data));
+                changed |= reset_param_button(ui, param, defaults.map(|e| &e.floats[..]));
             })
         });
     }
     horizontal_separator_empty(ui);
 
+    // Include the entry in the id so widget state like linked scale isn't shared between materials.
+    let entry_id = egui::Id::new(&entry.material_label).with(state.selected_material_index);
     for param in entry.vectors.iter_mut() {
         changed |= edit_vector(
             ui,
+            entry_id.with(param.param_id.to_string()),
             param,
             !unused_parameters.contains(&param.param_id),
             program,
+            defaults.map(|e| &e.vectors[..]),
+            &mut param_to_copy,
+            curve(param.param_id).map(|c| &c[..]),
+            state.axis_colors,
+            animated_track(param.param_id),
+            &mut track_to_open,
         );
     }
     horizontal_separator_empty(ui);
@@ -966,12 +1275,15 @@ fn edit_matl_entry_inner(
                 i,
                 param,
                 &mut state.texture_to_edit_index,
+                &mut state.texture_reassign,
                 texture_thumbnails,
                 default_thumbnails,
                 !unused_parameters.contains(&param.param_id),
                 &errors,
                 program,
+                &mut param_to_copy,
             );
+            changed |= reset_param_button(ui, param, defaults.map(|e| &e.textures[..]));
             ui.end_row();
         }
     });
@@ -1016,9 +1328,120 @@ fn edit_matl_entry_inner(
         changed |= edit_blend(ui, param, &errors);
     }
 
+    if let Some(track) = track_to_open {
+        state.track_to_open = Some(track);
+    }
+
+    if let Some(param_id) = param_to_copy {
+        state.param_to_copy = Some((state.selected_material_index, param_id));
+    }
+
+    changed
+}
+
+fn copy_to_entries_menu(
+    response: &Response,
+    param_id: ParamId,
+    param_to_copy: &mut Option<ParamId>,
+) {
+    response.context_menu(|ui| {
+        if ui
+            .button("Copy value to all entries with same shader")
+            .clicked()
+        {
+            ui.close_menu();
+            *param_to_copy = Some(param_id);
+        }
+    });
+}
+
+fn copy_param_window(
+    ctx: &Context,
+    param_to_copy: &mut Option<(usize, ParamId)>,
+    matl: &mut MatlData,
+) -> bool {
+    let mut changed = false;
+
+    if let Some((source_index, _)) = *param_to_copy {
+        let Some(source) = matl.entries.get(source_index).cloned() else {
+            *param_to_copy = None;
+            return false;
+        };
+
+        dialog_window(
+            ctx,
+            "Copy Parameter Value",
+            param_to_copy,
+            |ui, (_, param_id)| {
+                let param_id = *param_id;
+                let targets = same_shader_entries_with_param(&matl.entries, source_index, param_id);
+                ui.label(format!(
+                    "Copy {} from {} to {} other entries with shader {}?",
+                    param_label(param_id),
+                    source.material_label,
+                    targets.len(),
+                    source.shader_label
+                ));
+                horizontal_separator_empty(ui);
+
+                ui.horizontal(|ui| {
+                    if ui
+                        .add_enabled(!targets.is_empty(), Button::new("Apply"))
+                        .clicked()
+                    {
+                        for i in &targets {
+                            copy_parameter(&source, &mut matl.entries[*i], param_id);
+                        }
+                        changed = true;
+                        true
+                    } else {
+                        ui.button("Cancel").clicked()
+                    }
+                })
+                .inner
+            },
+        );
+    }
+
     changed
 }
 
+/// The applied preset or original entry used for resetting parameters.
+fn default_entry<'a>(
+    applied_presets: &'a HashMap<String, MatlEntryData>,
+    original_matl: Option<&'a ((usize, usize), MatlData)>,
+    material_label: &str,
+) -> Option<&'a MatlEntryData> {
+    applied_presets.get(material_label).or_else(|| {
+        original_matl.and_then(|(_, matl)| {
+            matl.entries
+                .iter()
+                .find(|e| e.material_label == material_label)
+        })
+    })
+}
+
+fn reset_param_button<T: PartialEq + Clone>(
+    ui: &mut Ui,
+    param: &mut ParamData<T>,
+    defaults: Option<&[ParamData<T>]>,
+) -> bool {
+    let default =
+        defaults.and_then(|defaults| defaults.iter().find(|p| p.param_id == param.param_id));
+    let can_reset = default.is_some_and(|d| d.data != param.data);
+
+    let response = ui
+        .add_enabled(can_reset, Button::new("↺").small())
+        .on_hover_text("Reset to the value from the applied preset or when the file was opened.");
+    match default {
+        Some(default) if response.clicked() => {
+            param.data = default.data.clone();
+            true
+        }
+        _ => false,
+    }
+}
+
 fn edit_shader(
     ui: &mut Ui,
     entry: &mut MatlEntryData,
@@ -1201,11 +1624,13 @@ fn edit_texture(
     i: usize,
     param: &mut TextureParam,
     texture_to_edit_index: &mut Option<usize>,
+    texture_reassign: &mut Option<TextureReassignState>,
     texture_thumbnails: &[Thumbnail],
     default_thumbnails: &[Thumbnail],
     enabled: bool,
     errors: &[&&MatlValidationError],
     program: Option<&ShaderProgram>,
+    param_to_copy: &mut Option<ParamId>,
 ) -> bool {
     let channel_mask = program
         .map(|p| p.accessed_channels(&param.param_id.to_string()))
@@ -1218,9 +1643,18 @@ fn edit_texture(
 
     let text = param_text(param.param_id, errors);
     let response = ui
-        .add_enabled(enabled, Label::new(text).sense(Sense::click()))
-        .on_hover_text(format!("Used channels: {channels}"))
-        .on_disabled_hover_text(UNUSED_PARAM);
+        .horizontal(|ui| {
+            let response = ui
+                .add_enabled(enabled, Label::new(text).sense(Sense::click()))
+                .on_hover_text(format!("Used channels: {channels}"))
+                .on_disabled_hover_text(UNUSED_PARAM);
+            if !enabled {
+                unused_label(ui);
+            }
+            response
+        })
+        .inner;
+    copy_to_entries_menu(&response, param.param_id, param_to_copy);
 
     // Show errors that apply to this param.
     if !errors.is_empty() {
@@ -1306,6 +1740,18 @@ fn edit_texture(
                         ui.close_menu();
                         *texture_to_edit_index = Some(i);
                     }
+
+                    if ui
+                        .button("Reassign Texture...")
+                        .on_hover_text("Replace this texture in all materials.")
+                        .clicked()
+                    {
+                        ui.close_menu();
+                        *texture_reassign = Some(TextureReassignState {
+                            old_name: param.data.clone(),
+                            new_name: param.data.clone(),
+                        });
+                    }
                 });
         });
     }
@@ -1313,6 +1759,85 @@ fn edit_texture(
     changed
 }
 
+fn reassign_texture_window(
+    ctx: &egui::Context,
+    texture_reassign: &mut Option<TextureReassignState>,
+    matl: &mut MatlData,
+) -> bool {
+    let mut changed = false;
+
+    let mut open = texture_reassign.is_some();
+    let mut should_close = false;
+    if let Some(reassign) = texture_reassign {
+        Window::new("Reassign Texture")
+            .open(&mut open)
+            .resizable(false)
+            .show(ctx, |ui| {
+                Grid::new("reassign_texture_grid").show(ui, |ui| {
+                    ui.label("Old Texture");
+                    ui.text_edit_singleline(&mut reassign.old_name);
+                    ui.end_row();
+
+                    ui.label("New Texture");
+                    ui.text_edit_singleline(&mut reassign.new_name);
+                    ui.end_row();
+                });
+                horizontal_separator_empty(ui);
+
+                // Preview the changes before modifying the matl.
+                let affected = texture_params_using(matl, &reassign.old_name);
+                ui.heading(format!("Affected Parameters ({})", affected.len()));
+                ScrollArea::vertical().max_height(200.0).show(ui, |ui| {
+                    for (material_label, param_id) in &affected {
+                        ui.label(format!("{material_label}: {param_id}"));
+                    }
+                });
+                horizontal_separator_empty(ui);
+
+                let can_reassign = !affected.is_empty() && !reassign.new_name.is_empty();
+                if ui
+                    .add_enabled(can_reassign, Button::new("Reassign"))
+                    .clicked()
+                {
+                    changed |= reassign_texture(matl, &reassign.old_name, &reassign.new_name) > 0;
+                    should_close = true;
+                }
+            });
+    }
+
+    if !open || should_close {
+        *texture_reassign = None;
+    }
+
+    changed
+}
+
+fn texture_params_using(matl: &MatlData, name: &str) -> Vec<(String, ParamId)> {
+    matl.entries
+        .iter()
+        .flat_map(|e| {
+            e.textures
+                .iter()
+                .filter(|t| t.data.eq_ignore_ascii_case(name))
+                .map(|t| (e.material_label.clone(), t.param_id))
+        })
+        .collect()
+}
+
+/// Replace texture parameters for all entries using `old_name` with `new_name`.
+/// Texture names aren't case sensitive.
+/// Returns the number of modified parameters.
+pub fn reassign_texture(matl: &mut MatlData, old_name: &str, new_name: &str) -> usize {
+    let mut count = 0;
+    for param in matl.entries.iter_mut().flat_map(|e| e.textures.iter_mut()) {
+        if param.data.eq_ignore_ascii_case(old_name) {
+            param.data = new_name.to_owned();
+            count += 1;
+        }
+    }
+    count
+}
+
 fn edit_sampler(ui: &mut Ui, param: &mut SamplerParam, errors: &[&&MatlValidationError]) -> bool {
     let mut changed = false;
 
@@ -1434,32 +1959,79 @@ fn anisotropy_label(v: MaxAnisotropy) -> &'static str {
     }
 }
 
+/// Flag parameters not accessed by the shader program to make them easier to remove.
+fn unused_label(ui: &mut Ui) {
+    ui.label(RichText::new("⚠ Unused").weak())
+        .on_hover_text(UNUSED_PARAM);
+}
+
+fn animated_param_icon(
+    ui: &mut Ui,
+    animated_track: Option<&AnimTrackIndex>,
+    track_to_open: &mut Option<AnimTrackIndex>,
+) {
+    if let Some(track) = animated_track {
+        if ui
+            .small_button("🎬")
+            .on_hover_text(
+                "This parameter is animated. Click to show the track in the Anim Editor.",
+            )
+            .clicked()
+        {
+            *track_to_open = Some(*track);
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 fn edit_vector(
     ui: &mut Ui,
+    id: egui::Id,
     param: &mut Vector4Param,
     enabled: bool,
     program: Option<&ShaderProgram>,
+    defaults: Option<&[Vector4Param]>,
+    param_to_copy: &mut Option<ParamId>,
+    curve: Option<&[Vec<f32>]>,
+    axis_colors: [egui::Color32; 3],
+    animated_track: Option<&AnimTrackIndex>,
+    track_to_open: &mut Option<AnimTrackIndex>,
 ) -> bool {
     let mut changed = false;
 
-    ui.add_enabled(enabled, Label::new(param_label(param.param_id)))
-        .on_disabled_hover_text(UNUSED_PARAM);
+    ui.horizontal(|ui| {
+        let response = ui
+            .add_enabled(
+                enabled,
+                Label::new(param_label(param.param_id)).sense(Sense::click()),
+            )
+            .on_disabled_hover_text(UNUSED_PARAM);
+        if !enabled {
+            unused_label(ui);
+        }
+        copy_to_entries_menu(&response, param.param_id, param_to_copy);
+        animated_param_icon(ui, animated_track, track_to_open);
+    });
 
     let channels = program
         .map(|p| p.accessed_channels(&param.param_id.to_string()))
         .unwrap_or_default();
     let labels = vector4_labels_short(param.param_id);
     let labels_long = vector4_labels_long(param.param_id);
+    let bounds = vector4_bounds(param.param_id);
 
     // Prevent editing components not accessed by the shader code.
-    let id = egui::Id::new(param.param_id.to_string());
     let edit_component = |ui: &mut Ui, changed: &mut bool, i, value| {
         let component = labels[i];
         ui.add_enabled_ui(enabled && channels[i], |ui| {
             ui.horizontal(|ui| {
                 ui.add_sized([15.0, 20.0], egui::Label::new(component));
+                let mut slider = DragSlider::new(id.with(labels[i]), value).width(50.0);
+                if let Some((min, max)) = bounds[i] {
+                    slider = slider.min(min).max(max);
+                }
                 *changed |= ui
-                    .add(DragSlider::new(id.with(labels[i]), value).width(50.0))
+                    .add(slider)
                     .on_hover_text(labels_long[i])
                     .changed();
             })
@@ -1475,10 +2047,44 @@ fn edit_vector(
         ui.add_enabled_ui(enabled, |ui| {
             changed |= edit_vector4_rgba(ui, &mut param.data);
         });
+        let (previous_x, previous_y) = (param.data.x, param.data.y);
         edit_component(ui, &mut changed, 0, &mut param.data.x);
         edit_component(ui, &mut changed, 1, &mut param.data.y);
+        if is_uv_scale_param(param.param_id) {
+            // Link the scale components to avoid accidentally scaling only one axis.
+            let link_id = id.with("link_scale");
+            let mut linked = ui.data(|d| d.get_temp::<bool>(link_id).unwrap_or_default());
+            if ui
+                .add_enabled(enabled, egui::SelectableLabel::new(linked, "🔗"))
+                .on_hover_text("Link Scale U and Scale V for uniform scaling.")
+                .clicked()
+            {
+                linked = !linked;
+                ui.data_mut(|d| d.insert_temp(link_id, linked));
+            }
+
+            if linked {
+                if param.data.x != previous_x {
+                    param.data.y = param.data.x;
+                } else if param.data.y != previous_y {
+                    param.data.x = param.data.y;
+                }
+            }
+        }
         edit_component(ui, &mut changed, 2, &mut param.data.z);
         edit_component(ui, &mut changed, 3, &mut param.data.w);
+        if let Some(curve) = curve {
+            sparkline(ui, curve, axis_colors);
+        }
+
+        ui.add_enabled_ui(enabled, |ui| {
+            let mut values = param.data.to_array();
+            if clipboard_buttons(ui, &mut values) {
+                param.data = values.into();
+                changed = true;
+            }
+            changed |= reset_param_button(ui, param, defaults);
+        });
     });
 
     changed
@@ -1527,3 +2133,94 @@ fn param_label(p: ParamId) -> String {
         p.to_string()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(material_label: &str, textures: Vec<TextureParam>) -> MatlEntryData {
+        MatlEntryData {
+            material_label: material_label.to_owned(),
+            shader_label: "SFX_PBS_0100000008008269_opaque".to_owned(),
+            blend_states: Vec::new(),
+            floats: Vec::new(),
+            booleans: Vec::new(),
+            vectors: Vec::new(),
+            rasterizer_states: Vec::new(),
+            samplers: Vec::new(),
+            textures,
+            uv_transforms: Vec::new(),
+        }
+    }
+
+    fn texture(param_id: ParamId, data: &str) -> TextureParam {
+        TextureParam {
+            param_id,
+            data: data.to_owned(),
+        }
+    }
+
+    #[test]
+    fn reassign_texture_all_entries() {
+        let mut matl = MatlData {
+            major_version: 1,
+            minor_version: 6,
+            entries: vec![
+                entry(
+                    "a",
+                    vec![
+                        texture(ParamId::Texture0, "alp_mario_001_col"),
+                        texture(ParamId::Texture4, "alp_mario_001_nor"),
+                    ],
+                ),
+                entry("b", vec![texture(ParamId::Texture0, "ALP_MARIO_001_COL")]),
+            ],
+        };
+
+        assert_eq!(
+            2,
+            reassign_texture(&mut matl, "alp_mario_001_col", "alp_mario_002_col")
+        );
+        assert_eq!("alp_mario_002_col", matl.entries[0].textures[0].data);
+        assert_eq!("alp_mario_001_nor", matl.entries[0].textures[1].data);
+        assert_eq!("alp_mario_002_col", matl.entries[1].textures[0].data);
+    }
+
+    #[test]
+    fn reassign_texture_no_matches() {
+        let mut matl = MatlData {
+            major_version: 1,
+            minor_version: 6,
+            entries: vec![entry("a", vec![texture(ParamId::Texture0, "a")])],
+        };
+
+        assert_eq!(0, reassign_texture(&mut matl, "b", "c"));
+        assert_eq!("a", matl.entries[0].textures[0].data);
+    }
+
+    #[test]
+    fn draw_swatch_label_bottom_of_cell() {
+        let font = ab_glyph::FontRef::try_from_slice(crate::FONT_BYTES).unwrap();
+        let mut image = image::RgbaImage::new(128, 128);
+        draw_swatch_label(&mut image, &font, "alp_mario_001", 0, 0, 128);
+
+        // The swatch above the label is unchanged.
+        assert!(image.rows().take(112).flatten().all(|p| p.0 == [0; 4]));
+        // The text is drawn on an opaque band.
+        assert!(image.rows().skip(112).flatten().all(|p| p[3] == 255));
+        assert!(image.rows().skip(112).flatten().any(|p| p[0] > 0));
+    }
+
+    #[test]
+    fn draw_swatch_label_clipped_to_cell() {
+        let font = ab_glyph::FontRef::try_from_slice(crate::FONT_BYTES).unwrap();
+        let mut image = image::RgbaImage::new(64, 32);
+        draw_swatch_label(&mut image, &font, &"a".repeat(64), 0, 0, 32);
+
+        // Long labels don't draw into the next cell.
+        assert!(image
+            .enumerate_pixels()
+            .filter(|(x, _, _)| *x >= 32)
+            .all(|(_, _, p)| p.0 == [0; 4]));
+    }
+}
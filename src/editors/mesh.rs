@@ -1,10 +1,13 @@
 use crate::{
-    app::{display_validation_errors, draggable_icon, warning_icon_text},
+    app::{
+        display_validation_errors, draggable_icon, warning_icon_text, IndexBufferSection,
+        IndexBufferState, MeshEditorState, PaintMode, WARNING_COLOR,
+    },
     horizontal_separator_empty,
     path::folder_editor_title,
     save_file, save_file_as,
     validation::{MeshValidationError, MeshValidationErrorKind},
-    widgets::bone_combo_box,
+    widgets::{bone_combo_box, show_toast, tooltip_text, EyeCheckBox, ToastLevel},
     EditorMessage, EditorResponse,
 };
 use egui::{
@@ -15,20 +18,25 @@ use egui_dnd::dnd;
 use log::error;
 use rfd::FileDialog;
 use ssbh_data::{
+    matl_data::ParamId,
     mesh_data::{
         transform_points, transform_vectors, AttributeData, BoneInfluence, MeshObjectData,
         VectorData, VertexWeight,
     },
     prelude::*,
 };
-use std::path::Path;
+use std::{io::Write, path::Path};
 
+#[allow(clippy::too_many_arguments)]
 pub fn mesh_editor(
     ctx: &egui::Context,
     folder_name: &Path,
     file_name: &str,
+    state: &mut MeshEditorState,
     mesh: &mut MeshData,
     skel: Option<&SkelData>,
+    modl: Option<&ModlData>,
+    matl: Option<&MatlData>,
     validation_errors: &[MeshValidationError],
     dark_mode: bool,
 ) -> EditorResponse {
@@ -53,6 +61,23 @@ pub fn mesh_editor(
                         ui.close_menu();
                         saved |= save_file_as(mesh, folder_name, file_name, "Mesh", "numshb");
                     }
+
+                    ui.separator();
+
+                    if ui
+                        .add_enabled(
+                            state.selected_mesh.is_some(),
+                            Button::new("Export Mesh as OBJ...").wrap_mode(TextWrapMode::Extend),
+                        )
+                        .on_disabled_hover_text("Select a mesh object to export.")
+                        .clicked()
+                    {
+                        ui.close_menu();
+
+                        if let Some(name) = &state.selected_mesh {
+                            export_mesh_obj(ui.ctx(), mesh, name, modl, matl, folder_name);
+                        }
+                    }
                 });
 
                 ui.menu_button("Mesh", |ui| {
@@ -77,6 +102,17 @@ pub fn mesh_editor(
                     }
                 });
 
+                ui.menu_button("Paint", |ui| {
+                    let mut enabled = state.paint_mode.is_some();
+                    if ui
+                        .checkbox(&mut enabled, "Vertex Paint (Experimental)")
+                        .on_hover_text("Click a mesh in the viewport to paint colorSet1.")
+                        .changed()
+                    {
+                        state.paint_mode = enabled.then(PaintMode::default);
+                    }
+                });
+
                 ui.menu_button("Help", |ui| {
                     if ui.button(format!("{GITHUB} Mesh Editor Wiki")).clicked() {
                         ui.close_menu();
@@ -90,12 +126,37 @@ pub fn mesh_editor(
             });
             ui.separator();
 
+            if let Some(paint_mode) = &mut state.paint_mode {
+                ui.horizontal(|ui| {
+                    ui.label("Radius");
+                    ui.add(
+                        egui::DragValue::new(&mut paint_mode.radius)
+                            .range(0.0..=f32::MAX)
+                            .speed(0.01),
+                    );
+                    ui.label("Color");
+                    let mut color = egui::Color32::from_rgba_unmultiplied(
+                        paint_mode.color[0],
+                        paint_mode.color[1],
+                        paint_mode.color[2],
+                        paint_mode.color[3],
+                    );
+                    if ui.color_edit_button_srgba(&mut color).changed() {
+                        paint_mode.color = color.to_srgba_unmultiplied();
+                    }
+                    ui.label("Strength");
+                    ui.add(egui::Slider::new(&mut paint_mode.strength, 0.0..=1.0));
+                });
+                ui.separator();
+            }
+
             ScrollArea::vertical()
                 .auto_shrink([false; 2])
                 .show(ui, |ui| {
                     changed |= edit_mesh(
                         ctx,
                         ui,
+                        state,
                         mesh,
                         validation_errors,
                         skel,
@@ -105,6 +166,9 @@ pub fn mesh_editor(
                 });
         });
 
+    vertex_buffer_window(ctx, mesh, &mut state.vertex_buffer_mesh);
+    index_buffer_window(ctx, mesh, &mut state.index_buffer, changed);
+
     EditorResponse {
         open,
         changed,
@@ -113,9 +177,407 @@ pub fn mesh_editor(
     }
 }
 
+const MAX_INDEX_BUFFER_ROWS: usize = 1000;
+const VERTEX_BUFFER_COLUMN_WIDTH: f32 = 80.0;
+
+fn vertex_buffer_window(
+    ctx: &egui::Context,
+    mesh: &MeshData,
+    selected: &mut Option<(String, u64)>,
+) {
+    let Some(mesh_object) = selected.as_ref().and_then(|(name, subindex)| {
+        mesh.objects
+            .iter()
+            .find(|o| &o.name == name && o.subindex == *subindex)
+    }) else {
+        *selected = None;
+        return;
+    };
+
+    let mut open = true;
+    egui::Window::new(format!(
+        "Vertex Buffer ({}, subindex {})",
+        mesh_object.name, mesh_object.subindex
+    ))
+    .open(&mut open)
+    .default_width(800.0)
+    .default_height(500.0)
+    .resizable(true)
+    .show(ctx, |ui| {
+        let vertex_count = mesh_object.vertex_count().unwrap_or_default();
+        let columns = vertex_buffer_columns(mesh_object);
+
+        // Only lay out the visible rows since meshes can have many vertices.
+        // The header is the first row and fixed column widths keep the rows aligned.
+        let row_height = ui.text_style_height(&egui::TextStyle::Body);
+        ScrollArea::both().auto_shrink([false; 2]).show_rows(
+            ui,
+            row_height,
+            vertex_count + 1,
+            |ui, rows| {
+                Grid::new("vertex_buffer_grid")
+                    .striped(true)
+                    .start_row(rows.start)
+                    .min_col_width(VERTEX_BUFFER_COLUMN_WIDTH)
+                    .max_col_width(VERTEX_BUFFER_COLUMN_WIDTH)
+                    .show(ui, |ui| {
+                        for row in rows {
+                            if row == 0 {
+                                ui.label(RichText::new("vertex").strong());
+                                for (name, _, _) in &columns {
+                                    ui.label(RichText::new(name).strong());
+                                }
+                            } else {
+                                let i = row - 1;
+                                ui.label(i.to_string());
+                                for (_, data, component) in &columns {
+                                    ui.label(
+                                        vector_component(data, i, *component)
+                                            .map(|v| v.to_string())
+                                            .unwrap_or_default(),
+                                    );
+                                }
+                            }
+                            ui.end_row();
+                        }
+                    });
+            },
+        );
+    });
+
+    if !open {
+        *selected = None;
+    }
+}
+
+fn export_vertex_buffer_csv(ctx: &egui::Context, mesh_object: &MeshObjectData) {
+    if let Some(file) = FileDialog::new()
+        .add_filter("CSV", &["csv"])
+        .set_file_name(format!("{}_vertices.csv", mesh_object.name))
+        .save_file()
+    {
+        // Write rows as they are formatted to avoid storing the whole file in memory.
+        let result = std::fs::File::create(&file).and_then(|f| {
+            let mut writer = std::io::BufWriter::new(f);
+            write_vertex_buffer_csv(&mut writer, mesh_object)?;
+            writer.flush()
+        });
+        match result {
+            Ok(()) => {
+                let message = format!("Saved vertex buffer CSV to {file:?}");
+                show_toast(ctx, ToastLevel::Info, message);
+            }
+            Err(e) => error!("Failed to save {:?}: {}", file, e),
+        }
+    }
+}
+
+fn write_vertex_buffer_csv<W: Write>(
+    writer: &mut W,
+    mesh_object: &MeshObjectData,
+) -> std::io::Result<()> {
+    let columns = vertex_buffer_columns(mesh_object);
+
+    let header: Vec<_> = columns.iter().map(|(name, _, _)| name.as_str()).collect();
+    writeln!(writer, "{}", header.join(","))?;
+
+    for i in 0..mesh_object.vertex_count().unwrap_or_default() {
+        for (c, (_, data, component)) in columns.iter().enumerate() {
+            if c > 0 {
+                write!(writer, ",")?;
+            }
+            if let Some(value) = vector_component(data, i, *component) {
+                write!(writer, "{value}")?;
+            }
+        }
+        writeln!(writer)?;
+    }
+
+    Ok(())
+}
+
+fn index_buffer_window(
+    ctx: &egui::Context,
+    mesh: &MeshData,
+    state: &mut Option<IndexBufferState>,
+    mesh_changed: bool,
+) {
+    let Some(index_buffer) = state else {
+        return;
+    };
+
+    // Collecting triangles is expensive for large meshes, so only update after changes.
+    let mesh_objects: Vec<_> = mesh
+        .objects
+        .iter()
+        .filter(|o| o.name == index_buffer.name)
+        .collect();
+    if mesh_changed || !sections_match(&index_buffer.sections, &mesh_objects) {
+        index_buffer.sections = mesh_objects
+            .iter()
+            .map(|o| index_buffer_section(o))
+            .collect();
+    }
+
+    let mut open = true;
+    egui::Window::new(format!("Index Buffer ({})", index_buffer.name))
+        .open(&mut open)
+        .default_width(400.0)
+        .default_height(500.0)
+        .resizable(true)
+        .show(ctx, |ui| {
+            ScrollArea::vertical()
+                .auto_shrink([false; 2])
+                .show(ui, |ui| {
+                    // Show each subindex as a separate section.
+                    for section in &index_buffer.sections {
+                        let triangles = &section.triangles;
+
+                        ui.heading(format!("Subindex {}", section.subindex));
+                        ui.label(format!(
+                            "{} triangles, {} vertices",
+                            triangles.len(),
+                            section.vertex_count
+                        ));
+                        if section.invalid_count > 0 {
+                            ui.label(
+                                RichText::new(format!(
+                                    "{} triangles have out of bounds vertex indices",
+                                    section.invalid_count
+                                ))
+                                .color(ui.visuals().error_fg_color),
+                            );
+                        }
+                        if triangles.len() > MAX_INDEX_BUFFER_ROWS {
+                            ui.label(
+                                RichText::new(format!(
+                                    "Truncated: showing {MAX_INDEX_BUFFER_ROWS} of {} triangles",
+                                    triangles.len()
+                                ))
+                                .color(WARNING_COLOR),
+                            );
+                        }
+
+                        Grid::new(("index_buffer_grid", section.subindex))
+                            .striped(true)
+                            .show(ui, |ui| {
+                                ui.heading("triangle");
+                                ui.heading("v0");
+                                ui.heading("v1");
+                                ui.heading("v2");
+                                ui.end_row();
+
+                                for (i, triangle) in
+                                    triangles.iter().take(MAX_INDEX_BUFFER_ROWS).enumerate()
+                                {
+                                    let color = if is_out_of_bounds(triangle, section.vertex_count)
+                                    {
+                                        ui.visuals().error_fg_color
+                                    } else {
+                                        ui.visuals().text_color()
+                                    };
+
+                                    ui.label(RichText::new(i.to_string()).color(color));
+                                    for index in triangle {
+                                        ui.label(RichText::new(index.to_string()).color(color));
+                                    }
+                                    ui.end_row();
+                                }
+                            });
+                        ui.separator();
+                    }
+                });
+        });
+
+    if !open {
+        *state = None;
+    }
+}
+
+fn index_buffer_section(mesh_object: &MeshObjectData) -> IndexBufferSection {
+    let vertex_count = mesh_object.vertex_count().unwrap_or_default();
+    let triangles = mesh_triangles(mesh_object);
+    let invalid_count = triangles
+        .iter()
+        .filter(|t| is_out_of_bounds(t, vertex_count))
+        .count();
+    IndexBufferSection {
+        subindex: mesh_object.subindex,
+        vertex_count,
+        triangles,
+        invalid_count,
+    }
+}
+
+/// Check if the cached `sections` still have the same subindices and buffer sizes as `mesh_objects`.
+fn sections_match(sections: &[IndexBufferSection], mesh_objects: &[&MeshObjectData]) -> bool {
+    sections.len() == mesh_objects.len()
+        && sections.iter().zip(mesh_objects).all(|(s, o)| {
+            s.subindex == o.subindex
+                && s.vertex_count == o.vertex_count().unwrap_or_default()
+                && s.triangles.len() == o.vertex_indices.len() / 3
+        })
+}
+
+fn mesh_triangles(mesh_object: &MeshObjectData) -> Vec<[u32; 3]> {
+    mesh_object
+        .vertex_indices
+        .chunks_exact(3)
+        .map(|t| [t[0], t[1], t[2]])
+        .collect()
+}
+
+fn is_out_of_bounds(triangle: &[u32; 3], vertex_count: usize) -> bool {
+    triangle.iter().any(|i| *i as usize >= vertex_count)
+}
+
+/// Blend the first color set of the vertices within the paint radius of the nearest
+/// triangle intersection along the ray from `origin` in the normalized `direction`.
+/// Only mesh objects in `visible_meshes` by name and subindex are painted.
+/// Returns `true` if any colors were modified.
+pub fn paint_vertex_colors(
+    mesh: &mut MeshData,
+    visible_meshes: &[(String, u64)],
+    origin: glam::Vec3,
+    direction: glam::Vec3,
+    paint_mode: &PaintMode,
+) -> bool {
+    // Find the closest triangle in front of the camera to avoid painting hidden vertices.
+    let hit = mesh
+        .objects
+        .iter()
+        .enumerate()
+        .filter(|(_, o)| {
+            visible_meshes
+                .iter()
+                .any(|(name, subindex)| *name == o.name && *subindex == o.subindex)
+        })
+        .filter(|(_, o)| !o.color_sets.is_empty())
+        .filter_map(|(i, o)| {
+            let positions = o
+                .positions
+                .first()
+                .map(|a| vector3_values(&a.data))
+                .unwrap_or_default();
+            o.vertex_indices
+                .chunks_exact(3)
+                .filter_map(|t| {
+                    let vertex = |i: u32| positions.get(i as usize).map(|p| glam::Vec3::from(*p));
+                    let triangle = [vertex(t[0])?, vertex(t[1])?, vertex(t[2])?];
+                    intersect_triangle(origin, direction, triangle)
+                })
+                .min_by(|a, b| a.total_cmp(b))
+                .map(|t| (i, t))
+        })
+        .min_by(|(_, a), (_, b)| a.total_cmp(b));
+
+    let Some((object_index, t)) = hit else {
+        return false;
+    };
+    let hit_position = origin + direction * t;
+
+    let mesh_object = &mut mesh.objects[object_index];
+    let positions = mesh_object
+        .positions
+        .first()
+        .map(|a| vector3_values(&a.data))
+        .unwrap_or_default();
+    let Some(VectorData::Vector4(colors)) = mesh_object.color_sets.first_mut().map(|a| &mut a.data)
+    else {
+        return false;
+    };
+
+    let target = paint_mode.color.map(|c| c as f32 / 255.0);
+    let mut changed = false;
+    for (position, color) in positions.iter().zip(colors.iter_mut()) {
+        let distance = glam::Vec3::from(*position).distance(hit_position);
+        if distance <= paint_mode.radius {
+            // Fade out the paint towards the edge of the radius.
+            let falloff = if paint_mode.radius > 0.0 {
+                1.0 - distance / paint_mode.radius
+            } else {
+                1.0
+            };
+            let t = paint_mode.strength * falloff;
+            for (c, target) in color.iter_mut().zip(target) {
+                *c += (target - *c) * t;
+            }
+            changed = true;
+        }
+    }
+    changed
+}
+
+/// The distance along the ray to the intersection with either side of `triangle`.
+fn intersect_triangle(
+    origin: glam::Vec3,
+    direction: glam::Vec3,
+    [a, b, c]: [glam::Vec3; 3],
+) -> Option<f32> {
+    // Möller-Trumbore ray triangle intersection.
+    let edge1 = b - a;
+    let edge2 = c - a;
+    let p = direction.cross(edge2);
+    let determinant = edge1.dot(p);
+    if determinant.abs() < f32::EPSILON {
+        return None;
+    }
+
+    let inverse_determinant = 1.0 / determinant;
+    let s = origin - a;
+    let u = s.dot(p) * inverse_determinant;
+    if !(0.0..=1.0).contains(&u) {
+        return None;
+    }
+
+    let q = s.cross(edge1);
+    let v = direction.dot(q) * inverse_determinant;
+    if v < 0.0 || u + v > 1.0 {
+        return None;
+    }
+
+    let t = edge2.dot(q) * inverse_determinant;
+    (t > 0.0).then_some(t)
+}
+
+/// The name, data, and component index for each column in the vertex buffer.
+fn vertex_buffer_columns(mesh_object: &MeshObjectData) -> Vec<(String, &VectorData, usize)> {
+    mesh_object
+        .positions
+        .iter()
+        .chain(&mesh_object.normals)
+        .chain(&mesh_object.binormals)
+        .chain(&mesh_object.tangents)
+        .chain(&mesh_object.texture_coordinates)
+        .chain(&mesh_object.color_sets)
+        .flat_map(|a| {
+            let count = match &a.data {
+                VectorData::Vector2(_) => 2,
+                VectorData::Vector3(_) => 3,
+                VectorData::Vector4(_) => 4,
+            };
+            ["x", "y", "z", "w"]
+                .into_iter()
+                .take(count)
+                .enumerate()
+                .map(|(i, c)| (format!("{}.{c}", a.name), &a.data, i))
+        })
+        .collect()
+}
+
+fn vector_component(data: &VectorData, index: usize, component: usize) -> Option<f32> {
+    match data {
+        VectorData::Vector2(v) => v.get(index)?.get(component).copied(),
+        VectorData::Vector3(v) => v.get(index)?.get(component).copied(),
+        VectorData::Vector4(v) => v.get(index)?.get(component).copied(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 fn edit_mesh(
     ctx: &egui::Context,
     ui: &mut Ui,
+    state: &mut MeshEditorState,
     mesh: &mut MeshData,
     validation_errors: &[MeshValidationError],
     skel: Option<&SkelData>,
@@ -144,12 +606,24 @@ fn edit_mesh(
                 .filter(|e| e.mesh_object_index == *item)
                 .collect();
 
-            let text = if !errors.is_empty() {
+            let mut text = if !errors.is_empty() {
                 warning_icon_text(&mesh_object.name)
             } else {
                 RichText::new(&mesh_object.name)
             };
 
+            // Isolate parts of the model by hiding individual mesh objects in the viewport.
+            let key = (mesh_object.name.clone(), mesh_object.subindex);
+            if let Some(visible) = state.mesh_visibility.get_mut(&key) {
+                ui.add(EyeCheckBox::new(visible, ""))
+                    .on_hover_text("Show or hide this mesh object in the viewport.");
+            }
+
+            let is_selected = state.selected_mesh.as_ref() == Some(&mesh_object.name);
+            if is_selected {
+                text = text.background_color(ui.visuals().selection.bg_fill);
+            }
+
             let header_response = CollapsingHeader::new(text)
                 .id_salt(id.with("name"))
                 .show(ui, |ui| {
@@ -166,6 +640,29 @@ fn edit_mesh(
                 .header_response;
 
             header_response.context_menu(|ui| {
+                if ui.button("View Vertex Buffer").clicked() {
+                    ui.close_menu();
+                    let mesh_object = &mesh.objects[*item];
+                    state.vertex_buffer_mesh =
+                        Some((mesh_object.name.clone(), mesh_object.subindex));
+                }
+                if ui.button("View Index Buffer").clicked() {
+                    ui.close_menu();
+                    let name = mesh.objects[*item].name.clone();
+                    state.index_buffer = Some(IndexBufferState::new(name));
+                }
+                if ui
+                    .add(
+                        Button::new("Export Vertex Buffer as CSV...")
+                            .wrap_mode(TextWrapMode::Extend),
+                    )
+                    .clicked()
+                {
+                    ui.close_menu();
+                    export_vertex_buffer_csv(ui.ctx(), &mesh.objects[*item]);
+                }
+                ui.separator();
+
                 if ui.button("Delete").clicked() {
                     ui.close_menu();
                     mesh_to_remove = Some(*item);
@@ -173,6 +670,11 @@ fn edit_mesh(
                 }
             });
 
+            // Keep the clicked mesh outlined in the viewport.
+            if header_response.clicked() {
+                state.selected_mesh = Some(mesh.objects[*item].name.clone());
+            }
+
             // Outline the selected mesh in the viewport.
             if header_response.hovered() {
                 *message = Some(EditorMessage::SelectMesh {
@@ -217,20 +719,32 @@ fn edit_mesh_object(
     Grid::new(id.with("mesh_grid")).show(ui, |ui| {
         // TODO: Link name edits with the numdlb and numshexb.
         // This will need to check for duplicate names.
-        ui.label("Name");
+        tooltip_text(
+            ui,
+            "Name",
+            "The mesh object name. Must match the names in the .numdlb and .numshexb.",
+        );
         changed |= ui
             .add(TextEdit::singleline(&mut mesh_object.name).clip_text(false))
             .changed();
         ui.end_row();
 
         // TODO: Is it possible to edit the subindex without messing up influence assignments?
-        ui.label("Subindex");
+        tooltip_text(
+            ui,
+            "Subindex",
+            "Distinguishes mesh objects with the same name.",
+        );
         changed |= ui
             .add(egui::DragValue::new(&mut mesh_object.subindex))
             .changed();
         ui.end_row();
 
-        ui.label("Sort Bias");
+        tooltip_text(
+            ui,
+            "Sort Bias",
+            "The offset applied to the mesh's depth when sorting transparent meshes.",
+        );
         changed |= ui
             .add(egui::DragValue::new(&mut mesh_object.sort_bias))
             .changed();
@@ -379,6 +893,171 @@ fn convert_parent_bone_to_influences(mesh_object: &mut MeshObjectData, skel: Opt
     mesh_object.parent_bone_name = String::new();
 }
 
+fn export_mesh_obj(
+    ctx: &egui::Context,
+    mesh: &MeshData,
+    name: &str,
+    modl: Option<&ModlData>,
+    matl: Option<&MatlData>,
+    folder_name: &Path,
+) {
+    if let Some(file) = FileDialog::new()
+        .add_filter("OBJ", &["obj"])
+        .set_directory(folder_name)
+        .set_file_name(format!("{name}.obj"))
+        .save_file()
+    {
+        // Export all subindices since the selection only stores the name.
+        let mesh_objects: Vec<_> = mesh.objects.iter().filter(|o| o.name == name).collect();
+        let materials: Vec<_> = mesh_objects
+            .iter()
+            .map(|o| mesh_object_material(o, modl, matl))
+            .collect();
+
+        let mtl_file = file.with_extension("mtl");
+        let mtl_name = mtl_file
+            .file_name()
+            .map(|f| f.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        let obj_result = std::fs::write(
+            &file,
+            mesh_objects_obj(&mesh_objects, &materials, &mtl_name),
+        );
+        if let Err(e) = &obj_result {
+            error!("Failed to save {:?}: {}", file, e);
+        }
+        let mtl_result = std::fs::write(&mtl_file, materials_mtl(&materials));
+        if let Err(e) = &mtl_result {
+            error!("Failed to save {:?}: {}", mtl_file, e);
+        }
+        if obj_result.is_ok() && mtl_result.is_ok() {
+            show_toast(ctx, ToastLevel::Info, format!("Saved OBJ to {file:?}"));
+        }
+    }
+}
+
+/// The assigned material label and Texture0 name using the model.numdlb and model.numatb.
+fn mesh_object_material(
+    mesh_object: &MeshObjectData,
+    modl: Option<&ModlData>,
+    matl: Option<&MatlData>,
+) -> (String, Option<String>) {
+    let label = modl
+        .and_then(|modl| {
+            modl.entries.iter().find(|e| {
+                e.mesh_object_name == mesh_object.name
+                    && e.mesh_object_subindex == mesh_object.subindex
+            })
+        })
+        .map(|e| e.material_label.clone())
+        .unwrap_or_default();
+
+    let texture = matl
+        .and_then(|matl| matl.entries.iter().find(|e| e.material_label == label))
+        .and_then(|e| e.textures.iter().find(|t| t.param_id == ParamId::Texture0))
+        .map(|t| t.data.clone());
+
+    (label, texture)
+}
+
+/// Write the positions, normals, and first UV map for `mesh_objects` as a Wavefront OBJ.
+/// Each mesh object is a separate group using the corresponding label in `materials`.
+fn mesh_objects_obj(
+    mesh_objects: &[&MeshObjectData],
+    materials: &[(String, Option<String>)],
+    mtl_name: &str,
+) -> String {
+    let mut obj = format!("mtllib {mtl_name}\n");
+
+    // OBJ indices are global and start from 1.
+    let mut position_offset = 1;
+    let mut normal_offset = 1;
+    let mut uv_offset = 1;
+
+    for (mesh_object, (label, _)) in mesh_objects.iter().zip(materials) {
+        let positions = mesh_object
+            .positions
+            .first()
+            .map(|a| vector3_values(&a.data))
+            .unwrap_or_default();
+        let normals = mesh_object
+            .normals
+            .first()
+            .map(|a| vector3_values(&a.data))
+            .unwrap_or_default();
+        let uvs = match mesh_object.texture_coordinates.first().map(|a| &a.data) {
+            Some(VectorData::Vector2(v)) => v.clone(),
+            _ => Vec::new(),
+        };
+
+        obj += &format!("g {}_{}\n", mesh_object.name, mesh_object.subindex);
+        obj += &format!("usemtl {label}\n");
+        for [x, y, z] in &positions {
+            obj += &format!("v {x} {y} {z}\n");
+        }
+        for [x, y, z] in &normals {
+            obj += &format!("vn {x} {y} {z}\n");
+        }
+        for [u, v] in &uvs {
+            // Flip the V coordinate since OBJ places the origin at the bottom left.
+            obj += &format!("vt {u} {}\n", 1.0 - v);
+        }
+
+        for face in mesh_object.vertex_indices.chunks_exact(3) {
+            let vertices: Vec<_> = face
+                .iter()
+                .map(|i| {
+                    let i = *i as usize;
+                    let position = i + position_offset;
+                    match (!uvs.is_empty(), !normals.is_empty()) {
+                        (true, true) => {
+                            format!("{}/{}/{}", position, i + uv_offset, i + normal_offset)
+                        }
+                        (true, false) => format!("{}/{}", position, i + uv_offset),
+                        (false, true) => format!("{}//{}", position, i + normal_offset),
+                        (false, false) => position.to_string(),
+                    }
+                })
+                .collect();
+            obj += &format!("f {}\n", vertices.join(" "));
+        }
+
+        position_offset += positions.len();
+        normal_offset += normals.len();
+        uv_offset += uvs.len();
+    }
+
+    obj
+}
+
+fn vector3_values(data: &VectorData) -> Vec<[f32; 3]> {
+    match data {
+        VectorData::Vector2(v) => v.iter().map(|[x, y]| [*x, *y, 0.0]).collect(),
+        VectorData::Vector3(v) => v.clone(),
+        VectorData::Vector4(v) => v.iter().map(|[x, y, z, _]| [*x, *y, *z]).collect(),
+    }
+}
+
+/// Write a material for each unique label referencing the PNG exported from its Texture0.
+fn materials_mtl(materials: &[(String, Option<String>)]) -> String {
+    let mut mtl = String::new();
+    let mut labels = Vec::new();
+    for (label, texture) in materials {
+        if labels.contains(&label) {
+            continue;
+        }
+        labels.push(label);
+
+        mtl += &format!("newmtl {label}\n");
+        mtl += "Kd 1.0 1.0 1.0\n";
+        if let Some(texture) = texture {
+            mtl += &format!("map_Kd {texture}.png\n");
+        }
+    }
+    mtl
+}
+
 fn match_mesh_order(mesh: &mut MeshData, reference: &MeshData) {
     mesh.objects.sort_by_key(|o| {
         // The sort is stable, so unmatched objects will be placed at the end in the same order.
@@ -644,4 +1323,304 @@ mod tests {
         assert_eq!("a", mesh.objects[2].name);
         assert_eq!(0, mesh.objects[2].subindex);
     }
+
+    #[test]
+    fn mesh_objects_obj_attributes() {
+        let mesh_object = MeshObjectData {
+            name: "a".to_owned(),
+            subindex: 1,
+            vertex_indices: vec![0, 1, 2],
+            positions: vec![AttributeData {
+                name: "Position0".to_owned(),
+                data: VectorData::Vector3(vec![[0.0; 3], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]),
+            }],
+            normals: vec![AttributeData {
+                name: "Normal0".to_owned(),
+                data: VectorData::Vector4(vec![[0.0, 0.0, 1.0, 0.0]; 3]),
+            }],
+            texture_coordinates: vec![AttributeData {
+                name: "map1".to_owned(),
+                data: VectorData::Vector2(vec![[0.0, 0.0], [1.0, 0.0], [0.0, 0.25]]),
+            }],
+            ..Default::default()
+        };
+        let materials = vec![("mat".to_owned(), None)];
+
+        assert_eq!(
+            "mtllib a.mtl\n\
+             g a_1\n\
+             usemtl mat\n\
+             v 0 0 0\n\
+             v 1 0 0\n\
+             v 0 1 0\n\
+             vn 0 0 1\n\
+             vn 0 0 1\n\
+             vn 0 0 1\n\
+             vt 0 1\n\
+             vt 1 1\n\
+             vt 0 0.75\n\
+             f 1/1/1 2/2/2 3/3/3\n",
+            mesh_objects_obj(&[&mesh_object], &materials, "a.mtl")
+        );
+    }
+
+    #[test]
+    fn vertex_buffer_columns_attributes() {
+        let mesh_object = MeshObjectData {
+            positions: vec![AttributeData {
+                name: "Position0".to_owned(),
+                data: VectorData::Vector3(vec![[1.0, 2.0, 3.0]]),
+            }],
+            texture_coordinates: vec![AttributeData {
+                name: "map1".to_owned(),
+                data: VectorData::Vector2(vec![[0.5, 0.25]]),
+            }],
+            ..Default::default()
+        };
+
+        let columns = vertex_buffer_columns(&mesh_object);
+        assert_eq!(
+            vec![
+                "Position0.x",
+                "Position0.y",
+                "Position0.z",
+                "map1.x",
+                "map1.y"
+            ],
+            columns
+                .iter()
+                .map(|(n, _, _)| n.as_str())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![Some(1.0), Some(2.0), Some(3.0), Some(0.5), Some(0.25)],
+            columns
+                .iter()
+                .map(|(_, d, c)| vector_component(d, 0, *c))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(None, vector_component(columns[0].1, 1, 0));
+    }
+
+    #[test]
+    fn write_vertex_buffer_csv_attributes() {
+        let mesh_object = MeshObjectData {
+            positions: vec![AttributeData {
+                name: "Position0".to_owned(),
+                data: VectorData::Vector3(vec![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]),
+            }],
+            color_sets: vec![AttributeData {
+                name: "colorSet1".to_owned(),
+                data: VectorData::Vector4(vec![[0.5; 4]; 2]),
+            }],
+            ..Default::default()
+        };
+
+        let mut csv = Vec::new();
+        write_vertex_buffer_csv(&mut csv, &mesh_object).unwrap();
+        assert_eq!(
+            "Position0.x,Position0.y,Position0.z,colorSet1.x,colorSet1.y,colorSet1.z,colorSet1.w\n\
+             1,2,3,0.5,0.5,0.5,0.5\n\
+             4,5,6,0.5,0.5,0.5,0.5\n",
+            String::from_utf8(csv).unwrap()
+        );
+    }
+
+    #[test]
+    fn mesh_triangles_out_of_bounds() {
+        let mesh_object = MeshObjectData {
+            vertex_indices: vec![0, 1, 2, 2, 1, 3, 0],
+            ..Default::default()
+        };
+
+        let triangles = mesh_triangles(&mesh_object);
+        assert_eq!(vec![[0, 1, 2], [2, 1, 3]], triangles);
+        assert!(!is_out_of_bounds(&triangles[0], 3));
+        assert!(is_out_of_bounds(&triangles[1], 3));
+    }
+
+    #[test]
+    fn index_buffer_sections_match_after_index_changes() {
+        let mut mesh_object = MeshObjectData {
+            name: "a".to_owned(),
+            subindex: 1,
+            vertex_indices: vec![0, 1, 2],
+            ..Default::default()
+        };
+
+        let sections = vec![index_buffer_section(&mesh_object)];
+        assert_eq!(1, sections[0].subindex);
+        assert_eq!(1, sections[0].invalid_count);
+        assert!(sections_match(&sections, &[&mesh_object]));
+
+        mesh_object.vertex_indices.extend([2, 1, 0]);
+        assert!(!sections_match(&sections, &[&mesh_object]));
+        assert!(!sections_match(&sections, &[]));
+    }
+
+    #[test]
+    fn paint_vertex_colors_radius() {
+        let mut mesh = MeshData {
+            major_version: 1,
+            minor_version: 10,
+            objects: vec![MeshObjectData {
+                name: "a".to_owned(),
+                subindex: 0,
+                vertex_indices: vec![0, 1, 3, 1, 2, 3],
+                positions: vec![AttributeData {
+                    name: "Position0".to_owned(),
+                    data: VectorData::Vector3(vec![
+                        [0.0; 3],
+                        [0.5, 0.0, 0.0],
+                        [4.0, 0.0, 0.0],
+                        [0.0, 0.5, 0.0],
+                    ]),
+                }],
+                color_sets: vec![AttributeData {
+                    name: "colorSet1".to_owned(),
+                    data: VectorData::Vector4(vec![[0.0; 4]; 4]),
+                }],
+                ..Default::default()
+            }],
+        };
+
+        let paint_mode = PaintMode {
+            radius: 1.0,
+            color: [255; 4],
+            strength: 1.0,
+        };
+        assert!(paint_vertex_colors(
+            &mut mesh,
+            &[("a".to_owned(), 0)],
+            glam::vec3(0.0, 0.0, 10.0),
+            glam::Vec3::NEG_Z,
+            &paint_mode
+        ));
+
+        // The far vertex is outside the radius.
+        assert_eq!(
+            VectorData::Vector4(vec![[1.0; 4], [0.5; 4], [0.0; 4], [0.5; 4]]),
+            mesh.objects[0].color_sets[0].data
+        );
+    }
+
+    #[test]
+    fn paint_vertex_colors_occluded_mesh() {
+        let object = |name: &str, z: f32| MeshObjectData {
+            name: name.to_owned(),
+            subindex: 0,
+            vertex_indices: vec![0, 1, 2],
+            positions: vec![AttributeData {
+                name: "Position0".to_owned(),
+                data: VectorData::Vector3(vec![[-1.0, -1.0, z], [1.0, -1.0, z], [0.0, 1.0, z]]),
+            }],
+            color_sets: vec![AttributeData {
+                name: "colorSet1".to_owned(),
+                data: VectorData::Vector4(vec![[0.0; 4]; 3]),
+            }],
+            ..Default::default()
+        };
+        let mut mesh = MeshData {
+            major_version: 1,
+            minor_version: 10,
+            objects: vec![object("back", -1.0), object("front", 0.0)],
+        };
+
+        let paint_mode = PaintMode {
+            radius: 2.0,
+            color: [255; 4],
+            strength: 1.0,
+        };
+        assert!(paint_vertex_colors(
+            &mut mesh,
+            &[("back".to_owned(), 0), ("front".to_owned(), 0)],
+            glam::vec3(0.0, 0.0, 10.0),
+            glam::Vec3::NEG_Z,
+            &paint_mode
+        ));
+
+        // Only the triangle closest to the camera is painted.
+        assert_eq!(
+            VectorData::Vector4(vec![[0.0; 4]; 3]),
+            mesh.objects[0].color_sets[0].data
+        );
+        assert_ne!(
+            VectorData::Vector4(vec![[0.0; 4]; 3]),
+            mesh.objects[1].color_sets[0].data
+        );
+    }
+
+    #[test]
+    fn paint_vertex_colors_miss() {
+        let mut mesh = MeshData {
+            major_version: 1,
+            minor_version: 10,
+            objects: vec![MeshObjectData {
+                name: "a".to_owned(),
+                subindex: 0,
+                vertex_indices: vec![0, 1, 2],
+                positions: vec![AttributeData {
+                    name: "Position0".to_owned(),
+                    data: VectorData::Vector3(vec![[0.0; 3], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]),
+                }],
+                color_sets: vec![AttributeData {
+                    name: "colorSet1".to_owned(),
+                    data: VectorData::Vector4(vec![[0.0; 4]; 3]),
+                }],
+                ..Default::default()
+            }],
+        };
+
+        // Vertices near the ray aren't painted without hitting a triangle.
+        assert!(!paint_vertex_colors(
+            &mut mesh,
+            &[("a".to_owned(), 0)],
+            glam::vec3(-0.1, -0.1, 10.0),
+            glam::Vec3::NEG_Z,
+            &PaintMode::default()
+        ));
+    }
+
+    #[test]
+    fn paint_vertex_colors_hidden_mesh() {
+        let mut mesh = MeshData {
+            major_version: 1,
+            minor_version: 10,
+            objects: vec![MeshObjectData {
+                name: "a".to_owned(),
+                subindex: 0,
+                vertex_indices: vec![0, 1, 2],
+                positions: vec![AttributeData {
+                    name: "Position0".to_owned(),
+                    data: VectorData::Vector3(vec![[0.0; 3], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]),
+                }],
+                color_sets: vec![AttributeData {
+                    name: "colorSet1".to_owned(),
+                    data: VectorData::Vector4(vec![[0.0; 4]; 3]),
+                }],
+                ..Default::default()
+            }],
+        };
+
+        assert!(!paint_vertex_colors(
+            &mut mesh,
+            &[],
+            glam::vec3(0.1, 0.1, 10.0),
+            glam::Vec3::NEG_Z,
+            &PaintMode::default()
+        ));
+    }
+
+    #[test]
+    fn materials_mtl_unique_labels() {
+        let materials = vec![
+            ("a".to_owned(), Some("def_mario_001_col".to_owned())),
+            ("b".to_owned(), None),
+            ("a".to_owned(), Some("def_mario_001_col".to_owned())),
+        ];
+        assert_eq!(
+            "newmtl a\nKd 1.0 1.0 1.0\nmap_Kd def_mario_001_col.png\nnewmtl b\nKd 1.0 1.0 1.0\n",
+            materials_mtl(&materials)
+        );
+    }
 }
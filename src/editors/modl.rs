@@ -4,6 +4,7 @@ use crate::{
     path::folder_editor_title,
     save_file, save_file_as,
     validation::{ModlValidationError, ModlValidationErrorKind},
+    widgets::tooltip_text,
     EditorMessage, EditorResponse,
 };
 use egui::{special_emojis::GITHUB, Grid, Label, RichText, ScrollArea, TextEdit};
@@ -310,19 +311,31 @@ fn edit_modl_file_names(ui: &mut egui::Ui, modl: &mut ModlData) -> bool {
     ui.heading("Model Files");
     Grid::new("modl_files_grid").show(ui, |ui| {
         let size = [125.0, 20.0];
-        ui.label("Model Name");
+        tooltip_text(
+            ui,
+            "Model Name",
+            "The name of the model. This is not used in game.",
+        );
         changed |= ui
             .add_sized(size, TextEdit::singleline(&mut modl.model_name))
             .changed();
         ui.end_row();
 
-        ui.label("Skeleton File Name");
+        tooltip_text(
+            ui,
+            "Skeleton File Name",
+            "The .nusktb file used for the model.",
+        );
         changed |= ui
             .add_sized(size, TextEdit::singleline(&mut modl.skeleton_file_name))
             .changed();
         ui.end_row();
 
-        ui.label("Material File Names");
+        tooltip_text(
+            ui,
+            "Material File Names",
+            "The .numatb files used for the model.",
+        );
         for file_name in &mut modl.material_file_names {
             changed |= ui
                 .add_sized(size, TextEdit::singleline(file_name))
@@ -330,7 +343,11 @@ fn edit_modl_file_names(ui: &mut egui::Ui, modl: &mut ModlData) -> bool {
         }
         ui.end_row();
 
-        ui.label("Animation File Name");
+        tooltip_text(
+            ui,
+            "Animation File Name",
+            "The optional .nuanmb file used for the model.",
+        );
         if let Some(file_name) = modl.animation_file_name.as_mut() {
             changed |= ui
                 .add_sized(size, TextEdit::singleline(file_name))
@@ -345,7 +362,7 @@ fn edit_modl_file_names(ui: &mut egui::Ui, modl: &mut ModlData) -> bool {
         }
         ui.end_row();
 
-        ui.label("Mesh File Name");
+        tooltip_text(ui, "Mesh File Name", "The .numshb file used for the model.");
         changed |= ui
             .add_sized(size, TextEdit::singleline(&mut modl.mesh_file_name))
             .changed();
@@ -1,21 +1,77 @@
-use crate::{horizontal_separator_empty, RenderState};
+use std::path::{Path, PathBuf};
+
+use crate::{
+    app::NutexbViewerState,
+    horizontal_separator_empty,
+    path::folder_editor_title,
+    widgets::{dialog_window, show_toast, ToastLevel},
+    EditorResponse, RenderState,
+};
 use egui::{special_emojis::GITHUB, ComboBox, DragValue, Slider};
 use egui_wgpu::CallbackTrait;
-use nutexb::{NutexbFile, NutexbFormat};
+use log::{error, warn};
+use nutexb::{NutexbFile, NutexbFormat, Surface};
 use nutexb_wgpu::RenderSettings;
+use rfd::FileDialog;
 
 pub fn nutexb_viewer(
     ctx: &egui::Context,
-    title: &str,
-    nutexb: &NutexbFile,
+    folder_name: &Path,
+    file_name: &str,
+    nutexb: &mut NutexbFile,
     settings: &mut RenderSettings,
-) -> bool {
+    state: &mut NutexbViewerState,
+) -> EditorResponse {
     let mut open = true;
+    let mut changed = false;
+    let mut saved = false;
+
+    let title = folder_editor_title(folder_name, file_name);
     egui::Window::new(format!("Nutexb Viewer ({title})"))
         .open(&mut open)
         .resizable(false)
         .show(ctx, |ui| {
             egui::menu::bar(ui, |ui| {
+                ui.menu_button("File", |ui| {
+                    if ui.button("Save").clicked() {
+                        ui.close_menu();
+                        saved |= save_nutexb(nutexb, &folder_name.join(file_name));
+                    }
+
+                    if ui.button("Save As...").clicked() {
+                        ui.close_menu();
+                        if let Some(file) = FileDialog::new()
+                            .set_directory(folder_name)
+                            .set_file_name(file_name)
+                            .add_filter("Nutexb", &["nutexb"])
+                            .save_file()
+                        {
+                            saved |= save_nutexb(nutexb, &file);
+                        }
+                    }
+                    ui.separator();
+
+                    if ui
+                        .button("Import PNG as Nutexb...")
+                        .on_hover_text(
+                            "Replace the image data and generate mipmaps from a PNG file.",
+                        )
+                        .clicked()
+                    {
+                        ui.close_menu();
+                        if let Some(file) = FileDialog::new().add_filter("PNG", &["png"]).pick_file()
+                        {
+                            let format = nutexb.footer.image_format;
+                            if import_format(format) != format {
+                                // Compressed formats can't be encoded, so confirm the new format first.
+                                state.png_to_import = Some(file);
+                            } else {
+                                changed |= import_png(ctx, nutexb, &file);
+                            }
+                        }
+                    }
+                });
+
                 ui.menu_button("Help", |ui| {
                     if ui.button(format!("{GITHUB} Nutexb Editor Wiki")).clicked() {
                         ui.close_menu();
@@ -65,6 +121,11 @@ pub fn nutexb_viewer(
                 ui.label("Data Size");
                 ui.label(nutexb.footer.data_size.to_string());
                 ui.end_row();
+
+                ui.label("Estimated VRAM")
+                    .on_hover_text("The size of all layers and mipmaps on the GPU.");
+                ui.label(format_bytes(estimated_vram_size(nutexb)));
+                ui.end_row();
             });
             horizontal_separator_empty(ui);
 
@@ -119,6 +180,15 @@ pub fn nutexb_viewer(
                     ui.label("Depth");
                     ui.add(DragValue::new(&mut settings.layer).range(0..=nutexb.footer.depth - 1));
                 }
+
+                if ui
+                    .button("Fit")
+                    .on_hover_text("Reset the zoom and pan. Scroll to zoom and drag with the middle mouse button to pan.")
+                    .clicked()
+                {
+                    state.zoom = None;
+                    state.pan = egui::Vec2::ZERO;
+                }
             });
 
             egui::Frame::canvas(ui.style()).show(ui, |ui| {
@@ -136,13 +206,238 @@ pub fn nutexb_viewer(
                     )
                 };
 
-                let (_, rect) = ui.allocate_space(dimensions);
+                let (canvas_rect, response) =
+                    ui.allocate_exact_size(dimensions, egui::Sense::drag());
+
+                // Scale in points per texel with the fit scale as the default.
+                let texture_size =
+                    egui::vec2(nutexb.footer.width as f32, nutexb.footer.height as f32);
+                let fit_scale = dimensions.x / texture_size.x.max(1.0);
+                let scale = state.zoom.unwrap_or(fit_scale);
 
+                if let Some(pos) = response.hover_pos() {
+                    let scroll = ui.input(|i| i.smooth_scroll_delta.y);
+                    if scroll != 0.0 {
+                        // Zoom towards the cursor.
+                        let new_scale = (scale * (scroll / 200.0).exp()).clamp(0.01, 256.0);
+                        let factor = new_scale / scale;
+                        let offset = pos - canvas_rect.center();
+                        state.pan = offset - (offset - state.pan) * factor;
+                        state.zoom = Some(new_scale);
+                    }
+                }
+                if response.dragged_by(egui::PointerButton::Middle) {
+                    state.pan += response.drag_delta();
+                }
+                // egui_wgpu clamps the callback viewport to the screen,
+                // so keep the texture on screen to avoid squashing it when zoomed in.
+                let screen_rect = ui.ctx().screen_rect();
+                let max_scale = (screen_rect.size() / texture_size.max(egui::Vec2::splat(1.0)))
+                    .min_elem()
+                    .max(fit_scale);
+                if let Some(zoom) = &mut state.zoom {
+                    *zoom = zoom.min(max_scale);
+                }
+                let scale = state.zoom.unwrap_or(fit_scale);
+
+                let rect = clamp_to_screen(
+                    egui::Rect::from_center_size(
+                        canvas_rect.center() + state.pan,
+                        texture_size * scale,
+                    ),
+                    screen_rect,
+                );
+                state.pan = rect.center() - canvas_rect.center();
+
+                let painter = ui.painter_at(canvas_rect);
                 let cb = egui_wgpu::Callback::new_paint_callback(rect, PaintTextureCallback);
-                ui.painter().add(cb);
+                painter.add(cb);
+
+                // 100% zoom shows one texel per physical pixel.
+                let zoom_percent = scale * ui.ctx().pixels_per_point() * 100.0;
+                painter.text(
+                    canvas_rect.right_top() + egui::vec2(-4.0, 4.0),
+                    egui::Align2::RIGHT_TOP,
+                    format!("{zoom_percent:.0}%"),
+                    egui::FontId::monospace(12.0),
+                    ui.visuals().strong_text_color(),
+                );
+
+                if let Some(pos) = response.hover_pos() {
+                    let uv = (pos - rect.min) / rect.size();
+                    let layer = settings.layer as usize;
+                    if let Some((x, y, pixel)) = hovered_pixel(&state.decoded_images, layer, uv) {
+                        response.on_hover_ui_at_pointer(|ui| {
+                            ui.label(format!("({x}, {y})"));
+                            ui.label(format!(
+                                "RGBA: {}, {}, {}, {}",
+                                pixel[0], pixel[1], pixel[2], pixel[3]
+                            ));
+                        });
+                    }
+                }
             });
         });
-    open
+
+    changed |= import_png_window(ctx, nutexb, &mut state.png_to_import);
+
+    EditorResponse {
+        open,
+        changed,
+        saved,
+        message: None,
+    }
+}
+
+fn import_png_window(
+    ctx: &egui::Context,
+    nutexb: &mut NutexbFile,
+    png_to_import: &mut Option<PathBuf>,
+) -> bool {
+    let mut changed = false;
+    let format = nutexb.footer.image_format;
+    dialog_window(ctx, "Import PNG", png_to_import, |ui, file| {
+        ui.label(format!(
+            "{} encoding is not supported. Import {:?} as uncompressed {} instead?",
+            format_name(format),
+            file,
+            format_name(import_format(format))
+        ));
+        ui.label("Uncompressed textures use more memory than the original format.");
+        horizontal_separator_empty(ui);
+
+        ui.horizontal(|ui| {
+            if ui.button("Import").clicked() {
+                changed = import_png(ctx, nutexb, file);
+                true
+            } else {
+                ui.button("Cancel").clicked()
+            }
+        })
+        .inner
+    });
+    changed
+}
+
+fn save_nutexb(nutexb: &NutexbFile, file: &Path) -> bool {
+    if let Err(e) = nutexb.write_to_file(file) {
+        error!("Failed to save {:?}: {}", file, e);
+        false
+    } else {
+        true
+    }
+}
+
+fn import_png(ctx: &egui::Context, nutexb: &mut NutexbFile, file: &Path) -> bool {
+    let footer = &nutexb.footer;
+    if footer.layer_count > 1 || footer.depth > 1 {
+        error!(
+            "Failed to import {:?}: PNG files can only replace 2D textures with a single layer",
+            file
+        );
+        return false;
+    }
+
+    let image = match image::open(file) {
+        Ok(image) => image.to_rgba8(),
+        Err(e) => {
+            error!("Failed to read {:?}: {}", file, e);
+            return false;
+        }
+    };
+
+    if (image.width(), image.height()) != (footer.width, footer.height) {
+        warn!(
+            "The dimensions of {:?} ({}x{}) do not match the nutexb ({}x{})",
+            file,
+            image.width(),
+            image.height(),
+            footer.width,
+            footer.height
+        );
+    }
+
+    let image_format = import_format(footer.image_format);
+
+    let mipmaps = generate_mipmaps(&image);
+    let surface = Surface {
+        width: image.width(),
+        height: image.height(),
+        depth: 1,
+        layers: 1,
+        mipmaps: mipmaps.len() as u32,
+        image_format,
+        data: mipmaps
+            .iter()
+            .flat_map(|mip| encode_rgba(mip, image_format))
+            .collect::<Vec<_>>(),
+    };
+
+    match NutexbFile::from_surface(surface, footer.string.to_string()) {
+        Ok(new_nutexb) => {
+            *nutexb = new_nutexb;
+            show_toast(ctx, ToastLevel::Info, format!("Imported {file:?}"));
+            true
+        }
+        Err(e) => {
+            error!("Failed to import {:?}: {}", file, e);
+            false
+        }
+    }
+}
+
+/// The closest format to `format` that can be created from uncompressed RGBA data.
+fn import_format(format: NutexbFormat) -> NutexbFormat {
+    match format {
+        NutexbFormat::R8Unorm
+        | NutexbFormat::R8G8B8A8Unorm
+        | NutexbFormat::R8G8B8A8Srgb
+        | NutexbFormat::R32G32B32A32Float
+        | NutexbFormat::B8G8R8A8Unorm
+        | NutexbFormat::B8G8R8A8Srgb => format,
+        NutexbFormat::BC1Srgb
+        | NutexbFormat::BC2Srgb
+        | NutexbFormat::BC3Srgb
+        | NutexbFormat::BC7Srgb => NutexbFormat::R8G8B8A8Srgb,
+        NutexbFormat::BC6Ufloat | NutexbFormat::BC6Sfloat => NutexbFormat::R32G32B32A32Float,
+        NutexbFormat::BC1Unorm
+        | NutexbFormat::BC2Unorm
+        | NutexbFormat::BC3Unorm
+        | NutexbFormat::BC4Unorm
+        | NutexbFormat::BC4Snorm
+        | NutexbFormat::BC5Unorm
+        | NutexbFormat::BC5Snorm
+        | NutexbFormat::BC7Unorm => NutexbFormat::R8G8B8A8Unorm,
+    }
+}
+
+/// The full mipmap chain down to 1x1 starting with `image` as the base level.
+fn generate_mipmaps(image: &image::RgbaImage) -> Vec<image::RgbaImage> {
+    let mut mipmaps = vec![image.clone()];
+    while let Some(mip) = mipmaps.last().filter(|m| m.width() > 1 || m.height() > 1) {
+        let width = (mip.width() / 2).max(1);
+        let height = (mip.height() / 2).max(1);
+        let next = image::imageops::resize(mip, width, height, image::imageops::Triangle);
+        mipmaps.push(next);
+    }
+    mipmaps
+}
+
+/// Convert RGBA8 pixels to the bytes for an uncompressed `format` from [import_format].
+fn encode_rgba(image: &image::RgbaImage, format: NutexbFormat) -> Vec<u8> {
+    match format {
+        NutexbFormat::R8Unorm => image.pixels().map(|p| p[0]).collect(),
+        NutexbFormat::B8G8R8A8Unorm | NutexbFormat::B8G8R8A8Srgb => image
+            .pixels()
+            .flat_map(|p| [p[2], p[1], p[0], p[3]])
+            .collect(),
+        NutexbFormat::R32G32B32A32Float => image
+            .pixels()
+            .flat_map(|p| p.0.map(|c| c as f32 / 255.0))
+            .flat_map(f32::to_le_bytes)
+            .collect(),
+        _ => image.as_raw().clone(),
+    }
 }
 
 struct PaintTextureCallback;
@@ -160,6 +455,94 @@ impl CallbackTrait for PaintTextureCallback {
     }
 }
 
+/// Translates `rect` to be inside `screen_rect` if `rect` fits on screen.
+fn clamp_to_screen(rect: egui::Rect, screen_rect: egui::Rect) -> egui::Rect {
+    let offset = |min: f32, max: f32, screen_min: f32, screen_max: f32| {
+        if max - min > screen_max - screen_min {
+            0.0
+        } else if min < screen_min {
+            screen_min - min
+        } else if max > screen_max {
+            screen_max - max
+        } else {
+            0.0
+        }
+    };
+    rect.translate(egui::vec2(
+        offset(rect.min.x, rect.max.x, screen_rect.min.x, screen_rect.max.x),
+        offset(rect.min.y, rect.max.y, screen_rect.min.y, screen_rect.max.y),
+    ))
+}
+
+/// The pixel coordinates and RGBA value of the base mip level at `uv` for `layer`.
+fn hovered_pixel(
+    images: &[image::RgbaImage],
+    layer: usize,
+    uv: egui::Vec2,
+) -> Option<(u32, u32, [u8; 4])> {
+    let image = images.get(layer).or_else(|| images.first())?;
+    if !(0.0..1.0).contains(&uv.x) || !(0.0..1.0).contains(&uv.y) {
+        return None;
+    }
+
+    let x = ((uv.x * image.width() as f32) as u32).min(image.width().saturating_sub(1));
+    let y = ((uv.y * image.height() as f32) as u32).min(image.height().saturating_sub(1));
+    Some((x, y, image.get_pixel_checked(x, y)?.0))
+}
+
+/// The size in bytes of the image data for all mipmaps and layers without padding.
+fn estimated_vram_size(nutexb: &NutexbFile) -> u64 {
+    let footer = &nutexb.footer;
+    let (block_size, bytes_per_block) = block_size_in_bytes(footer.image_format);
+
+    (0..footer.mipmap_count.max(1))
+        .map(|mip| {
+            let mip_dimension = |d: u32| (d >> mip).max(1).div_ceil(block_size) as u64;
+            mip_dimension(footer.width)
+                * mip_dimension(footer.height)
+                * (footer.depth >> mip).max(1) as u64
+                * bytes_per_block
+        })
+        .sum::<u64>()
+        * footer.layer_count.max(1) as u64
+}
+
+/// The block width and height in pixels and the size of a block in bytes.
+fn block_size_in_bytes(format: NutexbFormat) -> (u32, u64) {
+    match format {
+        NutexbFormat::R8Unorm => (1, 1),
+        NutexbFormat::R8G8B8A8Unorm
+        | NutexbFormat::R8G8B8A8Srgb
+        | NutexbFormat::B8G8R8A8Unorm
+        | NutexbFormat::B8G8R8A8Srgb => (1, 4),
+        NutexbFormat::R32G32B32A32Float => (1, 16),
+        NutexbFormat::BC1Unorm
+        | NutexbFormat::BC1Srgb
+        | NutexbFormat::BC4Unorm
+        | NutexbFormat::BC4Snorm => (4, 8),
+        NutexbFormat::BC2Unorm
+        | NutexbFormat::BC2Srgb
+        | NutexbFormat::BC3Unorm
+        | NutexbFormat::BC3Srgb
+        | NutexbFormat::BC5Unorm
+        | NutexbFormat::BC5Snorm
+        | NutexbFormat::BC6Ufloat
+        | NutexbFormat::BC6Sfloat
+        | NutexbFormat::BC7Unorm
+        | NutexbFormat::BC7Srgb => (4, 16),
+    }
+}
+
+fn format_bytes(bytes: u64) -> String {
+    if bytes >= 1024 * 1024 {
+        format!("{:.2} MiB", bytes as f64 / (1024.0 * 1024.0))
+    } else if bytes >= 1024 {
+        format!("{:.2} KiB", bytes as f64 / 1024.0)
+    } else {
+        format!("{bytes} bytes")
+    }
+}
+
 fn format_name(format: NutexbFormat) -> &'static str {
     match format {
         NutexbFormat::R8Unorm => "R8Unorm",
@@ -184,3 +567,76 @@ fn format_name(format: NutexbFormat) -> &'static str {
         NutexbFormat::BC7Srgb => "BC7Srgb",
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn import_format_uncompressed() {
+        assert_eq!(
+            NutexbFormat::B8G8R8A8Srgb,
+            import_format(NutexbFormat::B8G8R8A8Srgb)
+        );
+        assert_eq!(NutexbFormat::R8Unorm, import_format(NutexbFormat::R8Unorm));
+    }
+
+    #[test]
+    fn import_format_compressed() {
+        assert_eq!(
+            NutexbFormat::R8G8B8A8Srgb,
+            import_format(NutexbFormat::BC7Srgb)
+        );
+        assert_eq!(
+            NutexbFormat::R8G8B8A8Unorm,
+            import_format(NutexbFormat::BC5Snorm)
+        );
+        assert_eq!(
+            NutexbFormat::R32G32B32A32Float,
+            import_format(NutexbFormat::BC6Ufloat)
+        );
+    }
+
+    #[test]
+    fn generate_mipmaps_non_square() {
+        let image = image::RgbaImage::new(4, 2);
+        let sizes: Vec<_> = generate_mipmaps(&image)
+            .iter()
+            .map(|m| m.dimensions())
+            .collect();
+        assert_eq!(vec![(4, 2), (2, 1), (1, 1)], sizes);
+    }
+
+    #[test]
+    fn generate_mipmaps_single_pixel() {
+        let image = image::RgbaImage::new(1, 1);
+        assert_eq!(1, generate_mipmaps(&image).len());
+    }
+
+    #[test]
+    fn encode_rgba_formats() {
+        let image = image::RgbaImage::from_raw(1, 1, vec![1, 2, 3, 255]).unwrap();
+        assert_eq!(vec![1], encode_rgba(&image, NutexbFormat::R8Unorm));
+        assert_eq!(
+            vec![1, 2, 3, 255],
+            encode_rgba(&image, NutexbFormat::R8G8B8A8Unorm)
+        );
+        assert_eq!(
+            vec![3, 2, 1, 255],
+            encode_rgba(&image, NutexbFormat::B8G8R8A8Srgb)
+        );
+    }
+
+    #[test]
+    fn encode_rgba_float() {
+        let image = image::RgbaImage::from_raw(1, 1, vec![0, 51, 255, 255]).unwrap();
+        let expected: Vec<_> = [0.0f32, 0.2, 1.0, 1.0]
+            .into_iter()
+            .flat_map(f32::to_le_bytes)
+            .collect();
+        assert_eq!(
+            expected,
+            encode_rgba(&image, NutexbFormat::R32G32B32A32Float)
+        );
+    }
+}
@@ -1,14 +1,15 @@
 use std::path::Path;
 
 use crate::{
-    app::{draggable_icon, SkelEditorState, SkelMode},
+    app::{draggable_icon, CopyTransformsState, SkelEditorState, SkelMode},
+    csv::skel_csv,
     path::folder_editor_title,
-    widgets::enum_combo_box,
+    widgets::{dialog_window, enum_combo_box},
     EditorResponse,
 };
 use egui::{
-    special_emojis::GITHUB, Button, CollapsingHeader, Grid, Label, RichText, ScrollArea,
-    TextWrapMode,
+    collapsing_header::CollapsingState, special_emojis::GITHUB, Align, Button, DragValue, Grid,
+    RichText, ScrollArea, TextWrapMode,
 };
 use egui_dnd::dnd;
 use log::error;
@@ -57,6 +58,32 @@ pub fn skel_editor(
                             }
                         }
                     }
+                    ui.separator();
+
+                    if ui
+                        .add(
+                            Button::new("Export Bone List to CSV...")
+                                .wrap_mode(TextWrapMode::Extend),
+                        )
+                        .clicked()
+                    {
+                        ui.close_menu();
+
+                        let stem = Path::new(file_name)
+                            .file_stem()
+                            .map(|s| s.to_string_lossy().to_string())
+                            .unwrap_or_default();
+                        if let Some(file) = FileDialog::new()
+                            .add_filter("CSV", &["csv"])
+                            .set_directory(folder_name)
+                            .set_file_name(format!("{stem}_bones.csv"))
+                            .save_file()
+                        {
+                            if let Err(e) = std::fs::write(&file, skel_csv(skel)) {
+                                error!("Failed to save {:?}: {}", file, e);
+                            }
+                        }
+                    }
                 });
 
                 ui.menu_button("Skeleton", |ui| {
@@ -79,6 +106,26 @@ pub fn skel_editor(
                             }
                         }
                     }
+
+                    if ui
+                        .add(Button::new("Copy Transforms from...").wrap_mode(TextWrapMode::Extend))
+                        .clicked()
+                    {
+                        ui.close_menu();
+
+                        if let Some(file) = FileDialog::new()
+                            .add_filter("Skel", &["nusktb"])
+                            .pick_file()
+                        {
+                            match SkelData::from_file(&file) {
+                                Ok(source) => {
+                                    state.copy_transforms =
+                                        Some(CopyTransformsState::new(skel, source))
+                                }
+                                Err(e) => error!("Failed to read {:?}: {}", file, e),
+                            }
+                        }
+                    }
                 });
 
                 ui.menu_button("Help", |ui| {
@@ -105,19 +152,34 @@ pub fn skel_editor(
                     SkelMode::Hierarchy,
                     RichText::new("Hierarchy").heading(),
                 );
+
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    if ui
+                        .add_enabled(state.selected_bone.is_some(), Button::new("Reset Bone"))
+                        .on_hover_text(RESET_BONE_TOOLTIP)
+                        .on_disabled_hover_text("Select a bone to reset.")
+                        .clicked()
+                    {
+                        state.bone_to_reset = state.selected_bone.clone();
+                    }
+                });
             });
             ScrollArea::vertical()
                 .auto_shrink([false; 2])
                 .show(ui, |ui| match state.mode {
                     SkelMode::List => {
-                        changed |= edit_bones_list(ctx, ui, skel, dark_mode);
+                        changed |= edit_bones_list(ctx, ui, skel, state, dark_mode);
                     }
                     SkelMode::Hierarchy => {
-                        changed |= edit_bones_hierarchy(ui, skel);
+                        changed |= edit_bones_hierarchy(ui, skel, state);
                     }
                 });
+            // Only scroll once after selecting a bone from the viewport.
+            state.scroll_to_selected_bone = false;
         });
 
+    changed |= copy_transforms_window(ctx, skel, &mut state.copy_transforms);
+
     EditorResponse {
         open,
         changed,
@@ -126,10 +188,84 @@ pub fn skel_editor(
     }
 }
 
+fn copy_transforms_window(
+    ctx: &egui::Context,
+    skel: &mut SkelData,
+    copy_transforms: &mut Option<CopyTransformsState>,
+) -> bool {
+    let mut changed = false;
+    dialog_window(
+        ctx,
+        "Copy Bone Transforms",
+        copy_transforms,
+        |ui, copy_state| {
+            ui.label("Select the source bone to copy each bone's transform from.");
+            ui.label("The bone order and parents will not be changed.");
+            ui.separator();
+
+            ScrollArea::vertical().max_height(500.0).show(ui, |ui| {
+                Grid::new("copy_transforms_grid")
+                    .num_columns(3)
+                    .show(ui, |ui| {
+                        ui.label("");
+                        ui.label("Bone");
+                        ui.label("Source Bone");
+                        ui.end_row();
+
+                        for (i, (bone, (enabled, source_index))) in skel
+                            .bones
+                            .iter()
+                            .zip(copy_state.mappings.iter_mut())
+                            .enumerate()
+                        {
+                            ui.checkbox(enabled, "");
+                            ui.label(&bone.name);
+
+                            let source_name = source_index
+                                .and_then(|s| copy_state.source.bones.get(s))
+                                .map(|b| b.name.as_str())
+                                .unwrap_or("None");
+                            egui::ComboBox::from_id_salt(("copy_transform", i))
+                                .selected_text(source_name)
+                                .width(250.0)
+                                .show_ui(ui, |ui| {
+                                    ui.selectable_value(source_index, None, "None");
+                                    ui.separator();
+                                    for (s, source_bone) in
+                                        copy_state.source.bones.iter().enumerate()
+                                    {
+                                        ui.selectable_value(
+                                            source_index,
+                                            Some(s),
+                                            &source_bone.name,
+                                        );
+                                    }
+                                });
+                            ui.end_row();
+                        }
+                    });
+            });
+            ui.separator();
+
+            ui.horizontal(|ui| {
+                if ui.button("Copy").clicked() {
+                    changed = copy_state.apply(skel);
+                    true
+                } else {
+                    ui.button("Cancel").clicked()
+                }
+            })
+            .inner
+        },
+    );
+    changed
+}
+
 fn edit_bones_list(
     ctx: &egui::Context,
     ui: &mut egui::Ui,
     skel: &mut SkelData,
+    state: &mut SkelEditorState,
     dark_mode: bool,
 ) -> bool {
     let mut changed = false;
@@ -141,11 +277,14 @@ fn edit_bones_list(
     let mut items: Vec<_> = (0..skel.bones.len()).collect();
 
     let response = dnd(ui, "skel_dnd").show_custom_vec(&mut items, |ui, items, iter| {
-        Grid::new("skel_grid").num_columns(4).show(ui, |ui| {
+        Grid::new("skel_grid").num_columns(5).show(ui, |ui| {
             ui.label("");
             ui.label("Bone");
             ui.label("Parent Bone");
             ui.label("Billboard Type");
+            ui.label("World Space").on_hover_text(
+                "The bone's world position in the current frame of the enabled animations.",
+            );
             ui.end_row();
 
             for (i, item) in items.iter().enumerate() {
@@ -172,7 +311,17 @@ fn edit_bones_list(
                     });
 
                     // TODO: Highlight the selected bone on hover.
-                    ui.add(Label::new(&bone.name).sense(egui::Sense::click()));
+                    let is_selected = state.selected_bone.as_ref() == Some(&bone.name);
+                    let name_response = ui
+                        .selectable_label(is_selected, &bone.name)
+                        .on_hover_text(bone_chain_path(&other_bones, *item));
+                    if name_response.clicked() {
+                        state.selected_bone = Some(bone.name.clone());
+                    }
+                    if is_selected && state.scroll_to_selected_bone {
+                        name_response.scroll_to_me(Some(Align::Center));
+                    }
+                    name_response.context_menu(|ui| reset_bone_menu(ui, &bone.name, state));
 
                     let id = egui::Id::new("bone").with(item);
                     let parent_bone_name = bone
@@ -205,6 +354,20 @@ fn edit_bones_list(
 
                     changed |= enum_combo_box(ui, id.with("billboard"), &mut bone.billboard_type);
 
+                    // Copy the values since the positions are calculated and not editable.
+                    let mut position = state
+                        .world_positions
+                        .get(*item)
+                        .copied()
+                        .unwrap_or_default();
+                    ui.horizontal(|ui| {
+                        ui.add_enabled_ui(false, |ui| {
+                            ui.add(DragValue::new(&mut position.x).max_decimals(3));
+                            ui.add(DragValue::new(&mut position.y).max_decimals(3));
+                            ui.add(DragValue::new(&mut position.z).max_decimals(3));
+                        });
+                    });
+
                     ui.end_row();
 
                     response
@@ -241,39 +404,199 @@ fn move_bone(from: usize, to: usize, bones: &[BoneData]) -> Vec<BoneData> {
         .collect()
 }
 
-fn edit_bones_hierarchy(ui: &mut egui::Ui, skel: &mut SkelData) -> bool {
+fn edit_bones_hierarchy(
+    ui: &mut egui::Ui,
+    skel: &mut SkelData,
+    state: &mut SkelEditorState,
+) -> bool {
     let changed = false;
 
+    // Expand the parents of a bone selected in the viewport to make it visible.
+    let expanded_bones = if state.scroll_to_selected_bone {
+        selected_bone_parents(skel, state.selected_bone.as_deref())
+    } else {
+        Vec::new()
+    };
+
     for (i, bone) in skel.bones.iter().enumerate() {
         if bone.parent_index.is_none() {
-            display_bones_recursive(ui, i, &skel.bones);
+            display_bones_recursive(ui, i, &skel.bones, state, &expanded_bones);
         }
     }
 
     changed
 }
 
-fn display_bones_recursive(ui: &mut egui::Ui, root_index: usize, bones: &[BoneData]) {
+const RESET_BONE_TOOLTIP: &str =
+    "Set the bone's transform in the current frame of the enabled animations to the skel transform.";
+
+fn reset_bone_menu(ui: &mut egui::Ui, bone_name: &str, state: &mut SkelEditorState) {
+    if ui
+        .button("Reset Bone")
+        .on_hover_text(RESET_BONE_TOOLTIP)
+        .clicked()
+    {
+        ui.close_menu();
+        state.bone_to_reset = Some(bone_name.to_owned());
+    }
+}
+
+fn selected_bone_parents(skel: &SkelData, selected_bone: Option<&str>) -> Vec<usize> {
+    let mut parents = Vec::new();
+
+    let mut parent_index = skel
+        .bones
+        .iter()
+        .find(|b| Some(b.name.as_str()) == selected_bone)
+        .and_then(|b| b.parent_index);
+    // Limit the iterations in case of cycles.
+    while let Some(i) = parent_index.filter(|_| parents.len() < skel.bones.len()) {
+        parents.push(i);
+        parent_index = skel.bones.get(i).and_then(|b| b.parent_index);
+    }
+
+    parents
+}
+
+/// The names from the root bone to the bone at `index` like `Trans > Rot > Hip`.
+fn bone_chain_path(bones: &[BoneData], index: usize) -> String {
+    let mut names = Vec::new();
+
+    let mut current = Some(index);
+    // Limit the iterations in case of cycles.
+    while let Some(i) = current.filter(|_| names.len() < bones.len()) {
+        let Some(bone) = bones.get(i) else {
+            break;
+        };
+        names.push(bone.name.as_str());
+        current = bone.parent_index;
+    }
+
+    names.reverse();
+    names.join(" > ")
+}
+
+fn display_bones_recursive(
+    ui: &mut egui::Ui,
+    root_index: usize,
+    bones: &[BoneData],
+    state: &mut SkelEditorState,
+    expanded_bones: &[usize],
+) {
     // TODO: Does this handle cycles?
     // Don't assume bone names are unique.
     let name = &bones[root_index].name;
     let id = ui.make_persistent_id("skel").with(name).with(root_index);
 
-    CollapsingHeader::new(name)
-        .id_salt(id)
-        .default_open(true)
-        .show(ui, |ui| {
+    let mut collapsing = CollapsingState::load_with_default_open(ui.ctx(), id, true);
+    if expanded_bones.contains(&root_index) {
+        collapsing.set_open(true);
+    }
+
+    let is_selected = state.selected_bone.as_ref() == Some(name);
+    collapsing
+        .show_header(ui, |ui| {
+            let response = ui
+                .selectable_label(is_selected, name)
+                .on_hover_text(bone_chain_path(bones, root_index));
+            if response.clicked() {
+                state.selected_bone = Some(name.clone());
+            }
+            if is_selected && state.scroll_to_selected_bone {
+                response.scroll_to_me(Some(Align::Center));
+            }
+            response.context_menu(|ui| reset_bone_menu(ui, name, state));
+        })
+        .body(|ui| {
             // Recursively iterate over the child bones.
             for (i, _) in bones
                 .iter()
                 .enumerate()
                 .filter(|(_, b)| b.parent_index == Some(root_index))
             {
-                display_bones_recursive(ui, i, bones);
+                display_bones_recursive(ui, i, bones, state, expanded_bones);
             }
         });
 }
 
+/// Calculate the world position of each bone by accumulating the `local_transforms` of its parents.
+pub fn bone_world_positions(skel: &SkelData, local_transforms: &[glam::Mat4]) -> Vec<glam::Vec3> {
+    bone_world_transforms(skel, local_transforms)
+        .iter()
+        .map(|t| t.col(3).truncate())
+        .collect()
+}
+
+/// Calculate the world transform of each bone by accumulating the `local_transforms` of its parents.
+pub fn bone_world_transforms(skel: &SkelData, local_transforms: &[glam::Mat4]) -> Vec<glam::Mat4> {
+    (0..skel.bones.len())
+        .map(|i| {
+            let mut transform = local_transforms.get(i).copied().unwrap_or_default();
+
+            // Limit the iterations in case of cycles.
+            let mut parent_index = skel.bones[i].parent_index;
+            let mut depth = 0;
+            while let Some(p) = parent_index.filter(|_| depth < skel.bones.len()) {
+                transform = local_transforms.get(p).copied().unwrap_or_default() * transform;
+                parent_index = skel.bones.get(p).and_then(|b| b.parent_index);
+                depth += 1;
+            }
+
+            transform
+        })
+        .collect()
+}
+
+impl CopyTransformsState {
+    pub fn new(skel: &SkelData, source: SkelData) -> Self {
+        let mappings = skel
+            .bones
+            .iter()
+            .map(|bone| {
+                let source_index = match_bone_name(&bone.name, &source.bones);
+                (source_index.is_some(), source_index)
+            })
+            .collect();
+        Self { source, mappings }
+    }
+
+    /// Copy the transforms for the enabled mappings and return `true` if any bones changed.
+    fn apply(&self, skel: &mut SkelData) -> bool {
+        let mut changed = false;
+        for (bone, (enabled, source_index)) in skel.bones.iter_mut().zip(&self.mappings) {
+            if let Some(source) = source_index
+                .filter(|_| *enabled)
+                .and_then(|s| self.source.bones.get(s))
+            {
+                if bone.transform != source.transform {
+                    bone.transform = source.transform;
+                    changed = true;
+                }
+            }
+        }
+        changed
+    }
+}
+
+/// Find the source bone with the closest name to `name`.
+/// Names match if they are identical after ignoring case and separators like `_`.
+fn match_bone_name(name: &str, source_bones: &[BoneData]) -> Option<usize> {
+    let normalize = |n: &str| -> String {
+        n.chars()
+            .filter(|c| c.is_alphanumeric())
+            .map(|c| c.to_ascii_lowercase())
+            .collect()
+    };
+
+    source_bones
+        .iter()
+        .position(|b| b.name == name)
+        .or_else(|| {
+            let name = normalize(name);
+            source_bones.iter().position(|b| normalize(&b.name) == name)
+        })
+}
+
 fn match_skel_order(skel: &mut SkelData, reference: &SkelData) {
     // TODO: Sort by helper bones, swing bones, etc for added bones?
     // TODO: This won't correctly handle added bones.
@@ -292,6 +615,91 @@ mod tests {
     use ssbh_data::skel_data::{BillboardType, BoneData};
 
     use super::*;
+    use crate::test_bone;
+
+    #[test]
+    fn bone_world_positions_parent_chain() {
+        let skel = SkelData {
+            major_version: 1,
+            minor_version: 0,
+            bones: vec![
+                test_bone("a", None),
+                test_bone("b", Some(0)),
+                test_bone("c", Some(1)),
+            ],
+        };
+        let local_transforms = [
+            glam::Mat4::from_translation(glam::vec3(1.0, 0.0, 0.0)),
+            glam::Mat4::from_rotation_z(90f32.to_radians()),
+            glam::Mat4::from_translation(glam::vec3(2.0, 0.0, 0.0)),
+        ];
+
+        let positions = bone_world_positions(&skel, &local_transforms);
+        assert_eq!(glam::vec3(1.0, 0.0, 0.0), positions[0]);
+        assert_eq!(glam::vec3(1.0, 0.0, 0.0), positions[1]);
+        assert!(positions[2].abs_diff_eq(glam::vec3(1.0, 2.0, 0.0), 1e-6));
+    }
+
+    #[test]
+    fn bone_chain_path_cycle() {
+        let bones = vec![
+            test_bone("Trans", None),
+            test_bone("Rot", Some(0)),
+            test_bone("Hip", Some(1)),
+            test_bone("a", Some(4)),
+            test_bone("b", Some(3)),
+        ];
+
+        assert_eq!("Trans", bone_chain_path(&bones, 0));
+        assert_eq!("Trans > Rot > Hip", bone_chain_path(&bones, 2));
+        assert_eq!("a > b > a > b > a", bone_chain_path(&bones, 3));
+    }
+
+    #[test]
+    fn bone_list_csv_parents() {
+        let skel = SkelData {
+            major_version: 1,
+            minor_version: 0,
+            bones: vec![
+                BoneData {
+                    name: "Trans".to_owned(),
+                    transform: glam::Mat4::IDENTITY.to_cols_array_2d(),
+                    parent_index: None,
+                    billboard_type: BillboardType::Disabled,
+                },
+                BoneData {
+                    name: "Rot".to_owned(),
+                    transform: glam::Mat4::from_translation(glam::vec3(1.0, 2.0, 3.0))
+                        .to_cols_array_2d(),
+                    parent_index: Some(0),
+                    billboard_type: BillboardType::Disabled,
+                },
+            ],
+        };
+
+        assert_eq!(
+            "index,name,parent_index,parent_name,tx,ty,tz,rx,ry,rz,rw,sx,sy,sz\n\
+             0,Trans,-1,root,0,0,0,0,0,0,1,1,1,1\n\
+             1,Rot,0,Trans,1,2,3,0,0,0,1,1,1,1\n",
+            skel_csv(&skel)
+        );
+    }
+
+    #[test]
+    fn match_bone_names() {
+        let bones = vec![
+            test_bone("Hip", None),
+            test_bone("ArmL", None),
+            test_bone("Arm_L", None),
+            test_bone("H_Rib_L", None),
+        ];
+
+        assert_eq!(Some(0), match_bone_name("Hip", &bones));
+        assert_eq!(Some(2), match_bone_name("Arm_L", &bones));
+        assert_eq!(Some(1), match_bone_name("arm-l", &bones));
+        assert_eq!(Some(3), match_bone_name("H_RIB_L", &bones));
+        assert_eq!(None, match_bone_name("Head", &bones));
+    }
 
     #[test]
     fn skel_order_empty_reference() {
@@ -17,20 +17,26 @@ use ssbh_wgpu::{
     SharedRenderData, SkinningSettings, SsbhRenderer,
 };
 use std::{
-    collections::{BTreeMap, HashSet, VecDeque},
+    collections::{BTreeMap, HashMap, HashSet, VecDeque},
     error::Error,
+    ops::Range,
     path::{Path, PathBuf},
 };
 
+pub mod animation_preset;
 pub mod app;
 pub mod capture;
+pub mod cli;
+pub mod csv;
 pub mod editors;
 pub mod log;
 pub mod material;
 pub mod model_folder;
 pub mod path;
+pub mod plugins;
 pub mod preferences;
 pub mod presets;
+pub mod project;
 pub mod update;
 pub mod validation;
 pub mod widgets;
@@ -147,10 +153,41 @@ impl From<&wgpu::TextureViewDimension> for TextureDimension {
     }
 }
 
+/// The antialiasing method used for rendered screenshots and animations.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, strum::EnumIter, strum::Display)]
+pub enum AntialiasingMode {
+    None,
+    /// Render at twice the pixel count and downscale.
+    #[strum(serialize = "SSAA 2x")]
+    Ssaa2x,
+    /// Render at four times the pixel count and downscale.
+    #[default]
+    #[strum(serialize = "SSAA 4x")]
+    Ssaa4x,
+    /// Blur high contrast edges in the final image.
+    #[strum(serialize = "Edge Blur")]
+    EdgeBlur,
+}
+
+impl AntialiasingMode {
+    /// The number of supersamples rendered for each output pixel.
+    pub fn sample_count(&self) -> u32 {
+        match self {
+            AntialiasingMode::None => 1,
+            AntialiasingMode::Ssaa2x => 2,
+            AntialiasingMode::Ssaa4x => 4,
+            AntialiasingMode::EdgeBlur => 1,
+        }
+    }
+}
+
 pub struct RenderState {
     pub render_settings: RenderSettings,
+    pub antialiasing: AntialiasingMode,
     pub skinning_settings: SkinningSettings,
     pub model_render_options: ModelRenderOptions,
+    /// Overrides for [RenderState::model_render_options] by folder index.
+    pub per_model_options: HashMap<usize, ModelRenderOptions>,
     pub texture_render_settings: nutexb_wgpu::RenderSettings,
     pub shared_data: SharedRenderData,
     pub adapter_info: wgpu::AdapterInfo,
@@ -160,6 +197,8 @@ pub struct RenderState {
     // TODO: Is this the best place for this?
     pub render_models: Vec<RenderModel>,
     pub renderer: SsbhRenderer,
+    /// The renderer for the right half of the viewport when the viewport is split.
+    pub split_renderer: Option<SsbhRenderer>,
     pub texture_renderer: TextureRenderer,
     bone_name_renderer: BoneNameRenderer,
 }
@@ -186,8 +225,10 @@ impl RenderState {
         let shared_data = SharedRenderData::new(device, queue);
         Self {
             render_settings: RenderSettings::default(),
+            antialiasing: AntialiasingMode::default(),
             skinning_settings: SkinningSettings::default(),
             model_render_options: ModelRenderOptions::default(),
+            per_model_options: HashMap::new(),
             texture_render_settings: nutexb_wgpu::RenderSettings::default(),
             shared_data,
             adapter_info,
@@ -195,6 +236,7 @@ impl RenderState {
             camera_anim: None,
             render_models: Vec::new(),
             renderer,
+            split_renderer: None,
             texture_renderer,
             bone_name_renderer,
         }
@@ -217,10 +259,12 @@ impl RenderState {
         while let Some(action) = actions.pop_front() {
             match action {
                 RenderAction::UpdateRenderSettings => {
-                    self.renderer
-                        .update_render_settings(queue, &self.render_settings);
-                    self.renderer
-                        .update_skinning_settings(queue, &self.skinning_settings);
+                    for renderer in
+                        std::iter::once(&mut self.renderer).chain(self.split_renderer.as_mut())
+                    {
+                        renderer.update_render_settings(queue, &self.render_settings);
+                        renderer.update_skinning_settings(queue, &self.skinning_settings);
+                    }
                 }
                 RenderAction::UpdateCamera => {
                     self.camera_anim = camera_state.anim_path.as_ref().and_then(|path| {
@@ -274,6 +318,13 @@ impl RenderState {
             }
             RenderModelAction::Remove(i) => {
                 self.render_models.remove(i);
+
+                // Keep overrides for the remaining folders after shifting indices.
+                self.per_model_options = std::mem::take(&mut self.per_model_options)
+                    .into_iter()
+                    .filter(|(k, _)| *k != i)
+                    .map(|(k, o)| if k > i { (k - 1, o) } else { (k, o) })
+                    .collect();
             }
             RenderModelAction::Refresh => {
                 let mut new_render_models = ssbh_wgpu::load_render_models(
@@ -303,7 +354,10 @@ impl RenderState {
 
                 self.render_models = new_render_models;
             }
-            RenderModelAction::Clear => self.render_models = Vec::new(),
+            RenderModelAction::Clear => {
+                self.render_models = Vec::new();
+                self.per_model_options.clear();
+            }
             RenderModelAction::HideAll => {
                 for render_model in &mut self.render_models {
                     render_model.is_visible = false;
@@ -317,6 +371,11 @@ impl RenderState {
                     }
                 }
             }
+            RenderModelAction::ToggleVisibility(index) => {
+                if let Some(render_model) = self.render_models.get_mut(index) {
+                    render_model.is_visible = !render_model.is_visible;
+                }
+            }
             RenderModelAction::HideExpressions => {
                 for render_model in &mut self.render_models {
                     hide_expressions(render_model);
@@ -371,10 +430,12 @@ impl RenderState {
         self.animate_lighting(queue, current_frame);
 
         // color_grading_lut.nutexb
-        match &self.lighting_data.color_grading_lut {
-            Some(lut) => self.renderer.update_color_lut(device, queue, lut),
-            None => self.renderer.reset_color_lut(device, queue),
-        };
+        for renderer in std::iter::once(&mut self.renderer).chain(self.split_renderer.as_mut()) {
+            match &self.lighting_data.color_grading_lut {
+                Some(lut) => renderer.update_color_lut(device, queue, lut),
+                None => renderer.reset_color_lut(device, queue),
+            };
+        }
 
         // reflection_cubemap.nutexb
         match &self.lighting_data.reflection_cube_map {
@@ -394,11 +455,11 @@ impl RenderState {
 
     fn animate_lighting(&mut self, queue: &wgpu::Queue, current_frame: f32) {
         // Only the light00.nuanmb needs to animate.
-        match &self.lighting_data.light {
-            Some(light) => self
-                .renderer
-                .update_stage_uniforms(queue, light, current_frame),
-            None => self.renderer.reset_stage_uniforms(queue),
+        for renderer in std::iter::once(&mut self.renderer).chain(self.split_renderer.as_mut()) {
+            match &self.lighting_data.light {
+                Some(light) => renderer.update_stage_uniforms(queue, light, current_frame),
+                None => renderer.reset_stage_uniforms(queue),
+            }
         }
     }
 
@@ -409,8 +470,43 @@ impl RenderState {
         let clear_color = color.map(|c| linear_f32_from_gamma_u8(c) as f64);
         // This must be opaque to composite properly with egui.
         // Screenshots can set this to transparent for alpha support.
-        self.renderer
-            .set_clear_color([clear_color[0], clear_color[1], clear_color[2], 1.0]);
+        for renderer in std::iter::once(&mut self.renderer).chain(self.split_renderer.as_mut()) {
+            renderer.set_clear_color([clear_color[0], clear_color[1], clear_color[2], 1.0]);
+        }
+    }
+
+    /// The render options for drawing the render models in `model_range`.
+    /// The renderer draws the models in a range with a single set of options,
+    /// so a folder's override only applies if it's the only visible model in the range.
+    /// Each pane of the split viewport is drawn separately with its own range.
+    pub fn model_render_options_for(&self, model_range: Range<usize>) -> ModelRenderOptions {
+        let global = &self.model_render_options;
+        let mut visible =
+            model_range.filter(|i| self.render_models.get(*i).is_some_and(|m| m.is_visible));
+        let folder_index = match (visible.next(), visible.next()) {
+            (Some(i), None) => Some(i),
+            _ => None,
+        };
+        match folder_index.and_then(|i| self.per_model_options.get(&i)) {
+            Some(options) => ModelRenderOptions {
+                mask_model_index: global.mask_model_index,
+                mask_material_label: global.mask_material_label.clone(),
+                ..options.clone()
+            },
+            None => global.clone(),
+        }
+    }
+
+    fn select_meshes(&mut self, index: usize, mesh_object_name: &str) {
+        if let Some(render_model) = self.render_models.get_mut(index) {
+            for mesh in render_model
+                .meshes
+                .iter_mut()
+                .filter(|m| m.name == mesh_object_name)
+            {
+                mesh.is_selected = true;
+            }
+        }
     }
 
     fn clear_selected_meshes(&mut self) {
@@ -498,6 +594,39 @@ pub struct AnimationState {
     pub selected_slot: usize,
     pub animations: Vec<Vec<AnimationSlot>>,
     pub previous_frame_start: std::time::Instant,
+    /// The number of frames between each tick mark on the timeline.
+    pub tick_interval: u32,
+    /// Labeled frames sorted by frame for quickly jumping to important frames.
+    pub frame_markers: Vec<(f32, String)>,
+    pub new_marker_label: String,
+    /// The first frame when playing or looping animations.
+    pub play_start_frame: f32,
+    /// The last frame when playing animations or `None` to use the final frame.
+    pub play_end_frame: Option<f32>,
+    /// Render models in the skel's bind pose without applying any animations.
+    pub force_tpose: bool,
+}
+
+impl AnimationState {
+    /// The start and end frame for playback clamped to `final_frame_index`.
+    pub fn playback_range(&self, final_frame_index: f32) -> (f32, f32) {
+        let end = self
+            .play_end_frame
+            .unwrap_or(final_frame_index)
+            .clamp(0.0, final_frame_index);
+        (self.play_start_frame.clamp(0.0, end), end)
+    }
+
+    /// Add a marker at `frame` or rename the existing marker at that frame.
+    pub fn add_frame_marker(&mut self, frame: f32, label: String) {
+        match self.frame_markers.iter_mut().find(|(f, _)| *f == frame) {
+            Some(marker) => marker.1 = label,
+            None => {
+                self.frame_markers.push((frame, label));
+                self.frame_markers.sort_by(|a, b| a.0.total_cmp(&b.0));
+            }
+        }
+    }
 }
 
 impl Default for AnimationState {
@@ -512,6 +641,12 @@ impl Default for AnimationState {
             selected_slot: 0,
             should_loop: true,
             playback_speed: 1.0,
+            tick_interval: 10,
+            frame_markers: Vec::new(),
+            new_marker_label: String::new(),
+            play_start_frame: 0.0,
+            play_end_frame: None,
+            force_tpose: false,
         }
     }
 }
@@ -520,6 +655,12 @@ impl Default for AnimationState {
 pub struct AnimationSlot {
     pub is_enabled: bool,
     pub animation: Option<AnimationIndex>,
+    /// Scales [AnimationState::playback_speed] for this slot.
+    pub speed_multiplier: f32,
+    /// The number of frames to advance this slot relative to the current frame.
+    pub frame_offset: f32,
+    /// An optional user defined name for the slot like "face".
+    pub label: String,
 }
 
 impl AnimationSlot {
@@ -528,6 +669,20 @@ impl AnimationSlot {
         Self {
             is_enabled: true,
             animation: None,
+            speed_multiplier: 1.0,
+            frame_offset: 0.0,
+            label: String::new(),
+        }
+    }
+
+    /// Calculates the frame for this slot's animation after applying the speed and offset.
+    /// Scaled or offset frames wrap around to support looping.
+    pub fn slot_frame(&self, current_frame: f32, final_frame_index: f32) -> f32 {
+        if self.speed_multiplier == 1.0 && self.frame_offset == 0.0 {
+            current_frame
+        } else {
+            (current_frame * self.speed_multiplier + self.frame_offset)
+                .rem_euclid(final_frame_index + 1.0)
         }
     }
 }
@@ -763,27 +918,39 @@ pub fn default_fonts() -> egui::FontDefinitions {
     }
 }
 
-pub fn default_text_styles() -> BTreeMap<TextStyle, FontId> {
+pub fn default_text_styles(font_scale: f32) -> BTreeMap<TextStyle, FontId> {
     // Modified from the default theme.
+    let font = |size: f32| FontId::new(size * font_scale, FontFamily::Proportional);
     let mut text_styles = BTreeMap::new();
-    text_styles.insert(TextStyle::Small, FontId::new(9.0, FontFamily::Proportional));
-    text_styles.insert(TextStyle::Body, FontId::new(12.5, FontFamily::Proportional));
-    text_styles.insert(
-        TextStyle::Button,
-        FontId::new(12.5, FontFamily::Proportional),
-    );
-    text_styles.insert(
-        TextStyle::Heading,
-        FontId::new(18.0, FontFamily::Proportional),
-    );
+    text_styles.insert(TextStyle::Small, font(9.0));
+    text_styles.insert(TextStyle::Body, font(12.5));
+    text_styles.insert(TextStyle::Button, font(12.5));
+    text_styles.insert(TextStyle::Heading, font(18.0));
     // Use a consistent font for sliders and drag values.
-    text_styles.insert(
-        TextStyle::Monospace,
-        FontId::new(12.5, FontFamily::Proportional),
-    );
+    text_styles.insert(TextStyle::Monospace, font(12.5));
     text_styles
 }
 
+pub fn update_text_styles(preferences: &AppPreferences, ctx: &egui::Context) {
+    // Compact mode reduces padding more than text to fit more widgets on screen.
+    let (font_scale, padding_scale) = if preferences.compact_ui {
+        (preferences.font_scale * 0.75, preferences.font_scale * 0.5)
+    } else {
+        (preferences.font_scale, preferences.font_scale)
+    };
+
+    ctx.style_mut(|style| {
+        style.text_styles = default_text_styles(font_scale);
+
+        // Scale padding with the text to keep the same proportions.
+        let spacing = egui::style::Spacing::default();
+        style.spacing.item_spacing = spacing.item_spacing * padding_scale;
+        style.spacing.button_padding = spacing.button_padding * padding_scale;
+        style.spacing.window_margin = spacing.window_margin * padding_scale;
+        style.spacing.menu_margin = spacing.menu_margin * padding_scale;
+    });
+}
+
 const TEXT_COLOR_DARK: Color32 = Color32::from_gray(200);
 const TEXT_COLOR_LIGHT: Color32 = Color32::from_gray(40);
 
@@ -880,8 +1047,10 @@ pub fn widgets_light() -> Widgets {
 }
 
 fn horizontal_separator_empty(ui: &mut egui::Ui) {
+    // Use less space with smaller padding like in compact mode.
     let available_space = ui.available_size_before_wrap();
-    ui.allocate_space(egui::vec2(available_space.x, 6.0));
+    let height = 2.0 * ui.spacing().item_spacing.y;
+    ui.allocate_space(egui::vec2(available_space.x, height));
 }
 
 fn load_model(path: PathBuf, model: ssbh_wgpu::ModelFolder) -> ModelFolderState {
@@ -991,15 +1160,80 @@ fn save_file_as<T: SsbhData>(
 }
 
 pub fn update_color_theme(preferences: &AppPreferences, ctx: &egui::Context) {
-    if preferences.dark_mode {
-        ctx.set_visuals(Visuals {
-            widgets: widgets_dark(),
-            ..Default::default()
-        });
+    let (mut widgets, visuals) = if preferences.dark_mode {
+        (widgets_dark(), Visuals::default())
     } else {
-        ctx.set_visuals(Visuals {
-            widgets: widgets_light(),
-            ..Visuals::light()
-        });
+        (widgets_light(), Visuals::light())
+    };
+
+    if let Some([r, g, b]) = preferences.accent_color {
+        apply_accent_color(&mut widgets, Color32::from_rgb(r, g, b));
+    }
+
+    ctx.set_visuals(Visuals {
+        widgets,
+        error_fg_color: preferences.color_blind_mode.palette().error,
+        ..visuals
+    });
+}
+
+fn apply_accent_color(widgets: &mut Widgets, color: Color32) {
+    // Preserve the stroke widths from the theme.
+    for visuals in [&mut widgets.hovered, &mut widgets.active] {
+        visuals.bg_stroke.color = color;
+        visuals.fg_stroke.color = color;
+    }
+}
+
+/// A root or child bone with an identity transform for tests.
+#[cfg(test)]
+pub(crate) fn test_bone(name: &str, parent_index: Option<usize>) -> ssbh_data::skel_data::BoneData {
+    ssbh_data::skel_data::BoneData {
+        name: name.to_owned(),
+        transform: glam::Mat4::IDENTITY.to_cols_array_2d(),
+        parent_index,
+        billboard_type: ssbh_data::skel_data::BillboardType::Disabled,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn slot(speed_multiplier: f32, frame_offset: f32) -> AnimationSlot {
+        AnimationSlot {
+            speed_multiplier,
+            frame_offset,
+            ..AnimationSlot::new()
+        }
+    }
+
+    #[test]
+    fn slot_frame_default() {
+        assert_eq!(5.0, slot(1.0, 0.0).slot_frame(5.0, 9.0));
+        // Frames past the end are handled by the renderer.
+        assert_eq!(12.0, slot(1.0, 0.0).slot_frame(12.0, 9.0));
+    }
+
+    #[test]
+    fn slot_frame_offset() {
+        assert_eq!(7.0, slot(1.0, 2.0).slot_frame(5.0, 9.0));
+        assert_eq!(1.0, slot(1.0, 6.0).slot_frame(5.0, 9.0));
+        assert_eq!(8.0, slot(1.0, -2.0).slot_frame(0.0, 9.0));
+    }
+
+    #[test]
+    fn slot_frame_speed() {
+        assert_eq!(2.5, slot(0.5, 0.0).slot_frame(5.0, 9.0));
+        assert_eq!(8.0, slot(2.0, 0.0).slot_frame(4.0, 9.0));
+        // Faster slots loop instead of holding the final frame.
+        assert_eq!(2.0, slot(2.0, 0.0).slot_frame(6.0, 9.0));
+    }
+
+    #[test]
+    fn slot_frame_speed_offset() {
+        assert_eq!(4.5, slot(0.5, 2.0).slot_frame(5.0, 9.0));
+        assert_eq!(0.0, slot(2.0, 2.0).slot_frame(4.0, 9.0));
+        assert_eq!(9.0, slot(2.0, -1.0).slot_frame(0.0, 9.0));
     }
 }
@@ -1,29 +1,166 @@
-use std::sync::Mutex;
+use std::{
+    io::Write,
+    path::Path,
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Mutex,
+    },
+};
 
+use chrono::{DateTime, Local};
 use log::Log;
 
+pub type LogMessage = (DateTime<Local>, log::Level, String);
+
 pub struct AppLogger {
-    pub messages: Mutex<Vec<(log::Level, String)>>,
+    pub messages: Mutex<Vec<LogMessage>>,
+    /// The maximum number of messages before removing the oldest messages.
+    pub max_messages: AtomicUsize,
+    is_full: AtomicBool,
+    has_new_overflow: AtomicBool,
+}
+
+impl AppLogger {
+    pub fn new(max_messages: usize) -> Self {
+        Self {
+            messages: Mutex::new(Vec::new()),
+            max_messages: AtomicUsize::new(max_messages),
+            is_full: AtomicBool::new(false),
+            has_new_overflow: AtomicBool::new(false),
+        }
+    }
+
+    /// Returns `true` once after the messages first exceed the limit.
+    /// This avoids logging a warning from inside the logger itself.
+    pub fn take_overflow(&self) -> bool {
+        self.has_new_overflow.swap(false, Ordering::Relaxed)
+    }
+
+    /// Add `cleared_messages` back before any messages logged since clearing.
+    /// The oldest messages are removed if the combined messages exceed the limit.
+    pub fn restore_messages(&self, mut cleared_messages: Vec<LogMessage>) {
+        let mut messages = self.messages.lock().unwrap();
+        cleared_messages.append(&mut messages);
+        *messages = cleared_messages;
+        self.remove_oldest_messages(&mut messages);
+    }
+
+    fn remove_oldest_messages(&self, messages: &mut Vec<LogMessage>) {
+        let max_messages = self.max_messages.load(Ordering::Relaxed).max(1);
+        if messages.len() > max_messages {
+            let count = messages.len() - max_messages;
+            messages.drain(..count);
+
+            if !self.is_full.swap(true, Ordering::Relaxed) {
+                self.has_new_overflow.store(true, Ordering::Relaxed);
+            }
+        } else {
+            self.is_full.store(false, Ordering::Relaxed);
+        }
+    }
 }
 
 impl Log for AppLogger {
     fn enabled(&self, metadata: &log::Metadata) -> bool {
+        // Debug messages from dependencies like wgpu would flood the log.
+        let level_enabled = metadata.level() <= log::Level::Info
+            || metadata.target().starts_with(env!("CARGO_CRATE_NAME"));
+
         // TODO: Investigate why wgpu_text warns about cache resizing.
         // TODO: Use an RGBA8Unorm framebuffer for compatibility with egui_wgpu?
         // Silence this error for now.
-        metadata.level() <= log::Level::Warn
+        level_enabled
             && !metadata.target().starts_with("wgpu_text")
             && !metadata.target().starts_with("egui_wgpu")
     }
 
     fn log(&self, record: &log::Record) {
         if self.enabled(record.metadata()) {
-            self.messages
-                .lock()
-                .unwrap()
-                .push((record.level(), format!("{}", record.args())));
+            let mut messages = self.messages.lock().unwrap();
+            messages.push((Local::now(), record.level(), format!("{}", record.args())));
+            self.remove_oldest_messages(&mut messages);
         }
     }
 
     fn flush(&self) {}
 }
+
+/// Remove ANSI escape codes from `message` for displaying or saving as plain text.
+pub fn clean_message(message: &str) -> String {
+    // binrw formats backtraces, which isn't supported by egui font rendering.
+    let clean_message = strip_ansi_escapes::strip(message);
+    String::from_utf8_lossy(&clean_message).into_owned()
+}
+
+/// Write all `messages` to a text file with one timestamped message per line.
+pub fn write_log_file(path: &Path, messages: &[LogMessage]) -> std::io::Result<()> {
+    let mut writer = std::io::BufWriter::new(std::fs::File::create(path)?);
+    for (time, level, message) in messages {
+        writeln!(
+            writer,
+            "[{}] {level}: {}",
+            time.format("%Y-%m-%d %H:%M:%S%.3f"),
+            clean_message(message)
+        )?;
+    }
+    writer.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(text: &str) -> LogMessage {
+        (Local::now(), log::Level::Warn, text.to_owned())
+    }
+
+    fn texts(logger: &AppLogger) -> Vec<String> {
+        logger
+            .messages
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(_, _, m)| m.clone())
+            .collect()
+    }
+
+    #[test]
+    fn restore_messages_before_new_messages() {
+        let logger = AppLogger::new(10);
+        logger.messages.lock().unwrap().push(message("c"));
+
+        logger.restore_messages(vec![message("a"), message("b")]);
+        assert_eq!(vec!["a", "b", "c"], texts(&logger));
+        assert!(!logger.take_overflow());
+    }
+
+    #[test]
+    fn restore_messages_over_limit() {
+        let logger = AppLogger::new(2);
+        logger.messages.lock().unwrap().push(message("c"));
+
+        logger.restore_messages(vec![message("a"), message("b")]);
+        assert_eq!(vec!["b", "c"], texts(&logger));
+        assert!(logger.take_overflow());
+    }
+
+    fn is_enabled(level: log::Level, target: &str) -> bool {
+        let metadata = log::Metadata::builder().level(level).target(target).build();
+        AppLogger::new(10).enabled(&metadata)
+    }
+
+    #[test]
+    fn enabled_levels() {
+        assert!(is_enabled(log::Level::Error, "wgpu_core"));
+        assert!(is_enabled(log::Level::Info, "wgpu_core"));
+        assert!(!is_enabled(log::Level::Debug, "wgpu_core"));
+        assert!(is_enabled(log::Level::Debug, "ssbh_editor::app"));
+        assert!(!is_enabled(log::Level::Trace, "wgpu_core"));
+    }
+
+    #[test]
+    fn enabled_excluded_targets() {
+        assert!(!is_enabled(log::Level::Warn, "wgpu_text"));
+        assert!(!is_enabled(log::Level::Error, "egui_wgpu::renderer"));
+    }
+}
@@ -4,24 +4,26 @@
     windows_subsystem = "windows"
 )]
 
-use std::sync::Arc;
+use std::sync::{atomic::Ordering, Arc};
 
 use egui::ViewportBuilder;
 use egui_commonmark::CommonMarkCache;
 use egui_wgpu::WgpuConfiguration;
 use log::error;
 use nutexb_wgpu::TextureRenderer;
-use ssbh_editor::app::{RenderAction, SsbhApp, UiState};
+use ssbh_editor::app::{RenderAction, SplitViewportState, SsbhApp, UiState};
 use ssbh_editor::material::load_material_presets;
+use ssbh_editor::plugins::{builtin_plugins, load_dynamic_plugins};
 use ssbh_editor::preferences::{AppPreferences, GraphicsBackend};
 use ssbh_editor::presets::default_presets;
+use ssbh_editor::project::ProjectFile;
 use ssbh_editor::update::{check_for_updates, LatestReleaseInfo};
 use ssbh_editor::{
     checkerboard_texture, default_fonts, default_text_styles, generate_default_thumbnails,
     path::{presets_file, PROJECT_DIR},
     widgets_dark, AnimationState, CameraState, RenderState,
 };
-use ssbh_editor::{update_color_theme, SwingState, Thumbnail};
+use ssbh_editor::{update_color_theme, update_text_styles, SwingState, Thumbnail};
 use ssbh_wgpu::{BoneNameRenderer, SsbhRenderer};
 
 fn main() {
@@ -30,9 +32,14 @@ fn main() {
     // Initialize logging first in case app startup has warnings.
     // TODO: Also log to a file?
     log::set_logger(&*ssbh_editor::app::LOGGER)
-        .map(|()| log::set_max_level(log::LevelFilter::Info))
+        .map(|()| log::set_max_level(log::LevelFilter::Debug))
         .unwrap();
 
+    // Command line modes exit without opening a window.
+    if let Some(exit_code) = ssbh_editor::cli::run(&mut args) {
+        std::process::exit(exit_code);
+    }
+
     #[cfg(target_os = "macos")]
     let icon_bytes = include_bytes!("icons/SsbhEditor512_mac.png");
     #[cfg(not(target_os = "macos"))]
@@ -40,6 +47,9 @@ fn main() {
     let icon = eframe::icon_data::from_png_bytes(icon_bytes).unwrap();
 
     let mut preferences = AppPreferences::load_from_file();
+    ssbh_editor::app::LOGGER
+        .max_messages
+        .store(preferences.max_log_messages, Ordering::Relaxed);
 
     // Some Windows systems don't properly support Vulkan.
     // This mostly affects dual GPU systems like laptops.
@@ -92,7 +102,7 @@ fn main() {
             let ctx = &cc.egui_ctx;
 
             ctx.set_style(egui::style::Style {
-                text_styles: default_text_styles(),
+                text_styles: default_text_styles(preferences.font_scale),
                 visuals: egui::style::Visuals {
                     widgets: widgets_dark(),
                     ..Default::default()
@@ -105,6 +115,7 @@ fn main() {
 
             // Make sure the theme updates if changed from preferences.
             update_color_theme(&preferences, ctx);
+            update_text_styles(&preferences, ctx);
 
             let wgpu_state = cc.wgpu_render_state.as_ref().unwrap();
             let mut egui_renderer = wgpu_state.renderer.write();
@@ -202,6 +213,12 @@ fn create_app(
     camera_state: CameraState,
     preferences: AppPreferences,
 ) -> SsbhApp {
+    let project = ProjectFile::load_from_file();
+    let animation_state = AnimationState {
+        frame_markers: project.frame_markers.clone(),
+        ..Default::default()
+    };
+
     SsbhApp {
         models: Vec::new(),
         default_thumbnails,
@@ -209,26 +226,53 @@ fn create_app(
         release_info,
         should_validate_models: false,
         should_update_thumbnails: false,
+        gpu_progress: None,
         material_presets,
         default_presets: default_presets(),
         red_checkerboard,
         yellow_checkerboard,
         draw_bone_names: false,
+        show_bone_axes: false,
+        show_bounding_spheres: false,
         ui_state: UiState::default(),
-        animation_state: AnimationState::default(),
+        animation_state,
         swing_state: SwingState::default(),
         show_left_panel: true,
         show_right_panel: true,
         show_bottom_panel: true,
         camera_state,
+        per_folder_cameras: None,
+        camera_folder_index: None,
         preferences,
+        project,
+        validation_plugins: builtin_plugins()
+            .into_iter()
+            .chain(load_dynamic_plugins())
+            .collect(),
+        plugin_errors: Vec::new(),
         enable_helper_bones: true,
+        selected_bone: None,
+        selected_mesh: None,
         screenshot_to_render: None,
         animation_gif_to_render: None,
         animation_image_sequence_to_render: None,
+        animation_webm_to_render: None,
+        multiangle_batch_to_render: None,
+        turntable_gif_to_render: None,
+        comparison_screenshot_to_render: None,
+        depth_screenshot_to_render: None,
+        pending_captures: Vec::new(),
         markdown_cache: CommonMarkCache::default(),
         previous_viewport_width: 512.0,
         previous_viewport_height: 512.0,
+        split_viewport: false,
+        split_state: SplitViewportState {
+            camera_state: CameraState {
+                values: camera_state.values.clone(),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
         has_initialized_zoom_factor: false,
     }
 }
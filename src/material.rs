@@ -1,4 +1,5 @@
 use log::error;
+use serde::{Deserialize, Serialize};
 use ssbh_data::{matl_data::*, Vector4};
 use ssbh_wgpu::{split_param, ShaderProgram};
 use std::str::FromStr;
@@ -19,6 +20,40 @@ pub fn load_material_presets<P: AsRef<std::path::Path>>(path: P) -> Vec<MatlEntr
         .unwrap_or_default()
 }
 
+/// Material entries saved to JSON independent of a particular matl file.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MatlEntriesJson {
+    /// Incremented for changes that older versions of the editor can't read.
+    pub version: u32,
+    pub entries: Vec<MatlEntryData>,
+}
+
+impl MatlEntriesJson {
+    pub const VERSION: u32 = 1;
+}
+
+pub fn matl_entries_to_json(entries: &[MatlEntryData]) -> serde_json::Result<String> {
+    serde_json::to_string_pretty(&MatlEntriesJson {
+        version: MatlEntriesJson::VERSION,
+        entries: entries.to_vec(),
+    })
+}
+
+pub fn matl_entries_from_json(data: &[u8]) -> serde_json::Result<Vec<MatlEntryData>> {
+    match serde_json::from_slice::<MatlEntriesJson>(data) {
+        Ok(json) if json.version > MatlEntriesJson::VERSION => {
+            Err(serde::de::Error::custom(format!(
+                "unsupported material entries version {}, expected {} or lower",
+                json.version,
+                MatlEntriesJson::VERSION
+            )))
+        }
+        Ok(json) => Ok(json.entries),
+        // Also support the presets format that stores the entire matl.
+        Err(_) => serde_json::from_slice(data).map(|matl: MatlData| matl.entries),
+    }
+}
+
 pub fn apply_preset(entry: &MatlEntryData, preset: &MatlEntryData) -> MatlEntryData {
     // Textures paths are mesh specific and should be preserved if possible.
     // Remaining textures should use neutral default textures.
@@ -582,6 +617,18 @@ pub fn param_description(p: ParamId) -> &'static str {
     }
 }
 
+pub fn param_usage(p: ParamId) -> &'static str {
+    // TODO: Add usages for other parameters.
+    match p {
+        ParamId::CustomFloat1 => {
+            "Scales the intensity of the ambient occlusion from the texture maps."
+        }
+        ParamId::CustomFloat8 => "Specular scale. Scales the intensity of the specular highlights.",
+        ParamId::CustomFloat10 => "Stretches the specular highlight along the tangent direction.",
+        _ => "",
+    }
+}
+
 pub fn vector4_labels_short(p: ParamId) -> [&'static str; 4] {
     match p {
         ParamId::CustomVector1
@@ -651,10 +698,191 @@ pub fn vector4_labels_long(p: ParamId) -> [&'static str; 4] {
     }
 }
 
+/// The indices of the other entries with the same shader label as the entry at `source_index`
+/// that also have the `param_id` parameter.
+pub fn same_shader_entries_with_param(
+    entries: &[MatlEntryData],
+    source_index: usize,
+    param_id: ParamId,
+) -> Vec<usize> {
+    let Some(source) = entries.get(source_index) else {
+        return Vec::new();
+    };
+
+    entries
+        .iter()
+        .enumerate()
+        .filter(|(i, e)| {
+            *i != source_index
+                && e.shader_label == source.shader_label
+                && has_parameter(e, param_id)
+        })
+        .map(|(i, _)| i)
+        .collect()
+}
+
+fn has_parameter(entry: &MatlEntryData, param_id: ParamId) -> bool {
+    entry.blend_states.iter().any(|p| p.param_id == param_id)
+        || entry.floats.iter().any(|p| p.param_id == param_id)
+        || entry.booleans.iter().any(|p| p.param_id == param_id)
+        || entry.vectors.iter().any(|p| p.param_id == param_id)
+        || entry
+            .rasterizer_states
+            .iter()
+            .any(|p| p.param_id == param_id)
+        || entry.samplers.iter().any(|p| p.param_id == param_id)
+        || entry.textures.iter().any(|p| p.param_id == param_id)
+        || entry.uv_transforms.iter().any(|p| p.param_id == param_id)
+}
+
+/// Copy the value for `param_id` from `source` to `entry` if both entries have the parameter.
+pub fn copy_parameter(source: &MatlEntryData, entry: &mut MatlEntryData, param_id: ParamId) {
+    copy_param_data(&source.blend_states, &mut entry.blend_states, param_id);
+    copy_param_data(&source.floats, &mut entry.floats, param_id);
+    copy_param_data(&source.booleans, &mut entry.booleans, param_id);
+    copy_param_data(&source.vectors, &mut entry.vectors, param_id);
+    copy_param_data(
+        &source.rasterizer_states,
+        &mut entry.rasterizer_states,
+        param_id,
+    );
+    copy_param_data(&source.samplers, &mut entry.samplers, param_id);
+    copy_param_data(&source.textures, &mut entry.textures, param_id);
+    copy_param_data(&source.uv_transforms, &mut entry.uv_transforms, param_id);
+}
+
+fn copy_param_data<T: Clone>(
+    source: &[ParamData<T>],
+    target: &mut [ParamData<T>],
+    param_id: ParamId,
+) {
+    if let Some(source) = source.iter().find(|p| p.param_id == param_id) {
+        for param in target.iter_mut().filter(|p| p.param_id == param_id) {
+            param.data = source.data.clone();
+        }
+    }
+}
+
+/// Returns `true` if the first two components of `p` are the U and V scale of a UV transform.
+pub fn is_uv_scale_param(p: ParamId) -> bool {
+    matches!(
+        p,
+        ParamId::CustomVector6 | ParamId::CustomVector31 | ParamId::CustomVector32
+    )
+}
+
+/// The valid `(min, max)` range for each vector component if known.
+pub fn vector4_bounds(p: ParamId) -> [Option<(f32, f32)>; 4] {
+    const UNIT: Option<(f32, f32)> = Some((0.0, 1.0));
+    match p {
+        // Color values can exceed 1.0, but alpha values can't.
+        ParamId::CustomVector1
+        | ParamId::CustomVector2
+        | ParamId::CustomVector3
+        | ParamId::CustomVector5
+        | ParamId::CustomVector7
+        | ParamId::CustomVector8
+        | ParamId::CustomVector9
+        | ParamId::CustomVector10
+        | ParamId::CustomVector13
+        | ParamId::CustomVector15
+        | ParamId::CustomVector19
+        | ParamId::CustomVector20
+        | ParamId::CustomVector21
+        | ParamId::CustomVector22
+        | ParamId::CustomVector23
+        | ParamId::CustomVector24
+        | ParamId::CustomVector35
+        | ParamId::CustomVector43
+        | ParamId::CustomVector44
+        | ParamId::CustomVector45
+        | ParamId::CustomVector14 => [None, None, None, UNIT],
+        ParamId::CustomVector0 => [UNIT, None, None, None],
+        ParamId::CustomVector30 => [UNIT, UNIT, None, None],
+        ParamId::CustomVector47 => [UNIT; 4],
+        _ => [None; 4],
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn copy_parameter_same_shader() {
+        let entry = |material_label: &str, shader_label: &str, value| MatlEntryData {
+            material_label: material_label.to_owned(),
+            shader_label: shader_label.to_owned(),
+            blend_states: Vec::new(),
+            floats: vec![FloatParam {
+                param_id: ParamId::CustomFloat8,
+                data: value,
+            }],
+            booleans: Vec::new(),
+            vectors: Vec::new(),
+            rasterizer_states: Vec::new(),
+            samplers: Vec::new(),
+            textures: Vec::new(),
+            uv_transforms: Vec::new(),
+        };
+
+        let mut entries = vec![
+            entry("a", "SFX_PBS_0100000008008269_opaque", 0.5),
+            entry("b", "SFX_PBS_0100000008008269_opaque", 0.4),
+            entry("c", "SFX_PBS_010000000800826b_opaque", 0.4),
+        ];
+
+        let targets = same_shader_entries_with_param(&entries, 0, ParamId::CustomFloat8);
+        assert_eq!(vec![1], targets);
+        assert!(same_shader_entries_with_param(&entries, 0, ParamId::CustomFloat1).is_empty());
+
+        let source = entries[0].clone();
+        copy_parameter(&source, &mut entries[1], ParamId::CustomFloat8);
+        assert_eq!(0.5, entries[1].floats[0].data);
+        assert_eq!(0.4, entries[2].floats[0].data);
+    }
+
+    #[test]
+    fn matl_entries_json_round_trip() {
+        let entries = vec![default_material()];
+        let json = matl_entries_to_json(&entries).unwrap();
+        assert!(json.contains("\"version\": 1"));
+        assert_eq!(entries, matl_entries_from_json(json.as_bytes()).unwrap());
+    }
+
+    #[test]
+    fn matl_entries_from_json_newer_version() {
+        let json = serde_json::to_string(&MatlEntriesJson {
+            version: MatlEntriesJson::VERSION + 1,
+            entries: vec![default_material()],
+        })
+        .unwrap();
+        assert!(matl_entries_from_json(json.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn matl_entries_from_presets_json() {
+        let json = serde_json::to_string(&MatlData {
+            major_version: 1,
+            minor_version: 6,
+            entries: vec![default_material()],
+        })
+        .unwrap();
+        assert_eq!(
+            vec![default_material()],
+            matl_entries_from_json(json.as_bytes()).unwrap()
+        );
+    }
+
+    #[test]
+    fn vector4_bounds_alpha() {
+        assert_eq!(
+            [None, None, None, Some((0.0, 1.0))],
+            vector4_bounds(ParamId::CustomVector13)
+        );
+        assert_eq!([None; 4], vector4_bounds(ParamId::CustomVector6));
+    }
+
     #[test]
     fn add_parameters_all_missing() {
         let mut entry = MatlEntryData {
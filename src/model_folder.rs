@@ -1,5 +1,6 @@
 use std::path::{Path, PathBuf};
 
+use ssbh_data::mesh_data::{MeshObjectData, VectorData};
 use ssbh_wgpu::{swing::SwingPrc, ModelFolder, SharedRenderData};
 
 use crate::{validation::ModelValidationErrors, Thumbnail};
@@ -12,6 +13,62 @@ pub struct ModelFolderState {
     pub changed: FileChanged,
     pub swing_prc: Option<SwingPrc>, // TODO: Add animation slots?
     pub is_meshlist_open: bool,
+    /// Lazily calculated bounds for the model.numshb used for picking.
+    mesh_bounds: Option<Vec<MeshBounds>>,
+}
+
+/// The axis-aligned bounding box and bounding sphere of the vertex positions for a mesh object.
+#[derive(Debug, PartialEq, Clone)]
+pub struct MeshBounds {
+    pub name: String,
+    pub subindex: u64,
+    pub min: glam::Vec3,
+    pub max: glam::Vec3,
+    /// The average vertex position.
+    pub center: glam::Vec3,
+    /// The maximum distance of any vertex from [MeshBounds::center].
+    pub radius: f32,
+}
+
+impl MeshBounds {
+    pub fn from_mesh_object(mesh_object: &MeshObjectData) -> Option<Self> {
+        let positions: Vec<_> = match &mesh_object.positions.first()?.data {
+            VectorData::Vector2(_) => return None,
+            VectorData::Vector3(v) => v.iter().map(|p| glam::Vec3::from(*p)).collect(),
+            VectorData::Vector4(v) => v.iter().map(|p| glam::Vec4::from(*p).truncate()).collect(),
+        };
+
+        // TODO: Account for the parent bone transform for single bound meshes.
+        let min = positions.iter().copied().reduce(glam::Vec3::min)?;
+        let max = positions.iter().copied().reduce(glam::Vec3::max)?;
+
+        let center = positions.iter().sum::<glam::Vec3>() / positions.len() as f32;
+        let radius = positions
+            .iter()
+            .map(|p| p.distance(center))
+            .fold(0.0, f32::max);
+
+        Some(Self {
+            name: mesh_object.name.clone(),
+            subindex: mesh_object.subindex,
+            min,
+            max,
+            center,
+            radius,
+        })
+    }
+
+    /// The distance along the ray to the nearest intersection or `None` if the ray misses the box.
+    pub fn intersect_ray(&self, origin: glam::Vec3, direction: glam::Vec3) -> Option<f32> {
+        // Intersect the ray with the slabs between the min and max planes for each axis.
+        let inverse_direction = direction.recip();
+        let t0 = (self.min - origin) * inverse_direction;
+        let t1 = (self.max - origin) * inverse_direction;
+
+        let t_enter = t0.min(t1).max_element().max(0.0);
+        let t_exit = t0.max(t1).min_element();
+        (t_enter <= t_exit).then_some(t_enter)
+    }
 }
 
 impl ModelFolderState {
@@ -29,9 +86,33 @@ impl ModelFolderState {
             changed,
             swing_prc,
             is_meshlist_open: true,
+            mesh_bounds: None,
         }
     }
 
+    /// The bounds for each mesh object in the model.numshb.
+    /// These are only recalculated after calling [ModelFolderState::clear_mesh_bounds].
+    pub fn mesh_bounds(&mut self) -> &[MeshBounds] {
+        self.mesh_bounds.get_or_insert_with(|| {
+            self.model
+                .meshes
+                .iter()
+                .find(|(f, _)| f == "model.numshb")
+                .and_then(|(_, m)| m.as_ref().ok())
+                .map(|mesh| {
+                    mesh.objects
+                        .iter()
+                        .filter_map(MeshBounds::from_mesh_object)
+                        .collect()
+                })
+                .unwrap_or_default()
+        })
+    }
+
+    pub fn clear_mesh_bounds(&mut self) {
+        self.mesh_bounds = None;
+    }
+
     pub fn validate(&mut self, shared_data: &SharedRenderData) {
         self.validation = ModelValidationErrors::from_model(
             &self.model,
@@ -55,6 +136,7 @@ impl ModelFolderState {
         // Make sure the ModelFolder is updated first.
         self.model = ModelFolder::load_folder(&self.folder_path);
         self.changed = FileChanged::from_model(&self.model);
+        self.mesh_bounds = None;
     }
 }
 
@@ -131,7 +213,7 @@ fn find_folders_by_path_affinity<'a, P: Fn(&'a ModelFolderState) -> bool>(
 mod tests {
     use super::*;
     use crate::{model_folder::FileChanged, validation::ModelValidationErrors};
-    use ssbh_data::anim_data::AnimData;
+    use ssbh_data::{anim_data::AnimData, mesh_data::AttributeData};
     use ssbh_wgpu::ModelFolder;
 
     fn model_folder(folder_path: PathBuf) -> ModelFolderState {
@@ -154,6 +236,7 @@ mod tests {
             validation: ModelValidationErrors::default(),
             changed: FileChanged::default(),
             is_meshlist_open: true,
+            mesh_bounds: None,
         }
     }
 
@@ -185,6 +268,7 @@ mod tests {
             validation: ModelValidationErrors::default(),
             changed: FileChanged::default(),
             is_meshlist_open: true,
+            mesh_bounds: None,
         }
     }
 
@@ -214,4 +298,58 @@ mod tests {
         let folders = find_anim_folders(&model_folder("/model/body/c00".into()), &anim_folders);
         assert!(matches!(folders.as_slice(), [(2, _), (0, _), (1, _)]));
     }
+
+    #[test]
+    fn mesh_bounds_from_positions() {
+        let mesh_object = MeshObjectData {
+            name: "a".to_owned(),
+            subindex: 1,
+            positions: vec![AttributeData {
+                name: "Position0".to_owned(),
+                data: VectorData::Vector3(vec![[1.0, -2.0, 3.0], [-1.0, 2.0, 0.5]]),
+            }],
+            ..Default::default()
+        };
+        assert_eq!(
+            Some(MeshBounds {
+                name: "a".to_owned(),
+                subindex: 1,
+                min: glam::vec3(-1.0, -2.0, 0.5),
+                max: glam::vec3(1.0, 2.0, 3.0),
+                center: glam::vec3(0.0, 0.0, 1.75),
+                radius: 6.5625f32.sqrt(),
+            }),
+            MeshBounds::from_mesh_object(&mesh_object)
+        );
+    }
+
+    #[test]
+    fn mesh_bounds_intersect_ray() {
+        let bounds = MeshBounds {
+            name: String::new(),
+            subindex: 0,
+            min: glam::Vec3::splat(-1.0),
+            max: glam::Vec3::splat(1.0),
+            center: glam::Vec3::ZERO,
+            radius: 3.0f32.sqrt(),
+        };
+        assert_eq!(
+            Some(4.0),
+            bounds.intersect_ray(glam::vec3(0.0, 0.0, 5.0), glam::Vec3::NEG_Z)
+        );
+        // The origin is inside the box.
+        assert_eq!(
+            Some(0.0),
+            bounds.intersect_ray(glam::Vec3::ZERO, glam::Vec3::X)
+        );
+        // The box is behind the ray.
+        assert_eq!(
+            None,
+            bounds.intersect_ray(glam::vec3(0.0, 0.0, 5.0), glam::Vec3::Z)
+        );
+        assert_eq!(
+            None,
+            bounds.intersect_ray(glam::vec3(3.0, 0.0, 5.0), glam::Vec3::NEG_Z)
+        );
+    }
 }
@@ -24,6 +24,14 @@ pub fn preferences_file() -> PathBuf {
     PROJECT_DIR.data_local_dir().join("preferences.json")
 }
 
+pub fn plugins_dir() -> PathBuf {
+    PROJECT_DIR.data_local_dir().join("plugins")
+}
+
+pub fn project_file() -> PathBuf {
+    PROJECT_DIR.data_local_dir().join("project.json")
+}
+
 pub fn folder_editor_title(folder_name: &Path, file_name: &str) -> String {
     // Show a simplified version of the path.
     // fighter/mario/motion/body/c00/model.numatb -> c00/model.numatb
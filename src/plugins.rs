@@ -0,0 +1,261 @@
+//! Validation rules in addition to the built in file validation.
+//!
+//! Plugins can be compiled into the editor or loaded at startup from shared libraries
+//! in the plugins directory. Shared libraries export a C-compatible function named
+//! `ssbh_editor_validation_plugin` with the signature of [RegisterPluginFn].
+use std::{
+    collections::HashSet,
+    ffi::{c_char, c_void, CStr, CString},
+    path::Path,
+};
+
+use log::error;
+
+use crate::{app::SsbhApp, model_folder::ModelFolderState, path::plugins_dir};
+
+/// The version of [PluginDescriptor] expected by the editor.
+pub const PLUGIN_ABI_VERSION: u32 = 1;
+
+pub trait ValidationPlugin {
+    fn name(&self) -> &str;
+
+    fn validate(&self, app: &SsbhApp) -> Vec<ValidationError>;
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct ValidationError {
+    pub folder_index: usize,
+    pub file_name: Option<String>,
+    pub message: String,
+}
+
+impl std::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.file_name {
+            Some(file_name) => write!(f, "{file_name}: {}", self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+/// The plugins included with the editor.
+pub fn builtin_plugins() -> Vec<Box<dyn ValidationPlugin>> {
+    vec![Box::new(UnusedTextures)]
+}
+
+/// Run all the `plugins` on the currently loaded folders.
+pub fn run_validation_plugins(
+    plugins: &[Box<dyn ValidationPlugin>],
+    app: &SsbhApp,
+) -> Vec<ValidationError> {
+    plugins.iter().flat_map(|p| p.validate(app)).collect()
+}
+
+/// Check for textures in the folder that no material references.
+/// Unused textures still increase the size of mods but don't affect rendering.
+struct UnusedTextures;
+
+impl ValidationPlugin for UnusedTextures {
+    fn name(&self) -> &str {
+        "Unused Textures"
+    }
+
+    fn validate(&self, app: &SsbhApp) -> Vec<ValidationError> {
+        let mut errors = Vec::new();
+        for (folder_index, model) in app.models.iter().enumerate() {
+            // Texture names in the matl don't include the file extension.
+            let textures: HashSet<_> = model
+                .model
+                .matls
+                .iter()
+                .filter_map(|(_, matl)| matl.as_ref().ok())
+                .flat_map(|matl| &matl.entries)
+                .flat_map(|entry| &entry.textures)
+                .map(|texture| texture.data.to_lowercase())
+                .collect();
+
+            for (file_name, _) in &model.model.nutexbs {
+                let name = Path::new(file_name).with_extension("");
+                if !textures.contains(&name.to_string_lossy().to_lowercase()) {
+                    errors.push(ValidationError {
+                        folder_index,
+                        file_name: Some(file_name.clone()),
+                        message: "The texture is not used by any material.".to_owned(),
+                    });
+                }
+            }
+        }
+        errors
+    }
+}
+
+/// The value returned by the plugin's registration function.
+/// All strings are null terminated UTF-8.
+#[repr(C)]
+pub struct PluginDescriptor {
+    /// This must match [PLUGIN_ABI_VERSION].
+    pub abi_version: u32,
+    pub name: *const c_char,
+    /// Validate the folder at `folder_path` containing `file_count` files in `file_names`.
+    /// Call `report` with `context` for each error with a file name or null.
+    pub validate_folder: unsafe extern "C" fn(
+        folder_path: *const c_char,
+        file_names: *const *const c_char,
+        file_count: usize,
+        report: ReportErrorFn,
+        context: *mut c_void,
+    ),
+}
+
+pub type ReportErrorFn =
+    unsafe extern "C" fn(context: *mut c_void, file_name: *const c_char, message: *const c_char);
+
+pub type RegisterPluginFn = unsafe extern "C" fn() -> *const PluginDescriptor;
+
+struct DynamicPlugin {
+    name: String,
+    descriptor: *const PluginDescriptor,
+    // The library must outlive the descriptor.
+    _library: libloading::Library,
+}
+
+impl ValidationPlugin for DynamicPlugin {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn validate(&self, app: &SsbhApp) -> Vec<ValidationError> {
+        let mut errors = Vec::new();
+        for (folder_index, model) in app.models.iter().enumerate() {
+            let folder_path = CString::new(model.folder_path.to_string_lossy().as_bytes());
+            let file_names: Result<Vec<_>, _> = folder_file_names(model)
+                .map(|n| CString::new(n.as_bytes()))
+                .collect();
+            let (Ok(folder_path), Ok(file_names)) = (folder_path, file_names) else {
+                continue;
+            };
+            let file_name_ptrs: Vec<_> = file_names.iter().map(|n| n.as_ptr()).collect();
+
+            let mut context = ReportContext {
+                folder_index,
+                errors: Vec::new(),
+            };
+            // SAFETY: The descriptor was checked when loading, and all pointers outlive the call.
+            unsafe {
+                ((*self.descriptor).validate_folder)(
+                    folder_path.as_ptr(),
+                    file_name_ptrs.as_ptr(),
+                    file_name_ptrs.len(),
+                    report_error,
+                    &mut context as *mut ReportContext as *mut c_void,
+                );
+            }
+            errors.append(&mut context.errors);
+        }
+        errors
+    }
+}
+
+struct ReportContext {
+    folder_index: usize,
+    errors: Vec<ValidationError>,
+}
+
+unsafe extern "C" fn report_error(
+    context: *mut c_void,
+    file_name: *const c_char,
+    message: *const c_char,
+) {
+    if context.is_null() || message.is_null() {
+        return;
+    }
+
+    let context = &mut *(context as *mut ReportContext);
+    let file_name =
+        (!file_name.is_null()).then(|| CStr::from_ptr(file_name).to_string_lossy().into_owned());
+    context.errors.push(ValidationError {
+        folder_index: context.folder_index,
+        file_name,
+        message: CStr::from_ptr(message).to_string_lossy().into_owned(),
+    });
+}
+
+fn folder_file_names(model: &ModelFolderState) -> impl Iterator<Item = &String> {
+    let files = &model.model;
+    files
+        .meshes
+        .iter()
+        .map(|(n, _)| n)
+        .chain(files.meshexes.iter().map(|(n, _)| n))
+        .chain(files.skels.iter().map(|(n, _)| n))
+        .chain(files.matls.iter().map(|(n, _)| n))
+        .chain(files.modls.iter().map(|(n, _)| n))
+        .chain(files.adjs.iter().map(|(n, _)| n))
+        .chain(files.anims.iter().map(|(n, _)| n))
+        .chain(files.hlpbs.iter().map(|(n, _)| n))
+        .chain(files.nutexbs.iter().map(|(n, _)| n))
+}
+
+/// Load the plugins from any shared libraries in the plugins directory.
+pub fn load_dynamic_plugins() -> Vec<Box<dyn ValidationPlugin>> {
+    let dir = plugins_dir();
+    let Ok(entries) = std::fs::read_dir(&dir) else {
+        // The plugins directory is optional.
+        return Vec::new();
+    };
+
+    entries
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| {
+            p.extension()
+                .is_some_and(|e| e == std::env::consts::DLL_EXTENSION)
+        })
+        .filter_map(|path| match load_dynamic_plugin(&path) {
+            Ok(plugin) => Some(Box::new(plugin) as Box<dyn ValidationPlugin>),
+            Err(e) => {
+                error!("Failed to load plugin {:?}: {}", path, e);
+                None
+            }
+        })
+        .collect()
+}
+
+fn load_dynamic_plugin(path: &Path) -> Result<DynamicPlugin, Box<dyn std::error::Error>> {
+    // SAFETY: Plugins are trusted to follow the documented ABI.
+    unsafe {
+        let library = libloading::Library::new(path)?;
+        let descriptor = {
+            let register: libloading::Symbol<RegisterPluginFn> =
+                library.get(b"ssbh_editor_validation_plugin")?;
+            register()
+        };
+        if descriptor.is_null() {
+            return Err("plugin returned a null descriptor".into());
+        }
+        if (*descriptor).abi_version != PLUGIN_ABI_VERSION {
+            return Err(format!(
+                "unsupported plugin ABI version {}, expected {}",
+                (*descriptor).abi_version,
+                PLUGIN_ABI_VERSION
+            )
+            .into());
+        }
+
+        let name = if (*descriptor).name.is_null() {
+            path.file_stem()
+                .map(|s| s.to_string_lossy().into_owned())
+                .unwrap_or_default()
+        } else {
+            CStr::from_ptr((*descriptor).name)
+                .to_string_lossy()
+                .into_owned()
+        };
+
+        Ok(DynamicPlugin {
+            name,
+            descriptor,
+            _library: library,
+        })
+    }
+}
@@ -1,7 +1,8 @@
 use crate::{path::preferences_file, widgets_dark, CameraValues};
+use egui::Color32;
 use log::error;
 use serde::{Deserialize, Serialize};
-use strum::{Display, EnumString, EnumVariantNames};
+use strum::{Display, EnumIter, EnumString, EnumVariantNames, IntoEnumIterator};
 
 #[derive(
     Debug,
@@ -23,6 +24,68 @@ pub enum GraphicsBackend {
     Dx12,
 }
 
+#[derive(Debug, Serialize, Deserialize, PartialEq, Display, EnumIter, Clone, Copy, Default)]
+pub enum ColorBlindMode {
+    #[default]
+    None,
+    Protanopia,
+    Deuteranopia,
+    Tritanopia,
+}
+
+/// Colors used to distinguish errors and axes in the UI and viewport.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ColorPalette {
+    pub error: Color32,
+    /// The colors for the X, Y, and Z axes.
+    pub axes: [Color32; 3],
+}
+
+pub const DEFAULT_PALETTE: ColorPalette = ColorPalette {
+    error: Color32::from_rgb(240, 80, 80),
+    axes: [Color32::RED, Color32::GREEN, Color32::BLUE],
+};
+
+// Red and green are hard to distinguish, so use blue and orange instead.
+pub const PROTANOPIA_PALETTE: ColorPalette = ColorPalette {
+    error: Color32::from_rgb(230, 159, 0),
+    axes: [
+        Color32::from_rgb(230, 159, 0),
+        Color32::from_rgb(86, 180, 233),
+        Color32::from_rgb(240, 240, 240),
+    ],
+};
+
+pub const DEUTERANOPIA_PALETTE: ColorPalette = ColorPalette {
+    error: Color32::from_rgb(213, 94, 0),
+    axes: [
+        Color32::from_rgb(213, 94, 0),
+        Color32::from_rgb(0, 114, 178),
+        Color32::from_rgb(240, 240, 240),
+    ],
+};
+
+// Blue and yellow are hard to distinguish, so avoid using blue for the Z axis.
+pub const TRITANOPIA_PALETTE: ColorPalette = ColorPalette {
+    error: Color32::from_rgb(240, 80, 80),
+    axes: [
+        Color32::from_rgb(213, 94, 0),
+        Color32::from_rgb(0, 158, 115),
+        Color32::from_rgb(204, 121, 167),
+    ],
+};
+
+impl ColorBlindMode {
+    pub fn palette(&self) -> ColorPalette {
+        match self {
+            ColorBlindMode::None => DEFAULT_PALETTE,
+            ColorBlindMode::Protanopia => PROTANOPIA_PALETTE,
+            ColorBlindMode::Deuteranopia => DEUTERANOPIA_PALETTE,
+            ColorBlindMode::Tritanopia => TRITANOPIA_PALETTE,
+        }
+    }
+}
+
 // Use defaults for missing values to avoid most version conflicts.
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(default)]
@@ -35,6 +98,93 @@ pub struct AppPreferences {
     pub graphics_backend: GraphicsBackend,
     pub scale_factor: f32,
     pub default_camera: CameraValues,
+    pub panel_layout: PanelLayout,
+    pub clear_log_on_load: bool,
+    pub camera_bookmarks: Vec<CameraBookmark>,
+    pub show_shading_shortcuts: bool,
+    pub shading_shortcuts: Vec<ShadingShortcutButton>,
+    pub max_log_messages: usize,
+    /// The output size for screenshots or `None` to use the viewport size.
+    pub screenshot_resolution: Option<ScreenshotResolution>,
+    /// The length in world units of each axis for "Show Bone Axes" before applying the bone's scale.
+    pub bone_axes_length: f32,
+    /// The scale applied to all text sizes and padding.
+    pub font_scale: f32,
+    /// The color for hovered and active widgets or `None` to use the theme colors.
+    pub accent_color: Option<[u8; 3]>,
+    /// Reduce the size of text and padding to fit more on smaller screens.
+    pub compact_ui: bool,
+    pub color_blind_mode: ColorBlindMode,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Copy)]
+pub struct ScreenshotResolution {
+    pub width: u32,
+    pub height: u32,
+}
+
+impl Default for ScreenshotResolution {
+    fn default() -> Self {
+        Self {
+            width: 3840,
+            height: 2160,
+        }
+    }
+}
+
+/// A button on the viewport shortcut bar for quickly changing the shading.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Copy)]
+pub struct ShadingShortcutButton {
+    pub shortcut: ShadingShortcut,
+    pub visible: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Copy, Display, EnumIter)]
+pub enum ShadingShortcut {
+    Shaded,
+    Wireframe,
+    Normal,
+    #[strum(serialize = "UV")]
+    Uv,
+    #[strum(serialize = "Vertex Color")]
+    VertexColor,
+    Silhouette,
+}
+
+fn default_shading_shortcuts() -> Vec<ShadingShortcutButton> {
+    ShadingShortcut::iter()
+        .map(|shortcut| ShadingShortcutButton {
+            shortcut,
+            visible: true,
+        })
+        .collect()
+}
+
+/// Named camera settings for quickly switching between views.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct CameraBookmark {
+    pub name: String,
+    pub values: CameraValues,
+}
+
+/// The sizes of the main window panels in logical points.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Copy)]
+#[serde(default)]
+pub struct PanelLayout {
+    pub tree_width: f32,
+    pub right_panel_width: f32,
+    pub timeline_height: f32,
+}
+
+impl Default for PanelLayout {
+    fn default() -> Self {
+        Self {
+            tree_width: 200.0,
+            right_panel_width: 450.0,
+            timeline_height: 40.0,
+        }
+    }
 }
 
 impl AppPreferences {
@@ -83,6 +233,18 @@ impl Default for AppPreferences {
             graphics_backend: GraphicsBackend::default(),
             scale_factor: 1.0,
             default_camera: CameraValues::default(),
+            panel_layout: PanelLayout::default(),
+            clear_log_on_load: false,
+            camera_bookmarks: Vec::new(),
+            show_shading_shortcuts: true,
+            shading_shortcuts: default_shading_shortcuts(),
+            max_log_messages: 1000,
+            screenshot_resolution: None,
+            bone_axes_length: 2.0,
+            font_scale: 1.0,
+            accent_color: None,
+            compact_ui: false,
+            color_blind_mode: ColorBlindMode::None,
         }
     }
 }
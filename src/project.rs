@@ -0,0 +1,48 @@
+use std::collections::HashMap;
+
+use crate::path::project_file;
+use log::error;
+use serde::{Deserialize, Serialize};
+
+/// Editor data associated with the opened folders that isn't stored in the SSBH files.
+// Use defaults for missing values to avoid most version conflicts.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ProjectFile {
+    /// Freeform text notes for each folder path.
+    pub folder_notes: HashMap<String, String>,
+    /// The animation slot labels for each folder path.
+    pub slot_labels: HashMap<String, Vec<String>>,
+    /// The labeled frames on the animation timeline.
+    pub frame_markers: Vec<(f32, String)>,
+}
+
+impl ProjectFile {
+    pub fn load_from_file() -> Self {
+        let path = project_file();
+        // The project file is only created after exiting the application.
+        if !path.exists() {
+            return Self::default();
+        }
+
+        std::fs::read(&path)
+            .and_then(|data| Ok(serde_json::from_slice(&data)?))
+            .map_err(|e| {
+                error!("Failed to load project from {:?}: {}", &path, e);
+                e
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn write_to_file(&self) {
+        let path = project_file();
+        match serde_json::to_string_pretty(self) {
+            Ok(json) => {
+                if let Err(e) = std::fs::write(&path, json) {
+                    error!("Failed to write project to {:?}: {}", &path, e);
+                }
+            }
+            Err(e) => error!("Failed to serialize project: {}", e),
+        }
+    }
+}
@@ -2,6 +2,7 @@ use crate::{FileResult, TextureDimension};
 use approx::relative_eq;
 use nutexb::{NutexbFile, NutexbFormat};
 use ssbh_data::{
+    adj_data::AdjEntryData,
     matl_data::{BlendFactor, MagFilter, MinFilter, ParamId, WrapMode},
     mesh_data::VectorData,
     prelude::*,
@@ -14,7 +15,6 @@ use std::{
 };
 use thiserror::Error;
 
-// TODO: Add a severity level to differentiate warnings vs errors.
 #[derive(Default)]
 pub struct ModelValidationErrors {
     pub mesh_errors: Vec<MeshValidationError>,
@@ -49,6 +49,10 @@ impl ModelValidationErrors {
         let modl = model.find_modl();
         let matl = model.find_matl();
 
+        if let (Some(adj), Some(mesh)) = (model.find_adj(), mesh) {
+            validate_adj_entries(&mut validation, adj, mesh);
+        }
+
         if let Some(modl) = modl {
             validate_modl_entries(&mut validation, modl, matl, mesh);
         }
@@ -84,6 +88,63 @@ impl ModelValidationErrors {
 
         validation
     }
+
+    /// Flatten the errors for each file into messages with a file name and severity.
+    pub fn messages(&self) -> Vec<ValidationMessage> {
+        let mut messages = Vec::new();
+        let mut add = |file_name: &str, severity, message: String| {
+            messages.push(ValidationMessage {
+                file_name: file_name.to_string(),
+                severity,
+                message,
+            })
+        };
+
+        for e in &self.mesh_errors {
+            add("model.numshb", e.kind.severity(), e.to_string());
+        }
+        for e in &self.meshex_errors {
+            add("model.numshexb", Severity::Error, e.to_string());
+        }
+        for e in &self.skel_errors {
+            add("model.nusktb", Severity::Error, e.to_string());
+        }
+        for e in &self.matl_errors {
+            add("model.numatb", e.kind.severity(), e.to_string());
+        }
+        for e in &self.modl_errors {
+            add("model.numdlb", Severity::Error, e.to_string());
+        }
+        for e in &self.adj_errors {
+            add("model.adjb", Severity::Error, e.to_string());
+        }
+        for e in &self.anim_errors {
+            add("model.nuanmb", Severity::Error, e.to_string());
+        }
+        for e in &self.hlpb_errors {
+            add("model.nuhlpb", Severity::Error, e.to_string());
+        }
+        for e in &self.nutexb_errors {
+            add(e.name(), Severity::Warning, e.to_string());
+        }
+
+        messages
+    }
+}
+
+/// Errors are likely to cause problems in game.
+/// Warnings may be intentional or have only minor effects.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, strum::Display)]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ValidationMessage {
+    pub file_name: String,
+    pub severity: Severity,
+    pub message: String,
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -125,6 +186,18 @@ pub enum MeshValidationErrorKind {
     MoreThan4WeightsPerVertex { mesh_name: String },
 }
 
+impl MeshValidationErrorKind {
+    pub fn severity(&self) -> Severity {
+        match self {
+            MeshValidationErrorKind::MissingRequiredVertexAttributes { .. } => Severity::Error,
+            MeshValidationErrorKind::DuplicateSubindex { .. } => Severity::Error,
+            MeshValidationErrorKind::VertexWeightsNotNormalized { .. } => Severity::Warning,
+            MeshValidationErrorKind::VertexWeightsZero { .. } => Severity::Warning,
+            MeshValidationErrorKind::MoreThan4WeightsPerVertex { .. } => Severity::Warning,
+        }
+    }
+}
+
 pub struct SkelValidationError;
 impl Display for SkelValidationError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -233,6 +306,26 @@ Use a Source Color of \"One\" or use a shader that does not premultiply alpha.")
     },
 }
 
+impl MatlValidationErrorKind {
+    pub fn severity(&self) -> Severity {
+        match self {
+            MatlValidationErrorKind::MissingRequiredVertexAttributes { .. } => Severity::Error,
+            MatlValidationErrorKind::UnexpectedTextureFormat { .. } => Severity::Warning,
+            MatlValidationErrorKind::UnexpectedTextureDimension { .. } => Severity::Error,
+            MatlValidationErrorKind::MissingTextures { .. } => Severity::Error,
+            MatlValidationErrorKind::RenormalMaterialMissingMeshAdjEntry { .. } => Severity::Error,
+            MatlValidationErrorKind::RenormalMaterialMissingAdj { .. } => Severity::Error,
+            MatlValidationErrorKind::WrapModeClampsUvs { .. } => Severity::Warning,
+            MatlValidationErrorKind::InvalidShaderLabel { .. } => Severity::Error,
+            MatlValidationErrorKind::PremultipliedShaderSrcAlpha { .. } => Severity::Warning,
+            MatlValidationErrorKind::DuplicateMaterialLabel { .. } => Severity::Error,
+            MatlValidationErrorKind::SamplerAnisotropyNonLinearFilterMode { .. } => {
+                Severity::Warning
+            }
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq)]
 pub struct ModlValidationError {
     pub entry_index: usize,
@@ -265,6 +358,34 @@ pub enum AdjValidationError {
         mesh_name: String,
         material_label: String,
     },
+
+    #[error("Entry {entry_index} references mesh object index {mesh_object_index}, but the model.numshb has {mesh_object_count} mesh objects.")]
+    InvalidMeshObjectIndex {
+        entry_index: usize,
+        mesh_object_index: usize,
+        mesh_object_count: usize,
+    },
+
+    #[error("Entry {entry_index} for mesh {mesh_name:?} has {actual} vertex adjacency values, but the mesh requires {expected}.")]
+    VertexAdjacencyLengthMismatch {
+        entry_index: usize,
+        mesh_name: String,
+        expected: usize,
+        actual: usize,
+    },
+}
+
+impl AdjValidationError {
+    /// The index of the invalid entry in the model.adjb or `None` if the entry is missing.
+    pub fn entry_index(&self) -> Option<usize> {
+        match self {
+            AdjValidationError::MissingRenormalEntry { .. } => None,
+            AdjValidationError::InvalidMeshObjectIndex { entry_index, .. } => Some(*entry_index),
+            AdjValidationError::VertexAdjacencyLengthMismatch { entry_index, .. } => {
+                Some(*entry_index)
+            }
+        }
+    }
 }
 
 pub struct AnimValidationError;
@@ -656,6 +777,36 @@ fn validate_texture_dimensions<'a>(
     }
 }
 
+fn validate_adj_entries(validation: &mut ModelValidationErrors, adj: &AdjData, mesh: &MeshData) {
+    for (entry_index, entry) in adj.entries.iter().enumerate() {
+        match mesh.objects.get(entry.mesh_object_index) {
+            Some(mesh_object) => {
+                // Compare with newly generated data to check the vertex count.
+                let expected = AdjEntryData::from_mesh_object(entry.mesh_object_index, mesh_object)
+                    .vertex_adjacency
+                    .len();
+                if entry.vertex_adjacency.len() != expected {
+                    let error = AdjValidationError::VertexAdjacencyLengthMismatch {
+                        entry_index,
+                        mesh_name: mesh_object.name.clone(),
+                        expected,
+                        actual: entry.vertex_adjacency.len(),
+                    };
+                    validation.adj_errors.push(error);
+                }
+            }
+            None => {
+                let error = AdjValidationError::InvalidMeshObjectIndex {
+                    entry_index,
+                    mesh_object_index: entry.mesh_object_index,
+                    mesh_object_count: mesh.objects.len(),
+                };
+                validation.adj_errors.push(error);
+            }
+        }
+    }
+}
+
 fn validate_renormal_material_entries(
     validation: &mut ModelValidationErrors,
     matl: &MatlData,
@@ -895,11 +1046,33 @@ fn validate_modl_entries(
     }
 }
 
+/// Returns the unique bone names used by aim constraints in `hlpb` that are missing from `skel`.
+/// Empty names are not included.
+pub fn validate_hlpb_against_skel(hlpb: &HlpbData, skel: &SkelData) -> Vec<String> {
+    let bone_names: HashSet<_> = skel.bones.iter().map(|b| b.name.as_str()).collect();
+
+    let mut missing = Vec::new();
+    for aim in &hlpb.aim_constraints {
+        for name in [
+            &aim.aim_bone_name1,
+            &aim.aim_bone_name2,
+            &aim.target_bone_name1,
+            &aim.target_bone_name2,
+        ] {
+            if !name.is_empty() && !bone_names.contains(name.as_str()) && !missing.contains(name) {
+                missing.push(name.clone());
+            }
+        }
+    }
+    missing
+}
+
 #[cfg(test)]
 mod tests {
     use nutexb::{NutexbFile, NutexbFooter, NutexbFormat};
     use ssbh_data::{
         adj_data::AdjEntryData,
+        hlpb_data::AimConstraintData,
         matl_data::{
             BlendFactor, BlendStateData, BlendStateParam, MatlEntryData, SamplerData, SamplerParam,
             TextureParam,
@@ -909,6 +1082,7 @@ mod tests {
     };
 
     use super::*;
+    use crate::test_bone;
 
     fn nutexb(image_format: NutexbFormat) -> NutexbFile {
         NutexbFile {
@@ -2295,4 +2469,131 @@ Use a Source Color of "One" or use a shader that does not premultiply alpha."#,
             format!("{}", validation.mesh_errors[0])
         );
     }
+
+    #[test]
+    fn validation_messages_severity() {
+        let validation = ModelValidationErrors {
+            mesh_errors: vec![
+                MeshValidationError {
+                    mesh_object_index: 0,
+                    kind: MeshValidationErrorKind::DuplicateSubindex {
+                        mesh_name: "a".to_owned(),
+                        subindex: 0,
+                    },
+                },
+                MeshValidationError {
+                    mesh_object_index: 1,
+                    kind: MeshValidationErrorKind::VertexWeightsZero {
+                        mesh_name: "b".to_owned(),
+                    },
+                },
+            ],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            vec![
+                ValidationMessage {
+                    file_name: "model.numshb".to_owned(),
+                    severity: Severity::Error,
+                    message: "Mesh \"a\" repeats subindex 0. Meshes with the same name must have unique subindices.".to_owned()
+                },
+                ValidationMessage {
+                    file_name: "model.numshb".to_owned(),
+                    severity: Severity::Warning,
+                    message: "Mesh \"b\" has vertex weights with a weight of 0.0 that can be removed.".to_owned()
+                }
+            ],
+            validation.messages()
+        );
+    }
+
+    #[test]
+    fn adj_entries_invalid_mesh_references() {
+        let mesh = MeshData {
+            major_version: 1,
+            minor_version: 10,
+            objects: vec![MeshObjectData {
+                name: "object0".to_owned(),
+                vertex_indices: vec![0, 1, 2],
+                positions: vec![AttributeData {
+                    name: "Position0".to_owned(),
+                    data: VectorData::Vector3(vec![[0.0; 3], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]),
+                }],
+                ..Default::default()
+            }],
+        };
+        let valid_entry = AdjEntryData::from_mesh_object(0, &mesh.objects[0]);
+        let adj = AdjData {
+            entries: vec![
+                valid_entry,
+                AdjEntryData {
+                    mesh_object_index: 0,
+                    vertex_adjacency: Vec::new(),
+                },
+                AdjEntryData {
+                    mesh_object_index: 2,
+                    vertex_adjacency: Vec::new(),
+                },
+            ],
+        };
+
+        let mut validation = ModelValidationErrors::default();
+        validate_adj_entries(&mut validation, &adj, &mesh);
+
+        assert!(matches!(
+            validation.adj_errors.as_slice(),
+            [
+                AdjValidationError::VertexAdjacencyLengthMismatch {
+                    entry_index: 1,
+                    actual: 0,
+                    ..
+                },
+                AdjValidationError::InvalidMeshObjectIndex {
+                    entry_index: 2,
+                    mesh_object_index: 2,
+                    mesh_object_count: 1
+                }
+            ]
+        ));
+        assert_eq!(
+            "Entry 2 references mesh object index 2, but the model.numshb has 1 mesh objects.",
+            format!("{}", validation.adj_errors[1])
+        );
+    }
+
+    #[test]
+    fn hlpb_aim_constraints_missing_bones() {
+        let skel = SkelData {
+            major_version: 1,
+            minor_version: 0,
+            bones: vec![test_bone("a", None), test_bone("b", None)],
+        };
+        let aim = |aim1: &str, aim2: &str, target1: &str, target2: &str| AimConstraintData {
+            name: String::new(),
+            aim_bone_name1: aim1.to_owned(),
+            aim_bone_name2: aim2.to_owned(),
+            aim_type1: "DEFAULT".to_owned(),
+            aim_type2: "DEFAULT".to_owned(),
+            target_bone_name1: target1.to_owned(),
+            target_bone_name2: target2.to_owned(),
+            unk1: 0,
+            unk2: 1,
+            aim: ssbh_data::Vector3::new(1.0, 0.0, 0.0),
+            up: ssbh_data::Vector3::new(0.0, 1.0, 0.0),
+            quat1: ssbh_data::Vector4::new(0.0, 0.0, 0.0, 1.0),
+            quat2: ssbh_data::Vector4::new(0.0, 0.0, 0.0, 1.0),
+        };
+        let hlpb = HlpbData {
+            major_version: 1,
+            minor_version: 0,
+            aim_constraints: vec![aim("a", "b", "c", ""), aim("d", "a", "c", "b")],
+            orient_constraints: Vec::new(),
+        };
+
+        assert_eq!(
+            vec!["c".to_owned(), "d".to_owned()],
+            validate_hlpb_against_skel(&hlpb, &skel)
+        );
+    }
 }
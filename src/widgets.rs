@@ -7,6 +7,11 @@ use ssbh_data::skel_data::SkelData;
 mod dragslider;
 pub use dragslider::DragSlider;
 
+mod expression;
+
+mod timeline;
+pub use timeline::TimelineScrubber;
+
 pub struct EyeCheckBox<'a> {
     checked: &'a mut bool,
     text: WidgetText,
@@ -92,6 +97,11 @@ fn eye_open_icon(ui: &Ui, rect: egui::Rect, visuals: &egui::style::WidgetVisuals
     }
 }
 
+/// Adds a label that shows `tooltip` when hovered.
+pub fn tooltip_text(ui: &mut Ui, text: impl Into<WidgetText>, tooltip: &str) -> Response {
+    ui.label(text).on_hover_text(tooltip)
+}
+
 pub fn enum_combo_box<V>(ui: &mut egui::Ui, id_source: impl std::hash::Hash, value: &mut V) -> bool
 where
     V: PartialEq + strum::IntoEnumIterator + ToString,
@@ -140,3 +150,260 @@ pub fn bone_combo_box(
         });
     changed
 }
+
+/// Small buttons to copy `values` to the clipboard as `"x, y, z"` and paste them back.
+pub fn clipboard_buttons(ui: &mut Ui, values: &mut [f32]) -> bool {
+    let mut changed = false;
+
+    if ui
+        .small_button("📋")
+        .on_hover_text("Copy the value to the clipboard.")
+        .clicked()
+    {
+        let text = values
+            .iter()
+            .map(|v| v.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        match with_clipboard(|c| c.set_text(text)) {
+            Ok(()) => show_toast(ui.ctx(), ToastLevel::Info, "Copied to clipboard"),
+            Err(e) => log::error!("Failed to copy to the clipboard: {}", e),
+        }
+    }
+
+    if ui
+        .small_button("📥")
+        .on_hover_text("Paste the value from the clipboard.")
+        .clicked()
+    {
+        match with_clipboard(|c| c.get_text()) {
+            Ok(text) => match parse_clipboard_values(&text, values.len()) {
+                Some(new_values) => {
+                    values.copy_from_slice(&new_values);
+                    changed = true;
+                }
+                None => show_toast(
+                    ui.ctx(),
+                    ToastLevel::Warning,
+                    format!("Failed to paste {:?} as {} value(s)", text, values.len()),
+                ),
+            },
+            Err(e) => log::error!("Failed to paste from the clipboard: {}", e),
+        }
+    }
+
+    changed
+}
+
+thread_local! {
+    // On Linux, copied text is only available while the clipboard that set it still exists.
+    static CLIPBOARD: std::cell::RefCell<Option<arboard::Clipboard>> =
+        const { std::cell::RefCell::new(None) };
+}
+
+/// Call `f` with a clipboard that is created once and reused for later calls.
+fn with_clipboard<T>(
+    f: impl FnOnce(&mut arboard::Clipboard) -> Result<T, arboard::Error>,
+) -> Result<T, arboard::Error> {
+    CLIPBOARD.with_borrow_mut(|cached| {
+        let mut clipboard = match cached.take() {
+            Some(clipboard) => clipboard,
+            None => arboard::Clipboard::new()?,
+        };
+        let result = f(&mut clipboard);
+        *cached = Some(clipboard);
+        result
+    })
+}
+
+fn parse_clipboard_values(text: &str, count: usize) -> Option<Vec<f32>> {
+    // Also accept tuples copied from debug output like "(1.0, 2.0, 3.0)".
+    let values = text
+        .trim()
+        .trim_start_matches(['(', '['])
+        .trim_end_matches([')', ']'])
+        .split(',')
+        .map(|v| v.trim().parse().ok())
+        .collect::<Option<Vec<f32>>>()?;
+    (values.len() == count).then_some(values)
+}
+
+/// A small line plot of each curve in `curves` with one value per frame.
+/// The XYZ curves use `axis_colors` from the color blind palette.
+/// Hovering shows the values at the frame under the cursor.
+pub fn sparkline(ui: &mut Ui, curves: &[Vec<f32>], axis_colors: [egui::Color32; 3]) -> Response {
+    let (rect, response) = ui.allocate_exact_size(vec2(60.0, 20.0), Sense::hover());
+
+    let frame_count = curves.iter().map(Vec::len).max().unwrap_or_default();
+    if frame_count == 0 {
+        return response;
+    }
+
+    let (min, max) = curves
+        .iter()
+        .flatten()
+        .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), v| {
+            (min.min(*v), max.max(*v))
+        });
+    let range = (max - min).max(f32::EPSILON);
+    let frame_x = |frame: usize| {
+        if frame_count > 1 {
+            rect.left() + rect.width() * frame as f32 / (frame_count - 1) as f32
+        } else {
+            rect.center().x
+        }
+    };
+
+    let hovered_frame = response.hover_pos().map(|pos| {
+        let t = ((pos.x - rect.left()) / rect.width()).clamp(0.0, 1.0);
+        (t * (frame_count - 1) as f32).round() as usize
+    });
+
+    if ui.is_rect_visible(rect) {
+        let painter = ui.painter_at(rect);
+        painter.rect_stroke(rect, 2.0, ui.visuals().widgets.noninteractive.bg_stroke);
+
+        let [x, y, z] = axis_colors;
+        let colors = [x, y, z, egui::Color32::GRAY];
+        for (curve, color) in curves.iter().zip(colors) {
+            let points = curve
+                .iter()
+                .enumerate()
+                .map(|(i, v)| {
+                    pos2(
+                        frame_x(i),
+                        rect.bottom() - (v - min) / range * rect.height(),
+                    )
+                })
+                .collect();
+            painter.add(epaint::Shape::line(points, egui::Stroke::new(1.0, color)));
+        }
+
+        if let Some(frame) = hovered_frame {
+            painter.vline(
+                frame_x(frame),
+                rect.y_range(),
+                egui::Stroke::new(1.0, ui.visuals().text_color()),
+            );
+        }
+    }
+
+    match hovered_frame {
+        Some(frame) => {
+            // Constant tracks only have a single value.
+            let values: Vec<_> = curves
+                .iter()
+                .filter_map(|c| c.get(frame).or(c.last()))
+                .map(|v| format!("{v:.3}"))
+                .collect();
+            response.on_hover_text(format!("Frame {frame}: {}", values.join(", ")))
+        }
+        None => response,
+    }
+}
+
+/// Show a dialog for the value in `state` until the user closes it.
+/// Closing the window or returning `true` from `add_contents` sets `state` to `None`.
+pub fn dialog_window<T>(
+    ctx: &egui::Context,
+    title: &str,
+    state: &mut Option<T>,
+    add_contents: impl FnOnce(&mut Ui, &mut T) -> bool,
+) {
+    if let Some(value) = state {
+        let mut open = true;
+        let mut close = false;
+        egui::Window::new(title)
+            .open(&mut open)
+            .resizable(false)
+            .show(ctx, |ui| {
+                close = add_contents(ui, value);
+            });
+
+        if !open || close {
+            *state = None;
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ToastLevel {
+    Info,
+    Warning,
+    Error,
+}
+
+/// A temporary message shown in the corner of the window.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Toast {
+    pub message: String,
+    /// The time in seconds when the message was added.
+    pub time: f64,
+    pub level: ToastLevel,
+}
+
+/// Queue a temporary message to show in the corner of the window.
+pub fn show_toast(ctx: &egui::Context, level: ToastLevel, message: impl Into<String>) {
+    let time = ctx.input(|i| i.time);
+    ctx.data_mut(|d| {
+        d.get_temp_mut_or_default::<Vec<Toast>>(toast_id())
+            .push(Toast {
+                message: message.into(),
+                time,
+                level,
+            })
+    });
+}
+
+/// Take the messages from [show_toast] in the order they were added.
+pub fn take_toasts(ctx: &egui::Context) -> Vec<Toast> {
+    ctx.data_mut(|d| d.remove_temp(toast_id()))
+        .unwrap_or_default()
+}
+
+fn toast_id() -> egui::Id {
+    egui::Id::new("toast_message")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_clipboard_single_value() {
+        assert_eq!(Some(vec![1.5]), parse_clipboard_values(" 1.5\n", 1));
+    }
+
+    #[test]
+    fn parse_clipboard_vector() {
+        assert_eq!(
+            Some(vec![1.0, -2.0, 0.5]),
+            parse_clipboard_values("1, -2.0,0.5", 3)
+        );
+    }
+
+    #[test]
+    fn parse_clipboard_tuple() {
+        assert_eq!(
+            Some(vec![1.0, 2.0, 3.0, 4.0]),
+            parse_clipboard_values("(1.0, 2.0, 3.0, 4.0)", 4)
+        );
+        assert_eq!(
+            Some(vec![1.0, 2.0]),
+            parse_clipboard_values("[1.0, 2.0]", 2)
+        );
+    }
+
+    #[test]
+    fn parse_clipboard_wrong_count() {
+        assert_eq!(None, parse_clipboard_values("1, 2, 3", 4));
+        assert_eq!(None, parse_clipboard_values("1, 2, 3", 2));
+    }
+
+    #[test]
+    fn parse_clipboard_invalid() {
+        assert_eq!(None, parse_clipboard_values("", 1));
+        assert_eq!(None, parse_clipboard_values("a, 2", 2));
+        assert_eq!(None, parse_clipboard_values("1,, 2", 2));
+    }
+}
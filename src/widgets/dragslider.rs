@@ -3,6 +3,8 @@ use egui::{
     Rect, Response, Sense, Stroke, TextEdit, TextStyle, Ui, Vec2, Widget, WidgetText,
 };
 
+use super::expression;
+
 /// A combined slider and text edit that fills up like an [egui::ProgressBar].
 pub struct DragSlider<'a> {
     id: Id,
@@ -10,6 +12,8 @@ pub struct DragSlider<'a> {
     width: f32,
     slider_min: f32,
     slider_max: f32,
+    min: Option<f32>,
+    max: Option<f32>,
 }
 
 impl<'a> DragSlider<'a> {
@@ -20,6 +24,8 @@ impl<'a> DragSlider<'a> {
             width: 100.0,
             slider_min: 0.0,
             slider_max: 1.0,
+            min: None,
+            max: None,
         }
     }
 
@@ -33,6 +39,27 @@ impl<'a> DragSlider<'a> {
         self.slider_max = max;
         self
     }
+
+    /// Clamp the value to be at least `min` when dragging or entering text.
+    pub fn min(mut self, min: f32) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Clamp the value to be at most `max` when dragging or entering text.
+    pub fn max(mut self, max: f32) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    fn clamp_to_bounds(&self, value: f32) -> f32 {
+        let value = self.min.map(|min| value.max(min)).unwrap_or(value);
+        self.max.map(|max| value.min(max)).unwrap_or(value)
+    }
+
+    fn is_at_bound(&self) -> bool {
+        Some(*self.value) == self.min || Some(*self.value) == self.max
+    }
 }
 
 // Based on a DragValue.
@@ -44,6 +71,7 @@ impl<'a> Widget for DragSlider<'a> {
 
         let kb_edit_id = self.id;
         let edit_text_id = kb_edit_id.with("text");
+        let invalid_id = kb_edit_id.with("invalid");
 
         // Switch from a slider to a text edit on click.
         // Return to using a slider if the text edit loses focus.
@@ -60,19 +88,43 @@ impl<'a> Widget for DragSlider<'a> {
                     .id(kb_edit_id)
                     .desired_width(desired_size.x),
             );
+            if response.changed() {
+                ui.memory_mut(|m| m.data.remove::<bool>(invalid_id));
+            }
 
             // Confirm the value on enter or if the user clicks away.
             // TODO: Also update value on lost focus.
             if ui.input(|i| i.key_pressed(Key::Enter)) {
-                if let Ok(new_value) = value_text.parse() {
-                    *self.value = new_value;
+                // Allow expressions like "45*PI/180" in addition to numbers.
+                if let Some(new_value) = expression::evaluate(&value_text) {
+                    *self.value = self.clamp_to_bounds(new_value);
                     response.mark_changed();
+                    response.surrender_focus();
+                    ui.memory_mut(|m| {
+                        m.data.remove::<String>(edit_text_id);
+                        m.data.remove::<bool>(invalid_id);
+                    });
+                } else {
+                    // Keep editing from the previous value.
+                    let previous_text = self.value.to_string();
+                    ui.memory_mut(|m| {
+                        m.request_focus(kb_edit_id);
+                        m.data.insert_temp(edit_text_id, previous_text);
+                        m.data.insert_temp(invalid_id, true);
+                    });
+                    select_all_text(ui, kb_edit_id);
                 }
-                response.surrender_focus();
-                ui.memory_mut(|m| m.data.remove::<String>(edit_text_id));
             } else {
                 ui.memory_mut(|m| m.data.insert_temp::<String>(edit_text_id, value_text));
             }
+
+            if ui.memory(|m| m.data.get_temp::<bool>(invalid_id).unwrap_or_default()) {
+                ui.painter().rect_stroke(
+                    response.rect,
+                    ui.visuals().widgets.inactive.rounding,
+                    Stroke::new(1.0, ui.visuals().error_fg_color),
+                );
+            }
             response
         } else {
             // Limit the displayed digits while still preserving precision.
@@ -93,7 +145,10 @@ impl<'a> Widget for DragSlider<'a> {
                 ui.memory_mut(|m| m.request_focus(kb_edit_id));
 
                 // Remove stale values if present.
-                ui.memory_mut(|m| m.data.remove::<String>(edit_text_id));
+                ui.memory_mut(|m| {
+                    m.data.remove::<String>(edit_text_id);
+                    m.data.remove::<bool>(invalid_id);
+                });
 
                 // Select all when next showing the text edit.
                 select_all_text(ui, kb_edit_id);
@@ -107,6 +162,7 @@ impl<'a> Widget for DragSlider<'a> {
                     // TODO: Set a speed based on the ranges?
                     *self.value += delta_value;
                     *self.value = self.value.clamp(self.slider_min, self.slider_max);
+                    *self.value = self.clamp_to_bounds(*self.value);
                     response.mark_changed();
                 }
             }
@@ -141,7 +197,13 @@ impl<'a> Widget for DragSlider<'a> {
                     .align_size_within_rect(text.size(), outer_rect)
                     .min;
 
-                ui.painter().galley(text_pos, text, visuals.text_color());
+                // Highlight the text to show that the value can't be moved further.
+                let text_color = if self.is_at_bound() {
+                    ui.visuals().strong_text_color()
+                } else {
+                    visuals.text_color()
+                };
+                ui.painter().galley(text_pos, text, text_color);
             }
             response
         };
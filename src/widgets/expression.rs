@@ -0,0 +1,178 @@
+//! A minimal expression evaluator for numeric text fields like `45*PI/180`.
+
+/// Evaluate an arithmetic expression with `+`, `-`, `*`, `/`, parentheses, `PI`, and `E`.
+/// Returns `None` if the expression is invalid or doesn't evaluate to a finite value.
+pub fn evaluate(text: &str) -> Option<f32> {
+    // Evaluate in f64 to avoid accumulating rounding errors.
+    let tokens = tokenize(text)?;
+    let mut parser = Parser {
+        tokens,
+        position: 0,
+    };
+    let value = parser.expression()?;
+
+    // Reject trailing input like "1 2" or "(1))".
+    if parser.position != parser.tokens.len() {
+        return None;
+    }
+
+    let value = value as f32;
+    value.is_finite().then_some(value)
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Token {
+    Number(f64),
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    LeftParen,
+    RightParen,
+}
+
+fn tokenize(text: &str) -> Option<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars = text.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        let token = match c {
+            ' ' | '\t' => continue,
+            '+' => Token::Plus,
+            '-' => Token::Minus,
+            '*' => Token::Star,
+            '/' => Token::Slash,
+            '(' => Token::LeftParen,
+            ')' => Token::RightParen,
+            '0'..='9' | '.' => {
+                let mut end = start + c.len_utf8();
+                while let Some((i, c)) = chars.next_if(|(_, c)| c.is_ascii_digit() || *c == '.') {
+                    end = i + c.len_utf8();
+                }
+                // Support scientific notation like "1e-3".
+                if let Some((i, c)) = chars.next_if(|(_, c)| *c == 'e') {
+                    end = i + c.len_utf8();
+                    if let Some((i, c)) = chars.next_if(|(_, c)| *c == '+' || *c == '-') {
+                        end = i + c.len_utf8();
+                    }
+                    while let Some((i, c)) = chars.next_if(|(_, c)| c.is_ascii_digit()) {
+                        end = i + c.len_utf8();
+                    }
+                }
+                Token::Number(text[start..end].parse().ok()?)
+            }
+            c if c.is_ascii_alphabetic() => {
+                let mut end = start + c.len_utf8();
+                while let Some((i, c)) = chars.next_if(|(_, c)| c.is_ascii_alphanumeric()) {
+                    end = i + c.len_utf8();
+                }
+                match text[start..end].to_ascii_uppercase().as_str() {
+                    "PI" => Token::Number(std::f64::consts::PI),
+                    "E" => Token::Number(std::f64::consts::E),
+                    _ => return None,
+                }
+            }
+            _ => return None,
+        };
+        tokens.push(token);
+    }
+    Some(tokens)
+}
+
+// expression = term (("+" | "-") term)*
+// term = factor (("*" | "/") factor)*
+// factor = ("+" | "-") factor | number | "(" expression ")"
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<Token> {
+        self.tokens.get(self.position).copied()
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.peek();
+        self.position += 1;
+        token
+    }
+
+    fn expression(&mut self) -> Option<f64> {
+        let mut value = self.term()?;
+        while let Some(token @ (Token::Plus | Token::Minus)) = self.peek() {
+            self.position += 1;
+            let rhs = self.term()?;
+            if token == Token::Plus {
+                value += rhs;
+            } else {
+                value -= rhs;
+            }
+        }
+        Some(value)
+    }
+
+    fn term(&mut self) -> Option<f64> {
+        let mut value = self.factor()?;
+        while let Some(token @ (Token::Star | Token::Slash)) = self.peek() {
+            self.position += 1;
+            let rhs = self.factor()?;
+            if token == Token::Star {
+                value *= rhs;
+            } else {
+                value /= rhs;
+            }
+        }
+        Some(value)
+    }
+
+    fn factor(&mut self) -> Option<f64> {
+        match self.next()? {
+            Token::Plus => self.factor(),
+            Token::Minus => self.factor().map(|v| -v),
+            Token::Number(value) => Some(value),
+            Token::LeftParen => {
+                let value = self.expression()?;
+                (self.next()? == Token::RightParen).then_some(value)
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn evaluate_numbers() {
+        assert_eq!(Some(1.5), evaluate("1.5"));
+        assert_eq!(Some(-0.25), evaluate(" -0.25 "));
+        assert_eq!(Some(0.001), evaluate("1e-3"));
+    }
+
+    #[test]
+    fn evaluate_operator_precedence() {
+        assert_eq!(Some(7.0), evaluate("1+2*3"));
+        assert_eq!(Some(9.0), evaluate("(1+2)*3"));
+        assert_eq!(Some(1.0), evaluate("4-2-1"));
+        assert_eq!(Some(1.0), evaluate("8/4/2"));
+        assert_eq!(Some(1.0 / 3.0), evaluate("1/3"));
+    }
+
+    #[test]
+    fn evaluate_constants() {
+        assert_eq!(Some(std::f32::consts::FRAC_PI_4), evaluate("45*PI/180"));
+        assert_eq!(Some(std::f32::consts::E), evaluate("e"));
+    }
+
+    #[test]
+    fn evaluate_invalid() {
+        assert_eq!(None, evaluate(""));
+        assert_eq!(None, evaluate("1+"));
+        assert_eq!(None, evaluate("(1"));
+        assert_eq!(None, evaluate("1)"));
+        assert_eq!(None, evaluate("1 2"));
+        assert_eq!(None, evaluate("abc"));
+        assert_eq!(None, evaluate("1/0"));
+    }
+}
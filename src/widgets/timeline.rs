@@ -0,0 +1,149 @@
+use egui::{pos2, vec2, CursorIcon, Rect, Response, Sense, Stroke, Ui, Widget};
+
+/// A horizontal animation timeline with tick marks and a draggable playhead.
+/// Clicking anywhere on the timeline jumps to the corresponding frame.
+pub struct TimelineScrubber<'a> {
+    frame: &'a mut f32,
+    final_frame: f32,
+    width: f32,
+    tick_interval: u32,
+    step: f32,
+    markers: &'a [(f32, String)],
+}
+
+/// The distance in points for snapping to or hovering a marker.
+const MARKER_RADIUS: f32 = 4.0;
+
+impl<'a> TimelineScrubber<'a> {
+    pub fn new(frame: &'a mut f32, final_frame: f32) -> Self {
+        TimelineScrubber {
+            frame,
+            final_frame,
+            width: 200.0,
+            tick_interval: 10,
+            step: 1.0,
+            markers: &[],
+        }
+    }
+
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// The number of frames between each tick mark.
+    pub fn tick_interval(mut self, tick_interval: u32) -> Self {
+        self.tick_interval = tick_interval;
+        self
+    }
+
+    /// Round selected frames to a multiple of `step` or disable rounding with `0.0`.
+    pub fn step(mut self, step: f32) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Labeled frames to draw on the timeline.
+    /// Clicking near a marker jumps to the marker's frame.
+    pub fn markers(mut self, markers: &'a [(f32, String)]) -> Self {
+        self.markers = markers;
+        self
+    }
+
+    fn nearest_marker(&self, rect: Rect, x: f32) -> Option<&(f32, String)> {
+        self.markers
+            .iter()
+            .map(|marker| (marker, (self.frame_to_x(rect, marker.0) - x).abs()))
+            .filter(|(_, distance)| *distance <= MARKER_RADIUS)
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(marker, _)| marker)
+    }
+
+    fn frame_to_x(&self, rect: Rect, frame: f32) -> f32 {
+        if self.final_frame > 0.0 {
+            rect.left() + frame / self.final_frame * rect.width()
+        } else {
+            rect.left()
+        }
+    }
+}
+
+impl<'a> Widget for TimelineScrubber<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let desired_size = vec2(self.width, ui.spacing().interact_size.y);
+        let (rect, mut response) = ui.allocate_exact_size(desired_size, Sense::click_and_drag());
+        let response_rect = rect.shrink2(vec2(4.0, 0.0));
+
+        if let Some(pointer) = response.interact_pointer_pos() {
+            let t = ((pointer.x - response_rect.left()) / response_rect.width()).clamp(0.0, 1.0);
+            let mut frame = t * self.final_frame;
+            if let Some((marker_frame, _)) = self.nearest_marker(response_rect, pointer.x) {
+                frame = *marker_frame;
+            } else if self.step > 0.0 {
+                frame = (frame / self.step).round() * self.step;
+            }
+
+            if frame != *self.frame {
+                *self.frame = frame;
+                response.mark_changed();
+            }
+        }
+        let mut response = response.on_hover_cursor(CursorIcon::ResizeHorizontal);
+
+        if let Some((marker_frame, label)) = response
+            .hover_pos()
+            .and_then(|pos| self.nearest_marker(response_rect, pos.x))
+        {
+            response = response.on_hover_text(format!("{label} (frame {marker_frame})"));
+        }
+
+        if ui.is_rect_visible(rect) {
+            let visuals = ui.style().interact(&response);
+            let painter = ui.painter();
+
+            painter.rect_filled(rect, visuals.rounding, ui.visuals().extreme_bg_color);
+
+            // Skip ticks that would be too close together to distinguish.
+            let tick_interval = self.tick_interval.max(1) as f32;
+            let tick_spacing = if self.final_frame > 0.0 {
+                tick_interval / self.final_frame * response_rect.width()
+            } else {
+                0.0
+            };
+            if tick_spacing >= 4.0 {
+                let stroke = Stroke::new(1.0, ui.visuals().weak_text_color());
+                let mut tick_frame = 0.0;
+                while tick_frame <= self.final_frame {
+                    let x = self.frame_to_x(response_rect, tick_frame);
+                    painter.line_segment(
+                        [
+                            pos2(x, rect.bottom() - rect.height() * 0.4),
+                            pos2(x, rect.bottom()),
+                        ],
+                        stroke,
+                    );
+                    tick_frame += tick_interval;
+                }
+            }
+
+            for (marker_frame, _) in self.markers {
+                let x = self.frame_to_x(response_rect, marker_frame.clamp(0.0, self.final_frame));
+                painter.line_segment(
+                    [pos2(x, rect.top()), pos2(x, rect.bottom())],
+                    Stroke::new(2.0, ui.visuals().warn_fg_color),
+                );
+            }
+
+            // Draw the playhead over the ticks and markers.
+            let x = self.frame_to_x(response_rect, self.frame.clamp(0.0, self.final_frame));
+            let color = ui.visuals().selection.bg_fill;
+            painter.line_segment(
+                [pos2(x, rect.top()), pos2(x, rect.bottom())],
+                Stroke::new(2.0, color),
+            );
+            painter.circle_filled(pos2(x, rect.top() + 3.0), 4.0, color);
+        }
+
+        response
+    }
+}